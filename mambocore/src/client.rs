@@ -1,10 +1,11 @@
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use anyhow::{Result, bail};
-use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderValue, REFERER, USER_AGENT};
+use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderValue, RANGE, REFERER, USER_AGENT};
 use tokio::sync::RwLock;
 
-use crate::model::WebResp;
+use crate::model::{ApiError, CommonError, WebResp};
 use crate::model::auth::{AuthData, RefreshTokenReq};
 
 const DEFAULT_BASE_URL: &str = "https://api.hachimi.world";
@@ -22,6 +23,8 @@ pub struct MamboClient {
     http: reqwest::Client,
     base_url: String,
     auth: Arc<RwLock<Option<AuthData>>>,
+    /// 每次设置/清除认证时递增，用于丢弃过期的异步清除
+    auth_generation: Arc<AtomicU64>,
 }
 
 impl MamboClient {
@@ -42,15 +45,38 @@ impl MamboClient {
             http,
             base_url: base_url.unwrap_or(DEFAULT_BASE_URL).to_string(),
             auth: Arc::new(RwLock::new(None)),
+            auth_generation: Arc::new(AtomicU64::new(0)),
         })
     }
 
     pub async fn set_auth(&self, data: AuthData) {
-        *self.auth.write().await = Some(data);
+        let mut guard = self.auth.write().await;
+        *guard = Some(data);
+        self.auth_generation.fetch_add(1, Ordering::SeqCst);
     }
 
     pub async fn clear_auth(&self) {
-        *self.auth.write().await = None;
+        let mut guard = self.auth.write().await;
+        *guard = None;
+        self.auth_generation.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// 当前认证代数，配合 [`Self::clear_auth_if`] 使用
+    pub fn auth_generation(&self) -> u64 {
+        self.auth_generation.load(Ordering::SeqCst)
+    }
+
+    /// 仅当认证自 `generation` 以来未被重新设置时才清除。
+    /// 登出后异步清除时使用，避免覆盖随后快速重新登录设置的新认证。
+    /// 返回是否实际清除。
+    pub async fn clear_auth_if(&self, generation: u64) -> bool {
+        let mut guard = self.auth.write().await;
+        if self.auth_generation.load(Ordering::SeqCst) != generation {
+            return false;
+        }
+        *guard = None;
+        self.auth_generation.fetch_add(1, Ordering::SeqCst);
+        true
     }
 
     pub async fn is_authenticated(&self) -> bool {
@@ -59,12 +85,13 @@ impl MamboClient {
 
     /// 同步检查是否已认证（用于渲染）
     pub fn is_authenticated_sync(&self) -> bool {
-        self.auth.try_read().map_or(false, |g| g.is_some())
+        self.auth.try_read().is_ok_and(|g| g.is_some())
     }
 
     /// 检查 token 是否过期，过期则尝试刷新，刷新失败则清除认证。
     /// 返回 `Some(AuthEvent)` 表示状态发生了变更，调用方应据此持久化。
     pub async fn ensure_valid_auth(&self) -> Option<AuthEvent> {
+        let generation = self.auth_generation();
         let (expired, refresh_token) = {
             let guard = self.auth.read().await;
             match guard.as_ref() {
@@ -98,9 +125,12 @@ impl MamboClient {
                 Some(AuthEvent::Refreshed(auth))
             }
             Err(_) => {
-                // 刷新失败，清除认证以降级到匿名模式
-                self.clear_auth().await;
-                Some(AuthEvent::Cleared)
+                // 刷新失败，清除认证以降级到匿名模式；刷新期间已重新登录则保留新认证
+                if self.clear_auth_if(generation).await {
+                    Some(AuthEvent::Cleared)
+                } else {
+                    None
+                }
             }
         }
     }
@@ -116,24 +146,36 @@ impl MamboClient {
             .map(|a| format!("Bearer {}", a.access_token))
     }
 
-    /// 解析 JSON 响应，出错时附带路径和原始 body 片段
+    /// 解析 JSON 响应，出错时附带路径和原始 body 片段。
+    /// 后端错误以 [`ApiError`] 返回；非标准响应遇到可识别的 HTTP 状态码时也归入 `ApiError`
     fn parse_response<T: serde::de::DeserializeOwned>(
         path: &str,
+        status: reqwest::StatusCode,
         text: &str,
     ) -> Result<T> {
         // 先尝试解析为标准 WebResp
         match serde_json::from_str::<WebResp<T>>(text) {
-            Ok(web) => web
-                .into_result()
-                .map_err(|e| anyhow::anyhow!("[{}] {}", path, e)),
+            Ok(web) => web.into_result().map_err(|error| {
+                ApiError { path: path.to_string(), error }.into()
+            }),
             Err(_) => {
-                // 非 WebResp 格式，尝试提取 error 字段（如 {"error":"Invalid token"}）
-                if let Ok(obj) = serde_json::from_str::<serde_json::Value>(text) {
-                    if let Some(err) = obj.get("error").and_then(|v| v.as_str()) {
-                        bail!("[{}] {}", path, err);
+                if let Some(code) = status_error_code(status) {
+                    return Err(ApiError {
+                        path: path.to_string(),
+                        error: CommonError {
+                            code: code.to_string(),
+                            msg: text.chars().take(200).collect::<String>(),
+                        },
                     }
+                    .into());
                 }
-                bail!("[{}] unexpected response: {}", path, &text[..text.len().min(200)]);
+                // 非 WebResp 格式，尝试提取 error 字段（如 {"error":"Invalid token"}）
+                if let Ok(obj) = serde_json::from_str::<serde_json::Value>(text)
+                    && let Some(err) = obj.get("error").and_then(|v| v.as_str())
+                {
+                    bail!("[{}] {}", path, err);
+                }
+                bail!("[{}] unexpected response: {}", path, text.chars().take(200).collect::<String>());
             }
         }
     }
@@ -146,8 +188,10 @@ impl MamboClient {
             req = req.header(AUTHORIZATION, auth);
         }
 
-        let text = req.send().await?.text().await?;
-        Self::parse_response(path, &text)
+        let resp = req.send().await?;
+        let status = resp.status();
+        let text = resp.text().await?;
+        Self::parse_response(path, status, &text)
     }
 
     /// GET 请求（带查询参数）
@@ -162,8 +206,10 @@ impl MamboClient {
             req = req.header(AUTHORIZATION, auth);
         }
 
-        let text = req.send().await?.text().await?;
-        Self::parse_response(path, &text)
+        let resp = req.send().await?;
+        let status = resp.status();
+        let text = resp.text().await?;
+        Self::parse_response(path, status, &text)
     }
 
     /// POST 请求（JSON body）
@@ -178,13 +224,15 @@ impl MamboClient {
             req = req.header(AUTHORIZATION, auth);
         }
 
-        let text = req.send().await?.text().await?;
-        Self::parse_response(path, &text)
+        let resp = req.send().await?;
+        let status = resp.status();
+        let text = resp.text().await?;
+        Self::parse_response(path, status, &text)
     }
 
     /// 获取音频流（用于流式播放）
-    /// url 可以是完整 URL 或相对路径
-    pub async fn get_audio_stream(&self, url: &str) -> Result<reqwest::Response> {
+    /// url 可以是完整 URL 或相对路径；`from` 为 Some 时请求从该字节开始的剩余部分（断点续传）
+    pub async fn get_audio_stream(&self, url: &str, from: Option<u64>) -> Result<reqwest::Response> {
         let full_url = if url.starts_with("http://") || url.starts_with("https://") {
             url.to_string()
         } else {
@@ -194,6 +242,20 @@ impl MamboClient {
         if let Some(auth) = self.auth_header().await {
             req = req.header(AUTHORIZATION, auth);
         }
+        if let Some(from) = from {
+            req = req.header(RANGE, format!("bytes={from}-"));
+        }
         Ok(req.send().await?)
     }
 }
+
+/// 非标准错误响应按 HTTP 状态码生成错误码，与后端错误码走同一套归类
+fn status_error_code(status: reqwest::StatusCode) -> Option<&'static str> {
+    match status.as_u16() {
+        401 => Some("unauthorized"),
+        404 => Some("not_found"),
+        429 => Some("too_many_requests"),
+        451 => Some("unavailable_for_legal_reasons"),
+        _ => None,
+    }
+}
@@ -139,6 +139,34 @@ pub struct CheckFavoriteQuery {
     pub playlist_id: i64,
 }
 
+// — 搜索参数构造 —
+
+/// 转义 filter 表达式双引号字符串中的用户输入（反斜杠与双引号）
+pub fn escape_filter_value(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '"' | '\\') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// 按标签精确匹配的 filter 表达式
+pub fn tag_filter(name: &str) -> String {
+    format!("tags = \"{}\"", escape_filter_value(name))
+}
+
+/// 清理自由文本搜索词：控制字符（如粘贴带入的换行）替换为空格并裁剪首尾空白
+pub fn sanitize_query(q: &str) -> String {
+    q.chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
 impl MamboClient {
     // — 认证 —
 
@@ -351,3 +379,33 @@ impl MamboClient {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{sanitize_query, tag_filter};
+
+    #[test]
+    fn tag_filter_passes_plain_names_through() {
+        assert_eq!(tag_filter("VOCALOID"), r#"tags = "VOCALOID""#);
+    }
+
+    #[test]
+    fn tag_filter_escapes_quotes_and_backslashes() {
+        assert_eq!(tag_filter(r#"say "hi""#), r#"tags = "say \"hi\"""#);
+        assert_eq!(tag_filter(r"a\b"), r#"tags = "a\\b""#);
+        assert_eq!(tag_filter(r#"\""#), r#"tags = "\\\"""#);
+    }
+
+    #[test]
+    fn tag_filter_keeps_unicode() {
+        assert_eq!(tag_filter("哈基米 ♪"), r#"tags = "哈基米 ♪""#);
+        assert_eq!(tag_filter("「引用」\"x"), r#"tags = "「引用」\"x""#);
+    }
+
+    #[test]
+    fn sanitize_query_replaces_control_chars() {
+        assert_eq!(sanitize_query("  hachimi\n"), "hachimi");
+        assert_eq!(sanitize_query("a\tb\u{7}c"), "a b c");
+        assert_eq!(sanitize_query(r#"say "hi" \ 哈"#), r#"say "hi" \ 哈"#);
+    }
+}
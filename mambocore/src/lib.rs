@@ -4,3 +4,4 @@ pub mod model;
 
 pub use client::{AuthEvent, MamboClient};
 pub use model::auth::AuthData;
+pub use model::{ApiError, ApiErrorKind};
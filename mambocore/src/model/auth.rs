@@ -29,6 +29,8 @@ pub struct TokenPair {
 pub struct LoginReq {
     pub email: String,
     pub password: String,
+    // TODO: 邮箱验证码登录——后端何时要求验证码、发送验证码的接口与错误码都未有文档；
+    // 确认后在登录步骤中加 `WaitingCode`，把输入的验证码填入此字段
     pub code: Option<String>,
     pub device_info: String,
     pub captcha_key: String,
@@ -45,6 +45,55 @@ impl std::fmt::Display for CommonError {
 
 impl std::error::Error for CommonError {}
 
+/// 后端错误码的大类，供调用方给出可操作的提示
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiErrorKind {
+    /// 请求过于频繁
+    RateLimited,
+    /// 未登录或 token 失效
+    Unauthorized,
+    /// 资源不存在或已删除
+    NotFound,
+    /// 所在地区不可用
+    RegionBlocked,
+    Other,
+}
+
+impl CommonError {
+    /// 按错误码归类。目前只识别 HTTP 状态码回退生成的错误码（见 `MamboClient`）；
+    /// 后端自身的错误码没有文档，一律归为 `Other`
+    pub fn kind(&self) -> ApiErrorKind {
+        match self.code.as_str() {
+            "too_many_requests" => ApiErrorKind::RateLimited,
+            "unauthorized" => ApiErrorKind::Unauthorized,
+            "not_found" => ApiErrorKind::NotFound,
+            "unavailable_for_legal_reasons" => ApiErrorKind::RegionBlocked,
+            _ => ApiErrorKind::Other,
+        }
+    }
+}
+
+/// 带请求路径的 API 错误。以 `anyhow::Error` 返回，调用方可 `downcast_ref` 取回错误码
+#[derive(Debug)]
+pub struct ApiError {
+    pub path: String,
+    pub error: CommonError,
+}
+
+impl ApiError {
+    pub fn kind(&self) -> ApiErrorKind {
+        self.error.kind()
+    }
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] {}", self.path, self.error)
+    }
+}
+
+impl std::error::Error for ApiError {}
+
 /// 播放历史
 pub mod play_history {
     use chrono::{DateTime, Utc};
@@ -65,3 +114,26 @@ pub mod play_history {
         pub list: Vec<PlayHistoryItem>,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ApiErrorKind, CommonError};
+
+    fn kind(code: &str) -> ApiErrorKind {
+        CommonError { code: code.to_string(), msg: String::new() }.kind()
+    }
+
+    #[test]
+    fn maps_status_fallback_codes() {
+        assert_eq!(kind("too_many_requests"), ApiErrorKind::RateLimited);
+        assert_eq!(kind("unauthorized"), ApiErrorKind::Unauthorized);
+        assert_eq!(kind("not_found"), ApiErrorKind::NotFound);
+        assert_eq!(kind("unavailable_for_legal_reasons"), ApiErrorKind::RegionBlocked);
+    }
+
+    #[test]
+    fn unknown_codes_are_other() {
+        assert_eq!(kind("parse_error"), ApiErrorKind::Other);
+        assert_eq!(kind("unknown"), ApiErrorKind::Other);
+    }
+}
@@ -16,6 +16,8 @@ pub struct PublicSongDetail {
     pub production_crew: Vec<SongProductionCrew>,
     pub creation_type: i32,
     pub origin_infos: Vec<CreationTypeInfo>,
+    // TODO: 专辑/合集——后端目前没有专辑字段，也没有按专辑列出曲目的接口；
+    // 接口提供后在此加 `album`（`#[serde(default)]` 兼容旧数据），并新增可浏览的 NavNode::Album
     pub uploader_uid: i64,
     pub uploader_name: String,
     pub play_count: i64,
@@ -137,6 +139,30 @@ impl PublicSongDetail {
         let secs = self.duration_seconds % 60;
         format!("{mins}:{secs:02}")
     }
+
+    /// 从制作人员中收集演唱/艺人类职位（按名字去重，保持原顺序）
+    pub fn artists(&self) -> Vec<&SongProductionCrew> {
+        let mut result: Vec<&SongProductionCrew> = Vec::new();
+        for member in &self.production_crew {
+            let Some(name) = member.person_name.as_deref() else { continue };
+            if member.is_artist_role()
+                && !result.iter().any(|m| m.person_name.as_deref() == Some(name))
+            {
+                result.push(member);
+            }
+        }
+        result
+    }
+}
+
+/// 视为"艺人"的职位关键词（小写匹配）
+const ARTIST_ROLE_KEYWORDS: &[&str] = &["vocal", "artist", "singer", "feat", "唱", "歌手"];
+
+impl SongProductionCrew {
+    pub fn is_artist_role(&self) -> bool {
+        let role = self.role.to_lowercase();
+        ARTIST_ROLE_KEYWORDS.iter().any(|k| role.contains(k))
+    }
 }
 
 impl SearchSongItem {
@@ -0,0 +1,249 @@
+//! 用本地 mock 服务器验证客户端的请求构造、响应解析与认证分支
+
+mod common;
+
+use mambocore::endpoints::{RecentQuery, SongSearchQuery};
+use mambocore::model::auth::LoginReq;
+use mambocore::{ApiError, ApiErrorKind, AuthData, AuthEvent};
+use serde_json::json;
+
+use common::{MockServer, search_hit, song, token_pair};
+
+fn api_kind(err: &anyhow::Error) -> Option<ApiErrorKind> {
+    err.downcast_ref::<ApiError>().map(ApiError::kind)
+}
+
+fn auth(access: &str, expires_at: i64) -> AuthData {
+    AuthData {
+        access_token: access.to_string(),
+        refresh_token: "refresh-old".to_string(),
+        expires_at,
+        username: Some("hachimi".to_string()),
+    }
+}
+
+fn login_req(code: Option<&str>) -> LoginReq {
+    LoginReq {
+        email: "user@example.com".to_string(),
+        password: "secret".to_string(),
+        code: code.map(str::to_string),
+        device_info: "tokaitui".to_string(),
+        captcha_key: "captcha".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn recent_songs_parses_list_and_sends_query() {
+    let server = MockServer::start().await;
+    server.ok("GET", "/song/recent_v2", json!({ "songs": [song(1, "A"), song(2, "B")] }));
+
+    let resp = server.client()
+        .recent_songs(&RecentQuery { cursor: None, limit: 30, after: None })
+        .await
+        .unwrap();
+
+    let ids: Vec<i64> = resp.songs.iter().map(|s| s.id).collect();
+    assert_eq!(ids, [1, 2]);
+    assert_eq!(resp.songs[0].title, "A");
+    // 未设置的可选参数不出现在查询串中
+    assert_eq!(server.last_request("/song/recent_v2").unwrap().query, "limit=30");
+}
+
+#[tokio::test]
+async fn empty_recommend_list_is_not_an_error() {
+    let server = MockServer::start().await;
+    server.ok("GET", "/song/recommend_anonymous", json!({ "songs": [] }));
+
+    let resp = server.client().recommend_songs_anonymous().await.unwrap();
+    assert!(resp.songs.is_empty());
+}
+
+#[tokio::test]
+async fn search_songs_returns_hits() {
+    let server = MockServer::start().await;
+    server.ok("GET", "/song/search", json!({
+        "hits": [search_hit(7, "hachimi")],
+        "query": "hachimi",
+        "processing_time_ms": 1,
+        "total_hits": 1,
+        "limit": 20,
+        "offset": 0,
+    }));
+
+    let resp = server.client()
+        .search_songs(&SongSearchQuery {
+            q: "hachimi".to_string(),
+            limit: Some(20),
+            offset: None,
+            filter: None,
+            sort_by: None,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(resp.total_hits, Some(1));
+    assert_eq!(resp.hits[0].id, 7);
+    let query = server.last_request("/song/search").unwrap().query;
+    assert!(query.contains("q=hachimi") && query.contains("limit=20"), "{query}");
+}
+
+#[tokio::test]
+async fn empty_search_has_no_hits() {
+    let server = MockServer::start().await;
+    server.ok("GET", "/song/search", json!({
+        "hits": [],
+        "query": "nothing",
+        "processing_time_ms": 0,
+        "total_hits": 0,
+        "limit": 20,
+        "offset": 0,
+    }));
+
+    let resp = server.client()
+        .search_songs(&SongSearchQuery {
+            q: "nothing".to_string(),
+            limit: Some(20),
+            offset: None,
+            filter: None,
+            sort_by: None,
+        })
+        .await
+        .unwrap();
+    assert!(resp.hits.is_empty());
+}
+
+#[tokio::test]
+async fn song_detail_backend_error_keeps_path_and_code() {
+    let server = MockServer::start().await;
+    server.err("GET", "/song/detail_by_id", "backend_error", "歌曲不存在");
+
+    let err = server.client().song_detail_by_id(42).await.unwrap_err();
+
+    let api = err.downcast_ref::<ApiError>().expect("ApiError");
+    assert_eq!(api.path, "/song/detail_by_id");
+    assert_eq!(api.error.code, "backend_error");
+    // 后端错误码没有文档，不做归类
+    assert_eq!(api.kind(), ApiErrorKind::Other);
+    assert_eq!(server.last_request("/song/detail_by_id").unwrap().query, "id=42");
+}
+
+#[tokio::test]
+async fn non_webresp_errors_fall_back_to_http_status() {
+    let server = MockServer::start().await;
+    server.mount("GET", "/song/recommend_anonymous", 429, "Too Many Requests");
+
+    let err = server.client().recommend_songs_anonymous().await.unwrap_err();
+    assert_eq!(api_kind(&err), Some(ApiErrorKind::RateLimited));
+
+    // 无法识别的状态码与非 JSON 内容不归入 ApiError，但仍带路径
+    server.mount("GET", "/song/recommend_anonymous", 502, "<html>bad gateway</html>");
+    let err = server.client().recommend_songs_anonymous().await.unwrap_err();
+    assert_eq!(api_kind(&err), None);
+    assert!(err.to_string().starts_with("[/song/recommend_anonymous]"), "{err}");
+}
+
+#[tokio::test]
+async fn non_ascii_error_pages_are_truncated_on_char_boundaries() {
+    let server = MockServer::start().await;
+    // 第 200 个字节落在多字节字符中间
+    let page = format!("<p>{}</p>", "请求过于频繁".repeat(40));
+    server.mount("GET", "/song/recommend_anonymous", 429, page.clone());
+
+    let err = server.client().recommend_songs_anonymous().await.unwrap_err();
+    let api = err.downcast_ref::<ApiError>().expect("ApiError");
+    assert_eq!(api.kind(), ApiErrorKind::RateLimited);
+    assert_eq!(api.error.msg.chars().count(), 200);
+    assert!(page.starts_with(&api.error.msg));
+
+    server.mount("GET", "/song/recommend_anonymous", 502, page);
+    let err = server.client().recommend_songs_anonymous().await.unwrap_err();
+    assert!(err.to_string().contains("请求过于频繁"), "{err}");
+}
+
+#[tokio::test]
+async fn login_posts_credentials_and_returns_tokens() {
+    let server = MockServer::start().await;
+    server.ok("POST", "/auth/login/email", json!({
+        "uid": 1,
+        "username": "hachimi",
+        "token": token_pair("access-1", "refresh-1"),
+    }));
+
+    let resp = server.client().login(&login_req(None)).await.unwrap();
+
+    assert_eq!(resp.username, "hachimi");
+    assert_eq!(resp.token.access_token, "access-1");
+    let body = server.last_request("/auth/login/email").unwrap().json();
+    assert_eq!(body["email"], "user@example.com");
+    assert_eq!(body["code"], serde_json::Value::Null);
+}
+
+#[tokio::test]
+async fn bearer_token_is_sent_only_when_authenticated() {
+    let server = MockServer::start().await;
+    server.ok("GET", "/song/recommend_anonymous", json!({ "songs": [song(1, "A")] }));
+    server.ok("GET", "/song/recommend", json!({ "songs": [song(2, "B")] }));
+    let client = server.client();
+
+    // 匿名：走 anonymous 接口，不带 Authorization
+    assert!(!client.is_authenticated().await);
+    client.recommend_songs_anonymous().await.unwrap();
+    let anon = server.last_request("/song/recommend_anonymous").unwrap();
+    assert_eq!(anon.header("authorization"), None);
+
+    // 登录后：个性化接口带上 Bearer token
+    client.set_auth(auth("access-1", i64::MAX)).await;
+    assert!(client.is_authenticated().await);
+    let resp = client.recommend_songs().await.unwrap();
+    assert_eq!(resp.songs[0].id, 2);
+    let authed = server.last_request("/song/recommend").unwrap();
+    assert_eq!(authed.header("authorization"), Some("Bearer access-1"));
+
+    // 登出后恢复匿名
+    client.clear_auth().await;
+    client.recommend_songs_anonymous().await.unwrap();
+    let anon = server.last_request("/song/recommend_anonymous").unwrap();
+    assert_eq!(anon.header("authorization"), None);
+}
+
+#[tokio::test]
+async fn expired_token_is_refreshed() {
+    let server = MockServer::start().await;
+    server.ok("POST", "/auth/refresh_token", token_pair("access-2", "refresh-2"));
+    let client = server.client();
+    client.set_auth(auth("access-1", 0)).await;
+
+    let Some(AuthEvent::Refreshed(data)) = client.ensure_valid_auth().await else {
+        panic!("expected refresh");
+    };
+    assert_eq!(data.access_token, "access-2");
+    // 刷新响应不含用户名，沿用旧值
+    assert_eq!(data.username.as_deref(), Some("hachimi"));
+    let body = server.last_request("/auth/refresh_token").unwrap().json();
+    assert_eq!(body["refresh_token"], "refresh-old");
+
+    // 新 token 未过期，不再刷新
+    assert!(client.ensure_valid_auth().await.is_none());
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn failed_refresh_falls_back_to_anonymous() {
+    let server = MockServer::start().await;
+    server.err("POST", "/auth/refresh_token", "invalid_token", "refresh token 无效");
+    let client = server.client();
+    client.set_auth(auth("access-1", 0)).await;
+
+    assert!(matches!(client.ensure_valid_auth().await, Some(AuthEvent::Cleared)));
+    assert!(!client.is_authenticated().await);
+}
+
+#[tokio::test]
+async fn valid_token_skips_refresh() {
+    let server = MockServer::start().await;
+    let client = server.client();
+    client.set_auth(auth("access-1", i64::MAX)).await;
+
+    assert!(client.ensure_valid_auth().await.is_none());
+    assert!(server.requests().is_empty());
+}
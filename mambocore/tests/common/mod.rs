@@ -0,0 +1,234 @@
+//! 集成测试用的本地 mock API 服务器：按 (方法, 路径) 返回预设响应，并记录收到的请求。
+//! 只实现测试需要的最小 HTTP/1.1 子集（每个连接一个请求，`Connection: close`），不依赖真实后端
+
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use serde_json::{Value, json};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// 收到的一次请求
+#[derive(Debug, Clone)]
+pub struct Recorded {
+    pub method: String,
+    pub path: String,
+    /// 原始查询串（不含 `?`），没有时为空
+    pub query: String,
+    /// 头名已转为小写
+    pub headers: HashMap<String, String>,
+    pub body: String,
+}
+
+impl Recorded {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).map(String::as_str)
+    }
+
+    pub fn json(&self) -> Value {
+        serde_json::from_str(&self.body).unwrap_or(Value::Null)
+    }
+}
+
+#[derive(Clone)]
+struct Route {
+    method: &'static str,
+    path: String,
+    status: u16,
+    body: String,
+}
+
+#[derive(Default)]
+struct State {
+    routes: Vec<Route>,
+    requests: Vec<Recorded>,
+}
+
+pub struct MockServer {
+    base_url: String,
+    state: Arc<Mutex<State>>,
+}
+
+impl MockServer {
+    /// 在随机端口上启动，任务随测试的 runtime 结束
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind mock server");
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let state = Arc::new(Mutex::new(State::default()));
+        let accept_state = state.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(serve(stream, accept_state.clone()));
+            }
+        });
+        Self { base_url, state }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// 指向本服务器的客户端
+    pub fn client(&self) -> mambocore::MamboClient {
+        mambocore::MamboClient::new(Some(&self.base_url)).unwrap()
+    }
+
+    /// 预设原样响应；同一路由后挂载的覆盖先挂载的
+    pub fn mount(&self, method: &'static str, path: &str, status: u16, body: impl Into<String>) {
+        self.state.lock().unwrap().routes.push(Route {
+            method,
+            path: path.to_string(),
+            status,
+            body: body.into(),
+        });
+    }
+
+    /// 预设成功的 `WebResp`：`{"ok": true, "data": data}`
+    pub fn ok(&self, method: &'static str, path: &str, data: Value) {
+        self.mount(method, path, 200, json!({ "ok": true, "data": data }).to_string());
+    }
+
+    /// 预设后端错误的 `WebResp`：`{"ok": false, "data": {"code", "msg"}}`
+    pub fn err(&self, method: &'static str, path: &str, code: &str, msg: &str) {
+        self.mount(
+            method,
+            path,
+            200,
+            json!({ "ok": false, "data": { "code": code, "msg": msg } }).to_string(),
+        );
+    }
+
+    /// 到目前为止收到的全部请求
+    pub fn requests(&self) -> Vec<Recorded> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// 某个路径最近一次收到的请求
+    pub fn last_request(&self, path: &str) -> Option<Recorded> {
+        self.requests().into_iter().rev().find(|r| r.path == path)
+    }
+}
+
+async fn serve(mut stream: TcpStream, state: Arc<Mutex<State>>) {
+    let Some(request) = read_request(&mut stream).await else { return };
+    let route = {
+        let mut state = state.lock().unwrap();
+        let route = state.routes.iter()
+            .rev()
+            .find(|r| r.method == request.method && r.path == request.path)
+            .cloned();
+        state.requests.push(request);
+        route
+    };
+    // 未挂载的路由按 404 纯文本返回，客户端归类为 not_found
+    let (status, body) = route.map_or((404, "no mock route".to_string()), |r| (r.status, r.body));
+    let response = format!(
+        "HTTP/1.1 {status} mock\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+        body.len(),
+    );
+    let _ = stream.write_all(response.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
+
+async fn read_request(stream: &mut TcpStream) -> Option<Recorded> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let head_end = loop {
+        let n = stream.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            return None;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buf[..head_end]).into_owned();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next()?.split(' ');
+    let method = request_line.next()?.to_string();
+    let target = request_line.next()?;
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let headers: HashMap<String, String> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(k, v)| (k.trim().to_ascii_lowercase(), v.trim().to_string()))
+        .collect();
+
+    let len: usize = headers.get("content-length").and_then(|v| v.parse().ok()).unwrap_or(0);
+    let mut body = buf[head_end + 4..].to_vec();
+    while body.len() < len {
+        let n = stream.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            break;
+        }
+        body.extend_from_slice(&chunk[..n]);
+    }
+
+    Some(Recorded {
+        method,
+        path: path.to_string(),
+        query: query.to_string(),
+        headers,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+/// 字段齐全的歌曲详情
+pub fn song(id: i64, title: &str) -> Value {
+    json!({
+        "id": id,
+        "display_id": format!("JM-{id}"),
+        "title": title,
+        "subtitle": "",
+        "description": "",
+        "duration_seconds": 180,
+        "tags": [{ "id": 1, "name": "VOCALOID", "description": null }],
+        "lyrics": "",
+        "audio_url": format!("/audio/{id}.mp3"),
+        "cover_url": format!("/cover/{id}.jpg"),
+        "production_crew": [],
+        "creation_type": 0,
+        "origin_infos": [],
+        "uploader_uid": 100,
+        "uploader_name": "uploader",
+        "play_count": 0,
+        "like_count": 0,
+        "external_links": [],
+        "create_time": "2025-01-01T00:00:00Z",
+        "release_time": "2025-01-01T00:00:00Z",
+        "explicit": null,
+        "gain": null,
+    })
+}
+
+/// 搜索结果条目
+pub fn search_hit(id: i64, title: &str) -> Value {
+    json!({
+        "id": id,
+        "display_id": format!("JM-{id}"),
+        "title": title,
+        "subtitle": "",
+        "description": "",
+        "artist": "artist",
+        "duration_seconds": 180,
+        "play_count": 0,
+        "like_count": 0,
+        "cover_art_url": format!("/cover/{id}.jpg"),
+        "audio_url": format!("/audio/{id}.mp3"),
+        "uploader_uid": 100,
+        "uploader_name": "uploader",
+        "explicit": null,
+    })
+}
+
+/// token 对，`expires_in` 为 RFC 3339 时间
+pub fn token_pair(access: &str, refresh: &str) -> Value {
+    json!({
+        "access_token": access,
+        "refresh_token": refresh,
+        "expires_in": "2099-01-01T00:00:00Z",
+    })
+}
@@ -1,13 +1,27 @@
+use crate::config::settings::AutoplayOnStart;
 use crate::model::auth::LoginReq;
+use crate::model::queue::QueueUndo;
 use crate::ui::login::{LoginState, LoginStep};
 
+use crate::ui::log_view::LogLevel;
+use crate::ui::toast::ToastKind;
+
 use super::super::{App, AppMessage, InputMode};
+use super::{describe_error, is_network_error};
+
+/// 网络错误时自动重新生成验证码的次数上限（间隔 2s、4s、8s）
+const CAPTCHA_AUTO_RETRIES: u32 = 3;
 
 impl App {
     // — 认证 —
 
-    /// 第一步：校验输入 → 异步生成 captcha
+    /// 第一步（按 Enter）：校验输入 → 异步生成 captcha，重新计算自动重试次数
     pub(crate) fn start_captcha(&mut self) {
+        self.login.captcha_attempts = 0;
+        self.request_captcha();
+    }
+
+    fn request_captcha(&mut self) {
         let email = self.login.email.trim().to_string();
         let password = self.login.password.clone();
 
@@ -18,6 +32,8 @@ impl App {
 
         self.login.step = LoginStep::GeneratingCaptcha;
         self.login.error = None;
+        self.login.captcha_retry_at = None;
+        self.login.captcha_url = None;
 
         let tx = self.msg_tx.clone();
         let client = self.client.clone();
@@ -27,11 +43,49 @@ impl App {
             let _ = tx.send(AppMessage::CaptchaGenerated(
                 result
                     .map(|resp| (resp.captcha_key, resp.url))
-                    .map_err(|e| e.to_string()),
+                    .map_err(|e| (describe_error(&e), is_network_error(&e))),
             ));
         });
     }
 
+    /// 验证码生成结果：浏览器打不开时展示地址并复制到剪贴板；
+    /// 网络错误按退避自动重试，服务端拒绝则停在输入页等待按 Enter 重试
+    pub(crate) fn on_captcha_generated(&mut self, result: Result<(String, String), (String, bool)>) {
+        match result {
+            Ok((captcha_key, url)) => {
+                self.login.captcha_key = Some(captcha_key);
+                self.login.step = LoginStep::WaitingCaptcha;
+                self.login.captcha_failed = false;
+                if let Err(e) = open::that(&url) {
+                    self.ui.logs.push(LogLevel::Warn, format!("无法打开浏览器：{e}，验证码地址：{url}"));
+                    super::share::copy_to_clipboard(&url);
+                    self.login.captcha_url = Some(url);
+                }
+            }
+            Err((e, transient)) => {
+                self.ui.logs.push(LogLevel::Warn, format!("生成验证码失败：{e}"));
+                self.login.error = Some(e);
+                self.login.step = LoginStep::Input;
+                self.login.captcha_failed = true;
+                if transient && self.login.captcha_attempts < CAPTCHA_AUTO_RETRIES {
+                    let delay = std::time::Duration::from_secs(2 << self.login.captcha_attempts);
+                    self.login.captcha_attempts += 1;
+                    self.login.captcha_retry_at = Some(std::time::Instant::now() + delay);
+                }
+            }
+        }
+    }
+
+    /// PlayerTick 中调用：自动重试的时间到了，重新生成验证码（已离开登录界面时不重试）
+    pub(crate) fn flush_captcha_retry(&mut self) {
+        if self.ui.input_mode == InputMode::Login
+            && self.login.step == LoginStep::Input
+            && self.login.captcha_retry_at.is_some_and(|at| std::time::Instant::now() >= at)
+        {
+            self.request_captcha();
+        }
+    }
+
     /// 第二步：captcha 已完成，提交登录
     pub(crate) fn submit_login(&mut self) {
         let Some(captcha_key) = self.login.captcha_key.clone() else {
@@ -60,7 +114,7 @@ impl App {
 
             let result = client.login(&req).await;
             let _ = tx.send(AppMessage::LoginResult(
-                result.map_err(|e| e.to_string()),
+                result.map_err(|e| describe_error(&e)),
             ));
         });
     }
@@ -68,14 +122,24 @@ impl App {
     pub(crate) fn logout(&mut self) {
         let _ = crate::config::auth_store::clear();
 
+        // 记录登出时的认证代数：若清除任务执行前已重新登录，则不覆盖新认证
         let client = self.client.clone();
+        let generation = client.auth_generation();
         tokio::spawn(async move {
-            client.clear_auth().await;
+            client.clear_auth_if(generation).await;
         });
 
+        // 登出即结束本次会话：停止播放并清空队列（含撤销记录），立即写盘，
+        // 下次启动或换账号登录都不会恢复上一位用户的队列。重新登录（relogin）不经过这里，队列保留
+        self.stop_playback();
+        self.queue.clear();
+        self.queue_undo = QueueUndo::default();
+        let _ = self.queue.persist();
+
         self.username = None;
         self.cache.songs.clear();
         self.cache.loading.clear();
+        self.cache.failed.clear();
         self.cache.tags = None;
         self.cache.playlists = None;
         self.cache.queue_song_detail.clear();
@@ -83,6 +147,68 @@ impl App {
         self.ui.input_mode = InputMode::Login;
     }
 
+    /// 用当前登录态请求一次用户资料，结果通过 toast/日志反馈
+    pub(crate) fn test_connection(&mut self) {
+        let token = crate::config::auth_store::load().ok().flatten().map(|a| a.access_token);
+        let Some(token) = token else {
+            self.show_toast(ToastKind::Warn, t!("toast.not_logged_in").to_string());
+            return;
+        };
+        let uid = match crate::config::auth_store::extract_uid_from_token(&token) {
+            Ok(uid) => uid,
+            Err(e) => {
+                self.ui.logs.push(LogLevel::Error, format!("连接测试失败：{e}"));
+                self.show_toast(ToastKind::Warn, t!("toast.connection_failed").to_string());
+                return;
+            }
+        };
+
+        self.show_toast(ToastKind::Info, t!("toast.testing_connection").to_string());
+        let tx = self.msg_tx.clone();
+        let client = self.client.clone();
+        tokio::spawn(async move {
+            let start = std::time::Instant::now();
+            let result = client
+                .user_profile(uid)
+                .await
+                .map(|p| (p.username, start.elapsed().as_millis()))
+                .map_err(|e| e.to_string());
+            let _ = tx.send(AppMessage::ConnectionTested(result));
+        });
+    }
+
+    /// 登录界面按 Esc（首次登录时）：不登录，以匿名身份浏览
+    pub(crate) fn continue_anonymously(&mut self) {
+        self.login = LoginState::new();
+        self.ui.input_mode = InputMode::Normal;
+        self.show_toast(ToastKind::Info, t!("toast.anonymous_mode").to_string());
+    }
+
+    /// 重新登录：只切换到登录界面，保留队列与缓存
+    pub(crate) fn relogin(&mut self) {
+        self.login = LoginState::new();
+        self.login.cancelable = self.client.is_authenticated_sync();
+        self.ui.input_mode = InputMode::Login;
+    }
+
+    /// 恢复上次退出时的队列：按 `autoplay_on_start` 继续播放，或只恢复播放栏并停在原位置
+    pub(crate) fn restore_last_session(&mut self) {
+        let Some(song) = self.queue.current_song().cloned() else {
+            return;
+        };
+        let pos_ms = *self.resume_position_ms.get_or_insert(self.queue.position_ms);
+        match self.settings.player.autoplay_on_start {
+            AutoplayOnStart::Off => {
+                self.player.bar.title = song.name.clone();
+                self.player.bar.artist = song.artist.clone();
+                self.player.bar.total_secs = song.duration_secs as u32;
+                self.player.bar.current_secs = (pos_ms / 1000) as u32;
+                self.player.bar.cover_url = song.cover_url.clone();
+            }
+            AutoplayOnStart::Resume => self.resume_playback(),
+        }
+    }
+
     /// 恢复上次退出时的播放
     pub(crate) fn resume_playback(&mut self) {
         if let Some(song) = self.queue.current_song().cloned() {
@@ -0,0 +1,60 @@
+use std::time::{Duration, Instant};
+
+use crate::config::settings::Settings;
+use crate::model::queue::QueueState;
+use crate::ui::log_view::LogLevel;
+
+use super::super::{App, AppMessage};
+
+impl App {
+    /// 每隔 `autosave_secs` 保存一次播放队列与改动过的设置，防止崩溃或被强制结束时丢失。
+    /// 序列化在主线程完成（队列内容与上次写入相同则跳过），写文件交给阻塞线程，不占用渲染
+    pub(crate) fn flush_autosave(&mut self) {
+        let interval = self.settings.autosave_secs;
+        if interval == 0 || self.autosave.last_at.elapsed() < Duration::from_secs(interval) {
+            return;
+        }
+        self.autosave.last_at = Instant::now();
+
+        let queue = match serde_json::to_string_pretty(&self.queue) {
+            Ok(content) if self.autosave.last_queue.as_ref() != Some(&content) => Some(content),
+            Ok(_) => None,
+            Err(e) => {
+                self.ui.logs.push(LogLevel::Warn, format!("自动保存队列失败：{e}"));
+                None
+            }
+        };
+        let settings = std::mem::take(&mut self.autosave.settings_dirty).then(|| self.settings.clone());
+        if queue.is_none() && settings.is_none() {
+            return;
+        }
+        if let Some(content) = &queue {
+            self.autosave.last_queue = Some(content.clone());
+        }
+
+        let tx = self.msg_tx.clone();
+        tokio::task::spawn_blocking(move || {
+            let result = write(queue.as_deref(), settings.as_ref());
+            if let Err(e) = result {
+                let _ = tx.send(AppMessage::AutoSaveFailed(e.to_string()));
+            }
+        });
+    }
+
+    /// 写入失败时下次自动保存全部重写
+    pub(crate) fn on_autosave_failed(&mut self, error: String) {
+        self.autosave.last_queue = None;
+        self.autosave.settings_dirty = true;
+        self.ui.logs.push(LogLevel::Warn, format!("自动保存失败：{error}"));
+    }
+}
+
+fn write(queue: Option<&str>, settings: Option<&Settings>) -> anyhow::Result<()> {
+    if let Some(content) = queue {
+        QueueState::write_persisted(content)?;
+    }
+    if let Some(settings) = settings {
+        settings.save()?;
+    }
+    Ok(())
+}
@@ -1,7 +1,12 @@
+use crate::config::settings::CoverPolicy;
 use crate::ui::navigation::{NavNode, SearchType};
+use crate::ui::graphics::GraphicsProtocol;
 
 use super::super::{App, AppMessage};
 
+/// 背景封面相对原图的亮度
+const BACKDROP_BRIGHTNESS: f32 = 0.25;
+
 impl App {
     // — 封面图片 —
 
@@ -12,7 +17,7 @@ impl App {
 
         match node {
             NavNode::Queue => {
-                let item = self.queue.songs.get(sel)?;
+                let item = self.queue.songs.get(self.queue_index_at(sel)?)?;
                 if let Some(detail) = self.cache.queue_song_detail.get(&item.id) {
                     Some(detail.cover_url.clone())
                 } else {
@@ -35,42 +40,93 @@ impl App {
             },
             NavNode::MyPlaylists => {
                 let pl = self.cache.playlists.as_ref()?.get(sel)?;
-                pl.cover_url.clone()
+                pl.cover_url.clone().or_else(|| {
+                    self.rich_preview_cover(&NavNode::PlaylistDetail { id: pl.id })
+                })
+            }
+            NavNode::Categories => {
+                let tag = self.cache.tags.as_ref()?.get(sel)?;
+                self.rich_preview_cover(&NavNode::Tag { name: tag.clone() })
             }
             node if !node.has_static_children() => {
                 let song = self.cache.songs.get(node)?.get(sel)?;
                 Some(song.cover_url.clone())
             }
-            _ => None,
+            node => self.rich_preview_cover(node.children().get(sel)?),
         }
     }
 
+    /// 开启列表详细预览时，悬停列表节点取其第一首歌的封面
+    fn rich_preview_cover(&self, list_node: &NavNode) -> Option<String> {
+        if !self.settings.display.rich_preview {
+            return None;
+        }
+        let song = self.cache.songs.get(list_node)?.first()?;
+        Some(song.cover_url.clone())
+    }
+
     /// 记录待加载封面（防抖：实际加载在 PlayerTick 中延迟触发）
     /// 若封面已就绪或正在下载则跳过。
     pub(crate) fn schedule_cover_load(&mut self) {
-        if !self.cover.kitty_supported {
+        if !self.cover.supported() || self.settings.data_saver {
             return;
         }
         // 展开页跟随播放时，优先取正在播放歌曲的封面
-        let url = if self.player.expanded && self.player.follow_playback {
-            self.player.current_detail.as_ref().map(|d| d.cover_url.clone())
-                .or_else(|| self.current_preview_cover_url())
-        } else {
-            self.current_preview_cover_url()
+        let follows_playing = self.player.expanded && self.player.follow_playback;
+        let playing_url = self.player.current_detail.as_ref().map(|d| d.cover_url.clone());
+        let url = match self.settings.display.cover_policy {
+            CoverPolicy::Off => None,
+            CoverPolicy::PlayingOnly => playing_url.filter(|_| follows_playing),
+            CoverPolicy::Browse | CoverPolicy::Prefetch if follows_playing => {
+                playing_url.or_else(|| self.current_preview_cover_url())
+            }
+            CoverPolicy::Browse | CoverPolicy::Prefetch => self.current_preview_cover_url(),
         };
-        if let Some(url) = url {
-            if !url.is_empty()
-                && !self.cache.covers.is_ready(&url)
-                && !self.cache.covers.is_loading(&url)
-            {
-                self.cover.pending_cover_load = Some((url, std::time::Instant::now()));
+        if let Some(url) = url
+            && !url.is_empty()
+            && !self.cache.covers.is_ready(&url)
+            && !self.cache.covers.is_loading(&url)
+        {
+            self.cover.pending_cover_load = Some((url, std::time::Instant::now()));
+        }
+    }
+
+    /// 播放栏是否显示小封面：需开启 `bar_thumbnail` 且使用 Kitty 图形协议
+    pub(crate) fn bar_thumbnail_enabled(&self) -> bool {
+        self.settings.display.bar_thumbnail && self.cover.protocol == Some(GraphicsProtocol::Kitty)
+    }
+
+    /// 新歌开始播放后按封面策略提前下载：PlayingOnly 下载正在播放的封面，
+    /// Prefetch 另外下载下一首的封面，切歌或打开展开页时无需等待。
+    /// 播放栏显示小封面时无论策略如何都下载正在播放的封面
+    pub(crate) fn load_playing_covers(&mut self) {
+        if !self.cover.supported() || self.settings.data_saver {
+            return;
+        }
+        let policy = self.settings.display.cover_policy;
+        let mut urls = Vec::new();
+        if (matches!(policy, CoverPolicy::PlayingOnly | CoverPolicy::Prefetch) || self.bar_thumbnail_enabled())
+            && let Some(detail) = &self.player.current_detail
+        {
+            urls.push(detail.cover_url.clone());
+        }
+        if policy == CoverPolicy::Prefetch {
+            let mode = &self.settings.player.default_play_mode;
+            if let Some(next) = self.queue.peek_next(mode, self.settings.player.repeat_all_from) {
+                urls.push(next.cover_url.clone());
+            }
+        }
+        for url in urls {
+            if !url.is_empty() {
+                self.maybe_load_cover(url);
             }
         }
     }
 
-    /// 异步下载并上传封面到终端（Kitty 图形协议）
+    /// 异步下载封面，按图形协议转换后交给 `CoverBackend`
     pub(crate) fn maybe_load_cover(&mut self, url: String) {
-        if !self.cover.kitty_supported {
+        let Some(protocol) = self.cover.protocol else { return };
+        if self.settings.data_saver {
             return;
         }
         if self.cache.covers.is_ready(&url) || self.cache.covers.is_loading(&url) {
@@ -78,13 +134,11 @@ impl App {
         }
 
         // 超过 10 张时淘汰最旧的一张
-        if self.cache.covers.len() >= 10 {
-            if let Some((_, old_id)) = self.cache.covers.evict_one() {
-                use std::io::Write;
-                let seq = crate::ui::kitty::delete_image(old_id);
-                let _ = std::io::stdout().write_all(&seq);
-                let _ = std::io::stdout().flush();
-            }
+        if self.cache.covers.len() >= 10
+            && let Some((_, old_id)) = self.cache.covers.evict_one()
+            && let Some(backend) = self.cover.backend.as_mut()
+        {
+            let _ = backend.evict(&mut std::io::stdout(), old_id);
         }
 
         let id = self.cache.covers.alloc_id();
@@ -92,8 +146,11 @@ impl App {
 
         let tx = self.msg_tx.clone();
         let url_clone = url.clone();
+        let limit = self.fetch_limits.background.clone();
+        let with_backdrop = self.settings.display.cover_backdrop && protocol.supports_backdrop();
 
         tokio::spawn(async move {
+            let Ok(_permit) = limit.acquire().await else { return };
             let bytes = match reqwest::get(&url_clone).await {
                 Ok(resp) => match resp.bytes().await {
                     Ok(b) => b.to_vec(),
@@ -104,42 +161,42 @@ impl App {
 
             let result = tokio::task::spawn_blocking(move || {
                 let img = image::load_from_memory(&bytes).ok()?;
-                let img = img.resize_to_fill(800, 800, image::imageops::FilterType::Lanczos3);
-                let rgb = img.to_rgb8();
-                let (w, h) = rgb.dimensions();
-                let raw_pixels = rgb.into_raw();
-                // 只上传，不创建 placement（placement 在每帧 draw 后由主循环负责）
-                let seq = crate::ui::kitty::upload_rgb(id, &raw_pixels, w, h);
-                Some(seq)
+                let size = crate::ui::graphics::COVER_PIXELS;
+                let img = img.resize_to_fill(size, size, image::imageops::FilterType::Lanczos3);
+                let raw_pixels = img.to_rgb8().into_raw();
+                // 背景用的调暗副本随同一份数据保存，缩放重传时一并恢复
+                let dimmed = with_backdrop.then(|| {
+                    raw_pixels.iter()
+                        .map(|&c| (c as f32 * BACKDROP_BRIGHTNESS) as u8)
+                        .collect()
+                });
+                // 只准备数据，不创建 placement（placement 在每帧 draw 后由主循环负责）
+                Some(protocol.prepare_cover(id, raw_pixels, dimmed))
             })
             .await;
 
-            if let Ok(Some(upload_seq)) = result {
-                let _ = tx.send(AppMessage::CoverReady { url: url_clone, id, upload_seq });
+            if let Ok(Some(data)) = result {
+                let _ = tx.send(AppMessage::CoverReady { url: url_clone, id, data });
             }
         });
     }
 
-    /// 下载当前选中歌曲的 B 站弹幕并保存为 XML
-    pub(crate) fn fetch_danmaku(&mut self) {
-        let song = if self.player.expanded {
-            let node = self.nav.current().node.clone();
-            let sel = self.nav.current().selected;
-            let browsed = if !node.has_static_children() {
-                self.cache.songs.get(&node).and_then(|s| s.get(sel)).cloned()
-            } else {
-                None
-            };
-            if self.player.follow_playback {
-                self.player.current_detail.clone().or(browsed)
-            } else {
-                browsed.or_else(|| self.player.current_detail.clone())
+    /// 新封面（`incoming` 字节）入缓存前按 `cover_memory_mb` 淘汰旧封面，
+    /// 与数量上限互不影响；单张超出预算时仍保留它本身
+    pub(crate) fn evict_covers_over_budget(&mut self, incoming: usize) {
+        let budget = self.settings.display.cover_memory_mb as usize * 1024 * 1024;
+        while self.cache.covers.total_bytes() + incoming > budget
+            && let Some((_, old_id)) = self.cache.covers.evict_one()
+        {
+            if let Some(backend) = self.cover.backend.as_mut() {
+                let _ = backend.evict(&mut std::io::stdout(), old_id);
             }
-        } else {
-            self.selected_song().cloned()
-        };
+        }
+    }
 
-        let Some(song) = song else {
+    /// 下载当前选中歌曲的 B 站弹幕并保存为 XML
+    pub(crate) fn fetch_danmaku(&mut self) {
+        let Some(song) = self.focused_song() else {
             self.ui.logs.push(crate::ui::log_view::LogLevel::Warn, "无选中歌曲".to_string());
             return;
         };
@@ -0,0 +1,128 @@
+use crate::model::song::PublicSongDetail;
+use crate::player::engine::{self, AudioSource, PreparedTrack};
+
+use super::super::{App, AppMessage};
+use super::describe_error;
+
+impl App {
+    /// 开启淡入淡出（`player.crossfade_secs` > 0）时，当前歌曲开始播放后预先下载队列中的下一首交给引擎。
+    /// 下一首按当前播放模式预览（随机与单曲循环同样适用），与播完后真正切到的歌曲一致
+    pub(crate) fn preload_next_track(&mut self) {
+        self.cancel_preload();
+        if self.settings.player.crossfade_secs == 0 || !self.player.audio_available {
+            return;
+        }
+        let mode = self.settings.player.default_play_mode.clone();
+        let repeat_from = self.settings.player.repeat_all_from;
+        let Some(song_id) = self.queue.peek_next(&mode, repeat_from).map(|s| s.id) else { return };
+        self.player.preload_song_id = Some(song_id);
+
+        let tx = self.msg_tx.clone();
+        let client = self.client.clone();
+        let limit = self.fetch_limits.audio.clone();
+        let replay_gain = self.settings.player.replay_gain;
+        let trim_silence = self.settings.player.trim_silence;
+        tokio::spawn(async move {
+            let Ok(_permit) = limit.acquire().await else { return };
+            let result = async {
+                let detail = client.song_detail_by_id(song_id).await?;
+                let resp = client.get_audio_stream(&detail.audio_url, None).await?.error_for_status()?;
+                let data = resp.bytes().await?.to_vec();
+                anyhow::Ok((detail, data))
+            }
+            .await;
+            // 预载失败只记日志，播完后照常下载下一首
+            let (detail, data) = match result {
+                Ok((detail, data)) if !data.is_empty() => (detail, data),
+                Ok(_) => return,
+                Err(e) => {
+                    let _ = tx.send(AppMessage::Error(format!("预载下一首失败：{}", describe_error(&e))));
+                    return;
+                }
+            };
+            // 解码与静音扫描在阻塞线程池中完成，播放线程只接收解码好的曲目
+            let duration_secs = detail.duration_seconds as u32;
+            let gain = if replay_gain { detail.gain } else { None };
+            let prepared = tokio::task::spawn_blocking(move || {
+                engine::prepare(AudioSource::Buffered(data), duration_secs, gain, trim_silence)
+            })
+            .await;
+            match prepared {
+                Ok(Ok(track)) => {
+                    let _ = tx.send(AppMessage::NextAudioPreloaded { detail, track });
+                }
+                Ok(Err(e)) => {
+                    let _ = tx.send(AppMessage::Error(format!("预载下一首解码失败：{e}")));
+                }
+                Err(_) => {}
+            }
+        });
+    }
+
+    /// 预载的下一首已下载并解码：下一首仍是它时交给引擎
+    pub(crate) fn on_next_audio_preloaded(&mut self, detail: PublicSongDetail, track: PreparedTrack) {
+        if self.player.preload_song_id != Some(detail.id) {
+            return;
+        }
+        let mode = self.settings.player.default_play_mode.clone();
+        let repeat_from = self.settings.player.repeat_all_from;
+        // 下载期间队列或播放模式有变，下一首已不是它
+        if self.queue.peek_next(&mode, repeat_from).map(|s| s.id) != Some(detail.id) {
+            self.player.preload_song_id = None;
+            return;
+        }
+        self.player.engine.preload(track);
+        self.player.preloaded = Some(detail);
+    }
+
+    /// 队列或播放模式改动后调用：预载中或已预载的歌曲不再是下一首时按新的下一首重新预载，
+    /// 以免引擎淡入或无缝接上一首已不该播放的歌
+    pub(crate) fn refresh_preload(&mut self) {
+        let Some(preloading) = self.player.preload_song_id else { return };
+        let mode = self.settings.player.default_play_mode.clone();
+        let repeat_from = self.settings.player.repeat_all_from;
+        if self.queue.peek_next(&mode, repeat_from).map(|s| s.id) != Some(preloading) {
+            self.preload_next_track();
+        }
+    }
+
+    /// 放弃预载（手动切歌、停止播放时），引擎中已预载的数据一并丢弃
+    pub(crate) fn cancel_preload(&mut self) {
+        self.player.preload_song_id = None;
+        if self.player.preloaded.take().is_some() {
+            self.player.engine.clear_preload();
+        }
+    }
+
+    /// 引擎已开始播放预载的下一首（淡入或无缝接上）：推进队列并更新播放状态，不再重新下载。
+    /// 上一首不会再收到 `TrackEnded`，因此不会重复切歌
+    pub(crate) fn on_next_track_started(&mut self) {
+        let Some(detail) = self.player.preloaded.take() else { return };
+        self.player.preload_song_id = None;
+        self.record_track_ended();
+        // 用户已在切歌或下载别的歌曲，以那首为准，它到达后会替换引擎中的播放
+        if self.player.pending_skip.is_some() || self.player.fetching_song_id.is_some() {
+            return;
+        }
+        let mode = self.settings.player.default_play_mode.clone();
+        let repeat_from = self.settings.player.repeat_all_from;
+        // 预载后队列或播放模式有变，引擎放的不是应有的下一首：按正常流程切歌
+        if self.queue.peek_next(&mode, repeat_from).map(|s| s.id) != Some(detail.id) {
+            self.play_next();
+            return;
+        }
+        self.queue.next_with_mode(&mode, repeat_from);
+        self.player.follow_playback = true;
+        self.player.restricted_skips = 0;
+        self.resume_position_ms = None;
+        let song_id = detail.id;
+        self.show_now_playing(detail);
+        if !self.settings.data_saver {
+            self.touch_play_history(song_id);
+        }
+        self.record_play_started();
+        self.schedule_cover_load();
+        self.load_playing_covers();
+        self.preload_next_track();
+    }
+}
@@ -1,23 +1,33 @@
 use mambocore::endpoints::{
     HistoryCursorQuery, PageByUserQuery, PlaylistSearchQuery, RecentQuery, SongSearchQuery,
-    UserSearchQuery,
+    UserSearchQuery, sanitize_query, tag_filter,
 };
 
 use crate::model::song::PublicSongDetail;
+use crate::ui::log_view::LogLevel;
 use crate::ui::navigation::{NavNode, SearchSort};
+use crate::ui::toast::ToastKind;
 
 use super::super::{App, AppMessage, DataPayload};
-use super::{HISTORY_PAGE_SIZE, SEARCH_PAGE_SIZE};
+use super::{HISTORY_PAGE_SIZE, SEARCH_PAGE_SIZE, describe_error, send_api_hint};
+
+/// 启动预取：当前队列项前后各取几首
+const QUEUE_PREFETCH_RADIUS: usize = 2;
 
 impl App {
     // — 搜索 —
 
     pub(crate) fn execute_search(&mut self) {
-        let query = self.search.query.trim().to_string();
+        let query = sanitize_query(&self.search.query);
         let sort = self.search.sort;
         let tx = self.msg_tx.clone();
         let client = self.client.clone();
 
+        self.search_history.record(&query);
+        if let Err(e) = self.search_history.save() {
+            self.ui.logs.push(LogLevel::Error, format!("保存搜索历史失败：{e}"));
+        }
+
         // 清空旧结果
         self.cache.songs.remove(&NavNode::SearchResults);
         self.cache.search_users.clear();
@@ -71,7 +81,8 @@ impl App {
                         NavNode::SearchResults,
                         vec![],
                     )));
-                    let _ = tx.send(AppMessage::Error(e.to_string()));
+                    send_api_hint(&tx, &e);
+                    let _ = tx.send(AppMessage::Error(describe_error(&e)));
                 }
             }
             match users_res {
@@ -80,7 +91,8 @@ impl App {
                 }
                 Err(e) => {
                     let _ = tx.send(AppMessage::DataLoaded(DataPayload::SearchUsers(vec![])));
-                    let _ = tx.send(AppMessage::Error(e.to_string()));
+                    send_api_hint(&tx, &e);
+                    let _ = tx.send(AppMessage::Error(describe_error(&e)));
                 }
             }
             match playlists_res {
@@ -89,7 +101,8 @@ impl App {
                 }
                 Err(e) => {
                     let _ = tx.send(AppMessage::DataLoaded(DataPayload::SearchPlaylists(vec![])));
-                    let _ = tx.send(AppMessage::Error(e.to_string()));
+                    send_api_hint(&tx, &e);
+                    let _ = tx.send(AppMessage::Error(describe_error(&e)));
                 }
             }
         });
@@ -110,12 +123,43 @@ impl App {
         } else if self.cache.loading.contains(node) || self.cache.songs.contains_key(node) {
             return;
         }
+        if self.cache.failed.contains(node) {
+            return;
+        }
+        if node.requires_login() && !self.client.is_authenticated_sync() {
+            self.cache.failed.insert(node.clone());
+            self.show_toast(ToastKind::Warn, t!("toast.login_required").to_string());
+            return;
+        }
+        // 用户投稿列表的 uid：我的投稿取自本地保存的 access token
+        let user_id = match node {
+            NavNode::UserDetail { id } => Some(*id),
+            NavNode::MyUploads => {
+                let token = crate::config::auth_store::load().ok().flatten().map(|a| a.access_token);
+                match token.as_deref().map(crate::config::auth_store::extract_uid_from_token) {
+                    Some(Ok(uid)) => Some(uid),
+                    Some(Err(e)) => {
+                        self.ui.logs.push(LogLevel::Error, format!("无法获取当前用户 uid：{e}"));
+                        self.cache.failed.insert(node.clone());
+                        return;
+                    }
+                    None => {
+                        self.cache.failed.insert(node.clone());
+                        self.show_toast(ToastKind::Warn, t!("toast.login_required").to_string());
+                        return;
+                    }
+                }
+            }
+            _ => None,
+        };
         self.cache.loading.insert(node.clone());
         let node_owned = node.clone();
         let tx = self.msg_tx.clone();
         let client = self.client.clone();
+        let limit = self.fetch_limits.background.clone();
 
         tokio::spawn(async move {
+            let Ok(_permit) = limit.acquire().await else { return };
             // Categories 走单独的 tag 加载流程
             if node_owned == NavNode::Categories {
                 let result = if client.is_authenticated().await {
@@ -129,8 +173,11 @@ impl App {
                         let _ = tx.send(AppMessage::DataLoaded(DataPayload::Tags(names)));
                     }
                     Err(e) => {
-                        let _ = tx.send(AppMessage::DataLoaded(DataPayload::Tags(vec![])));
-                        let _ = tx.send(AppMessage::Error(e.to_string()));
+                        send_api_hint(&tx, &e);
+                        let _ = tx.send(AppMessage::DataLoaded(DataPayload::LoadFailed(
+                            node_owned,
+                            describe_error(&e),
+                        )));
                     }
                 }
                 return;
@@ -143,8 +190,11 @@ impl App {
                         let _ = tx.send(AppMessage::DataLoaded(DataPayload::Playlists(resp.playlists)));
                     }
                     Err(e) => {
-                        let _ = tx.send(AppMessage::DataLoaded(DataPayload::Playlists(vec![])));
-                        let _ = tx.send(AppMessage::Error(e.to_string()));
+                        send_api_hint(&tx, &e);
+                        let _ = tx.send(AppMessage::DataLoaded(DataPayload::LoadFailed(
+                            node_owned,
+                            describe_error(&e),
+                        )));
                     }
                 }
                 return;
@@ -176,7 +226,7 @@ impl App {
                             q: String::new(),
                             limit: Some(SEARCH_PAGE_SIZE),
                             offset: None,
-                            filter: Some(format!("tags = \"{}\"", name)),
+                            filter: Some(tag_filter(name)),
                             sort_by: Some("release_time_desc".to_string()),
                         })
                         .await
@@ -194,10 +244,12 @@ impl App {
                         .await
                         .map(|r| r.songs.into_iter().map(|s| s.into_song_detail()).collect())
                 }
-                NavNode::UserDetail { id } => {
+                NavNode::UserDetail { .. } | NavNode::MyUploads => {
+                    // 两者的 uid 都已在上面解析
+                    let Some(user_id) = user_id else { return };
                     client
                         .songs_by_user(&PageByUserQuery {
-                            user_id: *id,
+                            user_id,
                             page: None,
                             size: Some(HISTORY_PAGE_SIZE as i64),
                         })
@@ -214,42 +266,91 @@ impl App {
                     )));
                 }
                 Err(e) => {
-                    let _ = tx.send(AppMessage::DataLoaded(DataPayload::Songs(
+                    send_api_hint(&tx, &e);
+                    let _ = tx.send(AppMessage::DataLoaded(DataPayload::LoadFailed(
                         node_owned,
-                        vec![],
+                        describe_error(&e),
                     )));
-                    let _ = tx.send(AppMessage::Error(e.to_string()));
                 }
             }
         });
     }
 
-    pub(crate) fn maybe_load_preview_data(&mut self) {
-        let node = self.nav.current().node.clone();
+    /// 预览栏需要动态加载的节点（选中的子节点、标签或歌单）
+    pub(crate) fn preview_data_node(&self) -> Option<NavNode> {
+        let node = &self.nav.current().node;
         let sel = self.nav.current().selected;
         if node.has_static_children() {
-            let children = node.children();
-            if let Some(child) = children.get(sel) {
-                if child.needs_dynamic_data() {
-                    let child = child.clone();
-                    self.load_node_data(&child);
-                }
+            node.children().get(sel).filter(|child| child.needs_dynamic_data()).cloned()
+        } else if *node == NavNode::Categories {
+            // 选中标签的歌曲预览
+            let tag_name = self.cache.tags.as_ref().and_then(|t| t.get(sel))?;
+            Some(NavNode::Tag { name: tag_name.clone() })
+        } else if *node == NavNode::MyPlaylists {
+            // 选中歌单的歌曲预览
+            let pl = self.cache.playlists.as_ref().and_then(|p| p.get(sel))?;
+            Some(NavNode::PlaylistDetail { id: pl.id })
+        } else {
+            None
+        }
+    }
+
+    /// 记录待加载的预览数据（防抖：快速滚动时只加载最终停留的项，
+    /// 实际加载在 PlayerTick 中延迟触发）
+    pub(crate) fn maybe_load_preview_data(&mut self) {
+        let node = self.preview_data_node();
+        if self.settings.display.preview_debounce_ms == 0 {
+            self.cache.pending_preview_load = None;
+            if let Some(node) = node {
+                self.load_node_data(&node);
             }
-        } else if node == NavNode::Categories {
-            // 加载选中标签的歌曲预览
-            if let Some(tag_name) = self.cache.tags.as_ref().and_then(|t| t.get(sel)).cloned() {
-                let tag_node = NavNode::Tag { name: tag_name };
-                self.load_node_data(&tag_node);
+            return;
+        }
+        self.cache.pending_preview_load = node.map(|n| (n, std::time::Instant::now()));
+    }
+
+    /// 防抖时间已过则加载预览数据
+    pub(crate) fn flush_pending_preview_load(&mut self) {
+        let debounce = std::time::Duration::from_millis(self.settings.display.preview_debounce_ms);
+        if let Some((node, t)) = self.cache.pending_preview_load.take() {
+            if t.elapsed() >= debounce {
+                self.load_node_data(&node);
+            } else {
+                self.cache.pending_preview_load = Some((node, t));
             }
-        } else if node == NavNode::MyPlaylists {
-            // 加载选中歌单的歌曲预览
-            if let Some(pl) = self.cache.playlists.as_ref().and_then(|p| p.get(sel)) {
-                let pl_node = NavNode::PlaylistDetail { id: pl.id };
-                self.load_node_data(&pl_node);
+        }
+    }
+
+    /// 终端重新获得焦点时丢弃随时间变化的列表缓存，正在显示的立即重新加载，其余在下次进入时加载
+    pub(crate) fn refresh_time_sensitive(&mut self) {
+        let visible = [Some(self.nav.current().node.clone()), self.preview_data_node()];
+        for node in [NavNode::LatestReleases, NavNode::DailyRecommend, NavNode::WeeklyHot] {
+            if self.cache.loading.contains(&node) {
+                continue;
+            }
+            let cached = self.cache.songs.remove(&node).is_some();
+            let failed = self.cache.failed.remove(&node);
+            if (cached || failed) && visible.contains(&Some(node.clone())) {
+                self.load_node_data(&node);
             }
         }
     }
 
+    /// 按 r 重新加载当前列或预览栏中加载失败的节点
+    pub(crate) fn retry_failed_load(&mut self) {
+        let targets = [Some(self.nav.current().node.clone()), self.preview_data_node()];
+        let mut retried = false;
+        for node in targets.into_iter().flatten() {
+            if self.cache.failed.remove(&node) {
+                self.load_node_data(&node);
+                retried = true;
+            }
+        }
+        if retried {
+            self.ui.logs.push(LogLevel::Info, "重新加载失败的列表".to_string());
+        }
+    }
+
     /// 预览选中歌曲时，若为搜索结果（partial），异步补全完整详情
     pub(crate) fn maybe_fetch_song_detail(&mut self) {
         let node = self.nav.current().node.clone();
@@ -269,8 +370,10 @@ impl App {
             let tx = self.msg_tx.clone();
             let client = self.client.clone();
             let node = node.clone();
+            let limit = self.fetch_limits.background.clone();
 
             tokio::spawn(async move {
+                let Ok(_permit) = limit.acquire().await else { return };
                 if let Ok(detail) = client.song_detail_by_id(song_id).await {
                     let _ = tx.send(AppMessage::SongDetailFetched { node, index: sel, detail });
                 }
@@ -284,7 +387,7 @@ impl App {
             return;
         }
         let sel = self.nav.current().selected;
-        if let Some(item) = self.queue.songs.get(sel) {
+        if let Some(item) = self.queue_index_at(sel).and_then(|i| self.queue.songs.get(i)) {
             let song_id = item.id;
             if self.cache.queue_song_detail.contains_key(&song_id)
                 || self.cache.detail_loading.contains(&song_id)
@@ -294,8 +397,10 @@ impl App {
             self.cache.detail_loading.insert(song_id);
             let tx = self.msg_tx.clone();
             let client = self.client.clone();
+            let limit = self.fetch_limits.background.clone();
 
             tokio::spawn(async move {
+                let Ok(_permit) = limit.acquire().await else { return };
                 if let Ok(detail) = client.song_detail_by_id(song_id).await {
                     let _ = tx.send(AppMessage::SongDetailFetched {
                         node: NavNode::Queue,
@@ -306,4 +411,48 @@ impl App {
             });
         }
     }
+
+    /// 启动后预取当前队列项附近歌曲的完整详情（需开启 `prefetch_queue`）
+    pub(crate) fn prefetch_queue_details(&mut self) {
+        if !self.settings.player.prefetch_queue || self.settings.data_saver {
+            return;
+        }
+        let Some(cur) = self.queue.current_index else { return };
+        let start = cur.saturating_sub(QUEUE_PREFETCH_RADIUS);
+        let end = (cur + QUEUE_PREFETCH_RADIUS + 1).min(self.queue.songs.len());
+
+        let mut ids = Vec::new();
+        for item in &self.queue.songs[start..end] {
+            if self.cache.queue_song_detail.contains_key(&item.id)
+                || self.cache.detail_loading.contains(&item.id)
+                || ids.contains(&item.id)
+            {
+                continue;
+            }
+            ids.push(item.id);
+        }
+        if ids.is_empty() {
+            return;
+        }
+        self.cache.detail_loading.extend(ids.iter().copied());
+
+        for song_id in ids {
+            let tx = self.msg_tx.clone();
+            let client = self.client.clone();
+            let limit = self.fetch_limits.background.clone();
+            tokio::spawn(async move {
+                let Ok(_permit) = limit.acquire().await else { return };
+                // 失败时也要通知，让该歌曲离开 detail_loading，之后还能再次获取
+                let msg = match client.song_detail_by_id(song_id).await {
+                    Ok(detail) => AppMessage::SongDetailFetched {
+                        node: NavNode::Queue,
+                        index: 0,
+                        detail,
+                    },
+                    Err(_) => AppMessage::SongDetailFailed(song_id),
+                };
+                let _ = tx.send(msg);
+            });
+        }
+    }
 }
@@ -0,0 +1,55 @@
+use crate::ui::log_view::LogLevel;
+use crate::ui::navigation::NavNode;
+use crate::ui::toast::ToastKind;
+
+use super::super::App;
+
+impl App {
+    // — 收藏标签 —
+
+    /// 按 t：收藏/取消收藏分类页选中的标签，或当前所在的标签页
+    pub(crate) fn toggle_favorite_tag(&mut self) {
+        let Some(name) = self.favorite_tag_target() else {
+            self.show_toast(ToastKind::Warn, t!("toast.favorite_tag_unsupported").to_string());
+            return;
+        };
+        let key = if self.favorite_tags.toggle(&name) { "toast.tag_favorited" } else { "toast.tag_unfavorited" };
+        self.show_toast(ToastKind::Info, format!("{} {name}", t!(key)));
+        if let Err(e) = self.favorite_tags.save() {
+            self.ui.logs.push(LogLevel::Error, format!("保存收藏标签失败：{e}"));
+        }
+    }
+
+    fn favorite_tag_target(&self) -> Option<String> {
+        match &self.nav.current().node {
+            NavNode::Tag { name } => Some(name.clone()),
+            NavNode::Categories => self.cache.tags.as_ref()
+                .and_then(|t| t.get(self.nav.current().selected))
+                .cloned(),
+            _ => None,
+        }
+    }
+
+    /// 按 Ctrl+T：依次进入下一个收藏标签。已在标签页时替换当前层，反复切换不会加深导航栈
+    pub(crate) fn next_favorite_tag(&mut self) {
+        let current = match &self.nav.current().node {
+            NavNode::Tag { name } => Some(name.clone()),
+            _ => None,
+        };
+        let Some(next) = self.favorite_tags.next_after(current.as_deref()).map(str::to_string) else {
+            self.show_toast(ToastKind::Warn, t!("toast.no_favorite_tags").to_string());
+            return;
+        };
+        self.player.expanded = false;
+        let node = NavNode::Tag { name: next };
+        if current.is_some() {
+            self.nav.pop();
+        }
+        if self.nav.pop_to(&node) {
+            self.ui.scroll_tick = 0;
+            self.after_nav_move();
+        } else {
+            self.push_and_load(node);
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::ui::log_view::LogLevel;
+use crate::ui::toast::ToastKind;
+
+use super::super::App;
+
+impl App {
+    // — 隐藏歌曲 —
+
+    /// 按 X：隐藏选中的歌曲，并立即从已加载的推荐、最新、标签与搜索列表中移除
+    pub(crate) fn hide_selected_song(&mut self) {
+        let Some(song) = self.selected_song().cloned() else {
+            return;
+        };
+        if !self.hidden.hide(&song) {
+            return;
+        }
+        for (node, songs) in self.cache.songs.iter_mut() {
+            if node.is_feed() {
+                self.hidden.filter(songs);
+            }
+        }
+        let current = self.nav.current().node.clone();
+        if current.is_feed() {
+            let len = self.cache.songs.get(&current).map_or(0, Vec::len);
+            let sel = &mut self.nav.current_mut().selected;
+            *sel = (*sel).min(len.saturating_sub(1));
+        }
+        self.show_toast(ToastKind::Info, format!("{} {}", t!("toast.song_hidden"), song.title));
+        self.save_hidden();
+        self.ui.scroll_tick = 0;
+        self.after_nav_move();
+    }
+
+    /// 按 H 打开已隐藏歌曲浮层
+    pub(crate) fn open_hidden(&mut self) {
+        self.ui.show_hidden = true;
+        self.ui.hidden_selected = 0;
+    }
+
+    /// 浮层中按 d：恢复选中的歌曲（已加载的列表需刷新后才会重新出现）
+    pub(crate) fn unhide_selected(&mut self) {
+        if self.ui.hidden_selected >= self.hidden.songs.len() {
+            return;
+        }
+        self.hidden.songs.remove(self.ui.hidden_selected);
+        self.ui.hidden_selected = self.ui.hidden_selected
+            .min(self.hidden.songs.len().saturating_sub(1));
+        self.save_hidden();
+    }
+
+    /// 浮层中按 c：清空隐藏列表
+    pub(crate) fn clear_hidden(&mut self) {
+        if self.hidden.songs.is_empty() {
+            return;
+        }
+        self.hidden.songs.clear();
+        self.ui.hidden_selected = 0;
+        self.show_toast(ToastKind::Info, t!("toast.hidden_cleared").to_string());
+        self.save_hidden();
+    }
+
+    fn save_hidden(&mut self) {
+        if let Err(e) = self.hidden.save() {
+            self.ui.logs.push(LogLevel::Error, format!("保存隐藏列表失败：{e}"));
+        }
+    }
+}
@@ -1,8 +1,54 @@
 mod auth;
+mod autosave;
 mod cover;
+mod crossfade;
 mod data;
+mod favorite_tags;
+mod hidden;
 mod navigation;
+mod pinned;
 mod playback;
+mod report;
+mod share;
+mod stats;
+
+use mambocore::{ApiError, ApiErrorKind};
+use tokio::sync::mpsc::UnboundedSender;
+
+use super::AppMessage;
 
 pub(crate) const SEARCH_PAGE_SIZE: i32 = 30;
 pub(crate) const HISTORY_PAGE_SIZE: i32 = 50;
+
+/// 可识别的后端错误码对应的本地化提示（告诉用户该怎么做），其余错误返回 None
+pub(crate) fn api_hint(err: &anyhow::Error) -> Option<&'static str> {
+    let key = match err.downcast_ref::<ApiError>()?.kind() {
+        ApiErrorKind::RateLimited => "error.rate_limited",
+        ApiErrorKind::Unauthorized => "error.unauthorized",
+        ApiErrorKind::NotFound => "error.not_found",
+        ApiErrorKind::RegionBlocked => "error.region_blocked",
+        ApiErrorKind::Other => return None,
+    };
+    Some(t!(key))
+}
+
+/// 连接失败、超时等网络层错误（请求未得到服务端答复），可以自动重试；服务端明确拒绝的返回 false
+pub(crate) fn is_network_error(err: &anyhow::Error) -> bool {
+    err.downcast_ref::<reqwest::Error>()
+        .is_some_and(|e| e.is_connect() || e.is_timeout() || e.is_request())
+}
+
+/// 日志用的错误描述：可识别时为本地化提示并附原始 `[code] msg`
+pub(crate) fn describe_error(err: &anyhow::Error) -> String {
+    match api_hint(err) {
+        Some(hint) => format!("{hint}（{err}）"),
+        None => err.to_string(),
+    }
+}
+
+/// 可识别的错误额外弹出提示
+pub(crate) fn send_api_hint(tx: &UnboundedSender<AppMessage>, err: &anyhow::Error) {
+    if let Some(hint) = api_hint(err) {
+        let _ = tx.send(AppMessage::ApiHint(hint.to_string()));
+    }
+}
@@ -1,27 +1,124 @@
+use crate::config::settings::RootBack;
 use crate::ui::navigation::{NavNode, SearchType};
+use crate::ui::settings_view::SettingsAction;
+use crate::ui::toast::ToastKind;
 
-use super::super::App;
+use super::super::{App, InputMode, NavFocus};
 
 impl App {
     // — Miller Columns 导航 —
 
     pub(crate) fn after_nav_move(&mut self) {
+        self.cache.last_nav_focus = Some(self.nav_focus());
         self.maybe_load_preview_data();
         self.maybe_fetch_song_detail();
         self.maybe_fetch_queue_detail();
         self.schedule_cover_load();
     }
 
+    /// 列表数据到达后的后处理：只在选中焦点确实变化时才执行 `after_nav_move`。
+    /// 数据消息往往成批到达（预览、预取、搜索的各类结果），其中大多与当前选中项无关；
+    /// 过去每条都会重新检查四类加载并重置预览/封面防抖计时，连续到达时防抖会被一再推迟。
+    /// 例如停在分类列表时到达 31 条 DataLoaded、只有最后一条改变光标下的内容：
+    /// 去重前执行 31 次 `after_nav_move`，现在只执行 1 次
+    pub(crate) fn after_data_loaded(&mut self) {
+        if self.nav_focus().changed_since(self.cache.last_nav_focus.as_ref()) {
+            self.after_nav_move();
+        }
+    }
+
+    fn nav_focus(&self) -> NavFocus {
+        NavFocus {
+            node: self.nav.current().node.clone(),
+            selected: self.nav.current().selected,
+            preview: self.preview_data_node(),
+            song_id: self.selected_song().map(|s| s.id),
+            cover_url: self.current_preview_cover_url(),
+        }
+    }
+
     /// 用户手动改变选中项后的共享后处理
     fn on_selection_changed(&mut self) {
         if self.player.expanded {
             self.player.follow_playback = false;
         }
         self.ui.scroll_tick = 0;
+        self.reset_detail_scroll();
         self.after_nav_move();
     }
 
-    fn push_and_load(&mut self, node: NavNode) {
+    /// 详情内容切换后回到顶部并恢复自动滚动
+    pub(crate) fn reset_detail_scroll(&mut self) {
+        self.player.detail_scroll = 0;
+        self.player.credits_roll_step = Some(0);
+    }
+
+    /// 展开页手动滚动详情，并暂停自动滚动
+    pub(crate) fn scroll_detail(&mut self, delta: i16) {
+        let max = self.player.detail_max_scroll;
+        self.player.detail_scroll = self.player.detail_scroll
+            .min(max)
+            .saturating_add_signed(delta)
+            .min(max);
+        self.player.credits_roll_step = None;
+    }
+
+    /// 每个 tick 推进自动滚动（仅在展开页且开启设置时）
+    pub(crate) fn advance_credits_roll(&mut self) {
+        if !self.player.expanded || !self.settings.display.credits_roll {
+            return;
+        }
+        let Some(step) = self.player.credits_roll_step.as_mut() else { return };
+        *step = step.wrapping_add(1);
+        self.player.detail_scroll =
+            crate::ui::player_view::credits_roll_offset(*step, self.player.detail_max_scroll);
+    }
+
+    /// 跳转到关注歌曲的艺人主页；停留在刚跳转的艺人页时再按则轮换到下一位
+    pub(crate) fn goto_artist(&mut self) {
+        if let Some((uids, idx)) = self.ui.artist_jump.take()
+            && uids.len() > 1
+            && self.nav.current().node == (NavNode::UserDetail { id: uids[idx] })
+        {
+            let next = (idx + 1) % uids.len();
+            self.nav.pop();
+            self.push_and_load(NavNode::UserDetail { id: uids[next] });
+            self.show_toast(ToastKind::Info, format!("{} {}/{}", t!("toast.artist"), next + 1, uids.len()));
+            self.ui.artist_jump = Some((uids, next));
+            return;
+        }
+
+        let Some(song) = self.focused_song() else { return };
+        let mut uids: Vec<i64> = song.artists().iter().filter_map(|m| m.uid).collect();
+        uids.dedup();
+        if uids.is_empty() {
+            // 没有关联主页的艺人时退回到上传者
+            uids.push(song.uploader_uid);
+        }
+        self.player.expanded = false;
+        self.push_and_load(NavNode::UserDetail { id: uids[0] });
+        if uids.len() > 1 {
+            self.show_toast(ToastKind::Info, format!("{} 1/{}", t!("toast.artist"), uids.len()));
+        }
+        self.ui.artist_jump = Some((uids, 0));
+    }
+
+    /// 从任意位置直接跳到播放队列（已在栈中则回退到该层，否则从根节点进入），选中正在播放的歌曲
+    pub(crate) fn goto_queue(&mut self) {
+        self.player.expanded = false;
+        if !self.nav.pop_to(&NavNode::Queue) {
+            self.nav.pop_to(&NavNode::Root);
+            if let Some(pos) = NavNode::Root.children().iter().position(|n| *n == NavNode::Queue) {
+                self.nav.current_mut().selected = pos;
+            }
+            self.nav.push(NavNode::Queue);
+        }
+        self.reselect_queue_index(self.queue.current_index);
+        self.ui.scroll_tick = 0;
+        self.after_nav_move();
+    }
+
+    pub(crate) fn push_and_load(&mut self, node: NavNode) {
         self.load_node_data(&node);
         self.nav.push(node);
         self.ui.scroll_tick = 0;
@@ -78,11 +175,16 @@ impl App {
         let node = self.nav.current().node.clone();
         let sel = self.nav.current().selected;
         if node == NavNode::Settings {
-            crate::ui::settings_view::cycle_setting(&mut self.settings, sel);
-            if sel == 3 {
-                // cover_scale 变化
+            match crate::ui::settings_view::cycle_setting(&mut self.settings, sel) {
+                Some(SettingsAction::TestConnection) => self.test_connection(),
+                Some(SettingsAction::Relogin) => self.relogin(),
+                Some(SettingsAction::RestartEngine) => self.restart_audio_engine(),
+                None => {
+                    let _ = self.settings.save();
+                    // 刚开启播放栏小封面时补下载正在播放的封面
+                    self.load_playing_covers();
+                }
             }
-            let _ = self.settings.save();
             return;
         }
         if node.has_static_children() {
@@ -104,18 +206,19 @@ impl App {
             }
         } else if node == NavNode::Queue {
             // 队列中按 Enter 播放选中歌曲
-            if sel < self.queue.songs.len() {
-                self.queue.current_index = Some(sel);
-                let item = self.queue.songs[sel].clone();
+            if let Some(index) = self.queue_index_at(sel) {
+                self.queue.current_index = Some(index);
+                self.queue.mark_play_start();
+                let item = self.queue.songs[index].clone();
                 self.start_audio_fetch(item.id, &item.name, &item.artist);
             }
         } else if node == NavNode::SearchResults {
             match self.search.search_type {
                 SearchType::Song => {
-                    if let Some(songs) = self.cache.songs.get(&NavNode::SearchResults).cloned() {
-                        if sel < songs.len() {
-                            self.play_from_list(&songs, sel);
-                        }
+                    if let Some(songs) = self.cache.songs.get(&NavNode::SearchResults).cloned()
+                        && sel < songs.len()
+                    {
+                        self.play_selected_in_list(&songs, sel);
                     }
                 }
                 SearchType::Playlist => {
@@ -132,20 +235,68 @@ impl App {
             }
         } else {
             // 当前节点是歌曲列表，按 Enter 播放选中歌曲
-            if let Some(songs) = self.cache.songs.get(&node).cloned() {
-                if sel < songs.len() {
-                    self.play_from_list(&songs, sel);
-                }
+            if let Some(songs) = self.cache.songs.get(&node).cloned()
+                && sel < songs.len()
+            {
+                self.play_selected_in_list(&songs, sel);
             }
         }
     }
 
+    /// h / ←：返回上一级；已在根层时按 `root_back` 设置切换到迷你播放器或展开页，默认不做任何事
     pub(crate) fn nav_drill_out(&mut self) {
+        if self.nav.depth() <= 1 {
+            match self.settings.root_back {
+                RootBack::Ignore => {}
+                RootBack::MiniPlayer => self.toggle_mini_player(),
+                RootBack::PlayerView => self.open_player_view(),
+            }
+            return;
+        }
         self.nav.pop();
         self.ui.scroll_tick = 0;
         self.after_nav_move();
     }
 
+    /// 按 x：丢弃搜索结果及其下钻的层级，清空查询，回到搜索前所在的位置
+    pub(crate) fn clear_search(&mut self) {
+        if !self.nav.remove_from(&NavNode::SearchResults) {
+            return;
+        }
+        self.search.clear();
+        self.cache.songs.remove(&NavNode::SearchResults);
+        self.cache.search_users.clear();
+        self.cache.search_playlists.clear();
+        self.cache.loading.remove(&NavNode::SearchResults);
+        self.cache.failed.remove(&NavNode::SearchResults);
+        self.ui.scroll_tick = 0;
+        self.after_nav_move();
+    }
+
+    /// 正在输入且查询为空时，结果区改为列出最近搜索词
+    pub(crate) fn showing_search_suggestions(&self) -> bool {
+        self.ui.input_mode == InputMode::Search
+            && self.search.query.is_empty()
+            && !self.search_history.terms.is_empty()
+    }
+
+    /// 打开展开播放器视图：有正在播放的歌曲时跟随播放
+    pub(crate) fn open_player_view(&mut self) {
+        self.player.expanded = true;
+        self.player.follow_playback = self.player.current_detail.is_some();
+        self.reset_detail_scroll();
+        self.schedule_cover_load();
+    }
+
+    /// Esc：收起展开页，否则返回上一级；已在根节点时不做任何事
+    pub(crate) fn go_back(&mut self) {
+        if self.player.expanded {
+            self.player.expanded = false;
+        } else if self.nav.depth() > 1 {
+            self.nav_drill_out();
+        }
+    }
+
     pub(crate) fn nav_top(&mut self) {
         self.nav.current_mut().selected = 0;
         self.on_selection_changed();
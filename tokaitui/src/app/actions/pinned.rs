@@ -0,0 +1,83 @@
+use crate::ui::log_view::LogLevel;
+use crate::ui::navigation::{NavNode, SearchType};
+use crate::ui::toast::ToastKind;
+
+use super::super::App;
+
+impl App {
+    // — 固定节点 —
+
+    /// 按 *：固定/取消固定当前节点；当前节点不可固定时作用于选中的标签、歌单或用户
+    pub(crate) fn toggle_pin(&mut self) {
+        let Some(node) = self.pin_target() else {
+            self.show_toast(ToastKind::Warn, t!("toast.pin_unsupported").to_string());
+            return;
+        };
+        let name = node.display_name().to_string();
+        let key = if self.pinned.toggle(node) { "toast.pinned" } else { "toast.unpinned" };
+        self.show_toast(ToastKind::Info, format!("{} {name}", t!(key)));
+        self.save_pinned();
+    }
+
+    fn pin_target(&self) -> Option<NavNode> {
+        let current = &self.nav.current().node;
+        if current.is_pinnable() {
+            return Some(current.clone());
+        }
+        let sel = self.nav.current().selected;
+        match current {
+            NavNode::Categories => self.cache.tags.as_ref()
+                .and_then(|t| t.get(sel))
+                .map(|name| NavNode::Tag { name: name.clone() }),
+            NavNode::MyPlaylists => self.cache.playlists.as_ref()
+                .and_then(|p| p.get(sel))
+                .map(|pl| NavNode::PlaylistDetail { id: pl.id }),
+            NavNode::SearchResults => match self.search.search_type {
+                SearchType::Song => None,
+                SearchType::Playlist => self.cache.search_playlists.get(sel)
+                    .map(|pl| NavNode::PlaylistDetail { id: pl.id }),
+                SearchType::User => self.cache.search_users.get(sel)
+                    .map(|user| NavNode::UserDetail { id: user.uid }),
+            },
+            _ => None,
+        }
+    }
+
+    /// 按 P 打开固定节点浮层
+    pub(crate) fn open_pinned(&mut self) {
+        self.ui.show_pinned = true;
+        self.ui.pinned_selected = 0;
+    }
+
+    /// 浮层中按 Enter：已在导航栈中则回退到该层，否则在当前位置进入
+    pub(crate) fn jump_to_pinned(&mut self) {
+        let Some(node) = self.pinned.nodes.get(self.ui.pinned_selected).cloned() else {
+            return;
+        };
+        self.ui.show_pinned = false;
+        self.player.expanded = false;
+        if self.nav.pop_to(&node) {
+            self.ui.scroll_tick = 0;
+            self.after_nav_move();
+        } else {
+            self.push_and_load(node);
+        }
+    }
+
+    /// 浮层中按 d：取消固定选中项
+    pub(crate) fn unpin_selected(&mut self) {
+        if self.ui.pinned_selected >= self.pinned.nodes.len() {
+            return;
+        }
+        self.pinned.nodes.remove(self.ui.pinned_selected);
+        self.ui.pinned_selected = self.ui.pinned_selected
+            .min(self.pinned.nodes.len().saturating_sub(1));
+        self.save_pinned();
+    }
+
+    fn save_pinned(&mut self) {
+        if let Err(e) = self.pinned.save() {
+            self.ui.logs.push(LogLevel::Error, format!("保存固定节点失败：{e}"));
+        }
+    }
+}
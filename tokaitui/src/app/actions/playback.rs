@@ -1,13 +1,35 @@
-use crate::model::queue::MusicQueueItem;
+use crate::config::settings::{EnterAction, PlayMode};
+use crate::model::{ApiError, ApiErrorKind, WebResp};
+use crate::model::queue::{MusicQueueItem, QueueSort};
 use crate::model::song::PublicSongDetail;
+use crate::player::engine::PlayerEngine;
+use crate::player::partial::{self, Partial};
+use reqwest::StatusCode;
+use reqwest::header::CONTENT_RANGE;
+use crate::ui::log_view::LogLevel;
+use std::time::{Duration, Instant};
+
 use crate::ui::navigation::{NavNode, SearchType};
+use crate::ui::player_bar::{DownloadProgress, LoadPhase, PlayerBarState};
+use crate::ui::toast::ToastKind;
 
 use super::super::{App, AppMessage};
+use super::{describe_error, send_api_hint};
+
+/// 同一首歌在此时间内再次按 `a` 视为误触重复
+const QUEUE_ADD_DEDUP_WINDOW: Duration = Duration::from_secs(3);
+/// 下载进度消息的最小间隔
+const DOWNLOAD_PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+/// 连按 n/N 后停顿多久才开始下载落点歌曲
+const SKIP_SETTLE: Duration = Duration::from_millis(300);
 
 impl App {
     // — 播放控制 —
 
     pub(crate) fn toggle_play_pause(&mut self) {
+        if !self.ensure_engine_alive() {
+            return;
+        }
         if self.player.bar.is_playing {
             self.player.engine.pause();
         } else if self.resume_position_ms.is_some() {
@@ -17,25 +39,240 @@ impl App {
             self.player.engine.resume();
         } else if let Some(song) = self.queue.current_song().cloned() {
             self.start_audio_fetch(song.id, &song.name, &song.artist);
+        } else if self.queue.songs.is_empty() && self.settings.player.space_plays_list {
+            self.play_current_list();
+        }
+    }
+
+    /// 队列为空时按空格：按 Enter 行为播放当前列表的选中项，选中越界时从第一项开始。
+    /// 设置、分类等非歌曲节点以及用户/歌单搜索结果没有可播放的内容
+    fn play_current_list(&mut self) {
+        let node = self.nav.current().node.clone();
+        if node.has_static_children()
+            || (node == NavNode::SearchResults && self.search.search_type != SearchType::Song)
+        {
+            return;
+        }
+        let Some(songs) = self.cache.songs.get(&node).cloned() else { return };
+        if songs.is_empty() {
+            return;
+        }
+        let sel = self.nav.current().selected;
+        let index = if sel < songs.len() { sel } else { 0 };
+        self.play_selected_in_list(&songs, index);
+    }
+
+    /// 播放引擎已退出时提示并返回 false，避免把命令发进已关闭的通道却毫无反馈
+    pub(crate) fn ensure_engine_alive(&mut self) -> bool {
+        // 无音频设备时引擎本就不会运行，只提示仅可浏览
+        if !self.player.audio_available {
+            self.show_toast(ToastKind::Warn, t!("toast.no_audio_device").to_string());
+            return false;
+        }
+        if self.player.engine.is_alive() {
+            return true;
+        }
+        self.show_toast(ToastKind::Warn, t!("toast.engine_dead").to_string());
+        false
+    }
+
+    /// 重建播放引擎；当前歌曲转为恢复模式，按空格从原位置重新加载
+    pub(crate) fn restart_audio_engine(&mut self) {
+        let engine = match PlayerEngine::spawn() {
+            Ok(engine) => engine,
+            Err(e) => {
+                self.ui.logs.push(LogLevel::Error, format!("重启音频引擎失败：{e}"));
+                self.show_toast(ToastKind::Warn, t!("toast.engine_restart_failed").to_string());
+                return;
+            }
+        };
+        engine.set_volume(self.player.volume.gain());
+        engine.set_crossfade(Duration::from_secs(self.settings.player.crossfade_secs.into()));
+        // 预载的数据随旧引擎一起丢弃
+        self.player.preload_song_id = None;
+        self.player.preloaded = None;
+        self.player.engine = engine;
+        self.player.audio_available = true;
+        self.player.engine_generation = self.player.engine_generation.wrapping_add(1);
+        self.forward_player_events();
+
+        self.player.bar.is_playing = false;
+        self.player.bar.phase = LoadPhase::Idle;
+        if self.player.bar.has_song() && self.queue.current_song().is_some() {
+            self.resume_position_ms = Some(self.queue.position_ms);
         }
+        self.ui.logs.push(LogLevel::Info, "音频引擎已重启".to_string());
+        self.show_toast(ToastKind::Info, t!("toast.engine_restarted").to_string());
+    }
+
+    /// 开始播放一首歌时更新播放栏、详情与歌词
+    pub(crate) fn show_now_playing(&mut self, detail: PublicSongDetail) {
+        self.player.bar.title = detail.title.clone();
+        self.player.bar.artist = detail.uploader_name.clone();
+        self.player.bar.total_secs = detail.duration_seconds as u32;
+        self.player.bar.current_secs = 0;
+        self.player.bar.cover_url = detail.cover_url.clone();
+        self.player.bar.codec = detail
+            .audio_url
+            .rsplit('.')
+            .next()
+            .unwrap_or("")
+            .to_string();
+        self.player.parsed_lyrics = crate::ui::lyrics::parse(&detail.lyrics);
+        self.player.current_detail = Some(detail);
+        self.reset_detail_scroll();
+    }
+
+    /// 停止播放并清空正在播放的状态：播放栏、详情、歌词，以及进行中的下载与切歌防抖
+    pub(crate) fn stop_playback(&mut self) {
+        self.cancel_preload();
+        self.player.engine.stop();
+        self.player.bar = PlayerBarState::default();
+        self.player.current_detail = None;
+        self.player.parsed_lyrics = crate::ui::lyrics::ParsedLyrics::Empty;
+        self.player.fetching_song_id = None;
+        self.player.pending_skip = None;
+        self.player.auto_advance = false;
+        self.player.restricted_skips = 0;
+        self.player.expanded = false;
+        self.resume_position_ms = None;
     }
 
     pub(crate) fn play_next(&mut self) {
         let mode = self.settings.player.default_play_mode.clone();
-        if let Some(item) = self.queue.next_with_mode(&mode).cloned() {
+        let repeat_from = self.settings.player.repeat_all_from;
+        if let Some(item) = self.queue.next_with_mode(&mode, repeat_from).cloned() {
             self.player.follow_playback = true;
             self.start_audio_fetch(item.id, &item.name, &item.artist);
+            self.player.auto_advance = true;
+        }
+    }
+
+    /// 歌曲因地区限制无法播放：提示原因；自动切歌时跳到下一首
+    pub(crate) fn on_audio_restricted(&mut self, song_id: i64) {
+        if self.player.fetching_song_id != Some(song_id) {
+            return;
+        }
+        self.player.bar.phase = LoadPhase::Idle;
+        self.player.bar.download = None;
+        self.player.fetching_song_id = None;
+        self.ui.logs.push(LogLevel::Warn, format!("歌曲 {song_id} 在所在地区不可用"));
+        self.show_toast(ToastKind::Warn, t!("toast.region_locked").to_string());
+        if self.player.auto_advance && self.player.restricted_skips < self.queue.songs.len() {
+            self.player.restricted_skips += 1;
+            self.play_next();
+        } else {
+            self.player.restricted_skips = 0;
         }
     }
 
-    pub(crate) fn play_prev(&mut self) {
+    /// 按 n：只移动队列位置，停顿后才下载，连按时跳过的歌曲不会逐首下载
+    pub(crate) fn skip_next(&mut self) {
         let mode = self.settings.player.default_play_mode.clone();
-        if let Some(item) = self.queue.prev_with_mode(&mode).cloned() {
-            self.player.follow_playback = true;
+        let repeat_from = self.settings.player.repeat_all_from;
+        if let Some(item) = self.queue.next_with_mode(&mode, repeat_from).cloned() {
+            self.schedule_skip(item);
+        }
+    }
+
+    /// 按 N：同 `skip_next`，退回上一首
+    pub(crate) fn skip_prev(&mut self) {
+        let mode = self.settings.player.default_play_mode.clone();
+        let repeat_from = self.settings.player.repeat_all_from;
+        if let Some(item) = self.queue.prev_with_mode(&mode, repeat_from).cloned() {
+            self.schedule_skip(item);
+        }
+    }
+
+    fn schedule_skip(&mut self, item: MusicQueueItem) {
+        self.player.follow_playback = true;
+        self.player.bar.phase = LoadPhase::Downloading;
+        self.player.bar.download = None;
+        self.player.bar.title = item.name.clone();
+        self.player.bar.artist = item.artist.clone();
+        // 之前尚未完成的下载作废，到达后不再播放
+        self.player.fetching_song_id = None;
+        self.cancel_preload();
+        self.player.pending_skip = Some((item, Instant::now()));
+    }
+
+    /// PlayerTick 中调用：最后一次切歌后停顿足够久，开始下载落点歌曲
+    pub(crate) fn flush_pending_skip(&mut self) {
+        if self.player.pending_skip.as_ref().is_some_and(|(_, t)| t.elapsed() >= SKIP_SETTLE)
+            && let Some((item, _)) = self.player.pending_skip.take()
+        {
             self.start_audio_fetch(item.id, &item.name, &item.artist);
         }
     }
 
+    /// 随机模式下队列是否按实际播放顺序显示
+    pub(crate) fn shows_shuffle_order(&self) -> bool {
+        matches!(self.settings.player.default_play_mode, PlayMode::Shuffle) && self.ui.queue_shuffle_view
+    }
+
+    /// 队列列表的显示顺序（行 → 队列下标）
+    pub(crate) fn queue_display_order(&self) -> Vec<usize> {
+        if self.shows_shuffle_order() {
+            self.queue.shuffle_order()
+        } else {
+            (0..self.queue.songs.len()).collect()
+        }
+    }
+
+    /// 队列列表第 `row` 行对应的队列下标
+    pub(crate) fn queue_index_at(&self, row: usize) -> Option<usize> {
+        if self.shows_shuffle_order() {
+            self.queue.shuffle_order().get(row).copied()
+        } else {
+            (row < self.queue.songs.len()).then_some(row)
+        }
+    }
+
+    /// 当前在队列页时，选中行对应的队列下标
+    pub(crate) fn selected_queue_index(&self) -> Option<usize> {
+        if self.nav.current().node != NavNode::Queue {
+            return None;
+        }
+        self.queue_index_at(self.nav.current().selected)
+    }
+
+    /// 显示顺序变化后把选中行移回原来那首歌
+    pub(crate) fn reselect_queue_index(&mut self, index: Option<usize>) {
+        if let Some(index) = index
+            && let Some(row) = self.queue_display_order().iter().position(|&i| i == index)
+        {
+            self.nav.current_mut().selected = row;
+        }
+    }
+
+    /// 切换播放模式并写入配置，重启后保持。
+    /// 进出随机模式会改变队列的显示顺序，选中项跟随原来的歌曲
+    pub(crate) fn cycle_play_mode(&mut self) {
+        let selected = self.selected_queue_index();
+        self.settings.player.default_play_mode = self.settings.player.default_play_mode.next();
+        self.reselect_queue_index(selected);
+        // 下一首随播放模式改变，重新预载
+        self.refresh_preload();
+        if let Err(e) = self.settings.save() {
+            self.ui.logs.push(LogLevel::Warn, format!("保存播放模式失败：{e}"));
+        }
+    }
+
+    /// 切换随机模式下队列的显示顺序（播放顺序/队列顺序），选中项保持在同一首歌上
+    pub(crate) fn toggle_queue_order_view(&mut self) {
+        let selected = self.selected_queue_index();
+        self.ui.queue_shuffle_view = !self.ui.queue_shuffle_view;
+        self.reselect_queue_index(selected);
+        let msg = if !matches!(self.settings.player.default_play_mode, PlayMode::Shuffle) {
+            t!("toast.queue_order_not_shuffle")
+        } else if self.ui.queue_shuffle_view {
+            t!("toast.queue_order_shuffle")
+        } else {
+            t!("toast.queue_order_sequential")
+        };
+        self.show_toast(ToastKind::Info, msg.to_string());
+    }
+
     /// 获取当前 Miller Columns 选中的歌曲
     pub(crate) fn selected_song(&self) -> Option<&PublicSongDetail> {
         let node = &self.nav.current().node;
@@ -47,6 +284,26 @@ impl App {
         }
     }
 
+    /// 展开页手动切换跟随播放中歌曲/浏览选中歌曲
+    pub(crate) fn toggle_follow_playback(&mut self) {
+        self.player.follow_playback = !self.player.follow_playback;
+        self.reset_detail_scroll();
+        self.schedule_cover_load();
+    }
+
+    /// 当前关注的歌曲：展开页按跟随状态选择播放中/浏览中歌曲，否则为选中歌曲
+    pub(crate) fn focused_song(&self) -> Option<PublicSongDetail> {
+        if !self.player.expanded {
+            return self.selected_song().cloned();
+        }
+        let browsed = self.selected_song().cloned();
+        if self.player.follow_playback {
+            self.player.current_detail.clone().or(browsed)
+        } else {
+            browsed.or_else(|| self.player.current_detail.clone())
+        }
+    }
+
     pub(crate) fn song_to_queue_item(song: &PublicSongDetail) -> MusicQueueItem {
         MusicQueueItem {
             id: song.id,
@@ -61,18 +318,88 @@ impl App {
         }
     }
 
-    /// 替换队列为歌曲列表并播放指定索引
+    /// 播放列表中的 `songs[index]`：按 `replace_queue_on_play` 替换队列，或插入到当前歌曲之后
     pub(crate) fn play_from_list(&mut self, songs: &[PublicSongDetail], index: usize) {
-        self.queue.clear();
-        for song in songs {
-            self.queue.add(Self::song_to_queue_item(song));
+        if index >= songs.len() {
+            return;
         }
-        self.queue.current_index = Some(index);
+        let items: Vec<_> = songs.iter().map(Self::song_to_queue_item).collect();
+        self.snapshot_queue();
+        if self.settings.player.replace_queue_on_play {
+            self.queue.reshuffle();
+            self.queue.replace_with(items, index);
+        } else {
+            self.queue.insert_list_after_current(items, index);
+        }
+        // 旧的预载已不是下一首；新歌开始播放后会按新队列重新预载
+        self.cancel_preload();
+        self.queue.mark_play_start();
         self.player.follow_playback = true;
         let song = &songs[index];
         self.start_audio_fetch(song.id, &song.title, &song.uploader_name);
     }
 
+    /// `play_from_list` 的变体：只把 `songs[start..]` 放进队列并从 `songs[start]` 开始播放，
+    /// 选中项之前的歌曲不入队。替换还是插入同样按 `replace_queue_on_play`，已在队列中的歌曲不重复插入
+    pub(crate) fn play_list_from(&mut self, songs: &[PublicSongDetail], start: usize) {
+        if let Some(rest) = songs.get(start..) {
+            self.play_from_list(rest, 0);
+        }
+    }
+
+    /// 按 F：从当前列表的选中项播放到列表末尾。历史列表按收听顺序，与 Enter 一致
+    pub(crate) fn play_all_from_here(&mut self) {
+        let node = self.nav.current().node.clone();
+        if node == NavNode::Queue
+            || node.has_static_children()
+            || (node == NavNode::SearchResults && self.search.search_type != SearchType::Song)
+        {
+            return;
+        }
+        let Some(songs) = self.cache.songs.get(&node).cloned() else { return };
+        let sel = self.nav.current().selected;
+        if sel >= songs.len() {
+            return;
+        }
+        if node == NavNode::History {
+            let ordered = history_forward_from(&songs, sel);
+            self.play_from_list(&ordered, 0);
+        } else {
+            self.play_list_from(&songs, sel);
+        }
+    }
+
+    /// 在歌曲列表上按 Enter：按 `enter_action` 设置决定如何处理队列，
+    /// 搜索结果中设置了 `search_enter_action` 时以它为准
+    pub(crate) fn play_selected_in_list(&mut self, songs: &[PublicSongDetail], index: usize) {
+        let Some(song) = songs.get(index) else { return };
+        let node = self.nav.current().node.clone();
+        let action = match self.settings.player.search_enter_action {
+            Some(action) if node == NavNode::SearchResults => action,
+            _ => self.settings.player.enter_action,
+        };
+        match action {
+            EnterAction::PlayList if node == NavNode::History => {
+                let ordered = history_forward_from(songs, index);
+                self.play_from_list(&ordered, 0);
+            }
+            EnterAction::PlayList => self.play_from_list(songs, index),
+            EnterAction::PlayOne => {
+                self.player.follow_playback = true;
+                self.start_audio_fetch(song.id, &song.title, &song.uploader_name);
+            }
+            EnterAction::AddAndPlay => {
+                if !self.queue.songs.iter().any(|q| q.id == song.id) {
+                    self.snapshot_queue();
+                }
+                self.queue.select_or_add(Self::song_to_queue_item(song));
+                self.queue.mark_play_start();
+                self.player.follow_playback = true;
+                self.start_audio_fetch(song.id, &song.title, &song.uploader_name);
+            }
+        }
+    }
+
     /// 播放展开页当前显示的歌曲（如果不是正在播放的那首）
     pub(crate) fn play_expanded_song(&mut self) {
         // 复现 render_player_view 中确定展示歌曲的逻辑
@@ -80,7 +407,7 @@ impl App {
         let sel = self.nav.current().selected;
 
         let browsed_detail = if node == NavNode::Queue {
-            self.queue.songs.get(sel).map(|item| {
+            self.queue_index_at(sel).and_then(|i| self.queue.songs.get(i)).map(|item| {
                 self.cache.queue_song_detail.get(&item.id).cloned()
                     .unwrap_or_else(|| item.to_song_detail())
             })
@@ -106,14 +433,14 @@ impl App {
         let Some(detail) = detail else { return };
 
         // 如果已经在播放这首歌，不重复触发
-        if self.player.current_detail.as_ref().map_or(false, |p| p.id == detail.id) {
+        if self.player.current_detail.as_ref().is_some_and(|p| p.id == detail.id) {
             return;
         }
 
-        // 把当前列表的所有歌曲替换进队列（与 nav_drill_in 行为一致）
+        // 按 Enter 行为设置处理当前列表（与 nav_drill_in 行为一致）
         if let Some(songs) = self.cache.songs.get(&node).cloned() {
             if let Some(idx) = songs.iter().position(|s| s.id == detail.id) {
-                self.play_from_list(&songs, idx);
+                self.play_selected_in_list(&songs, idx);
                 return;
             }
         } else if node == NavNode::Queue {
@@ -125,6 +452,7 @@ impl App {
             // 没有列表上下文，单独加入
             let item = Self::song_to_queue_item(&detail);
             if !self.queue.songs.iter().any(|q| q.id == item.id) {
+                self.snapshot_queue();
                 self.queue.add(item);
             }
             self.queue.current_index = self.queue.songs.iter().position(|q| q.id == detail.id);
@@ -134,10 +462,73 @@ impl App {
     }
 
     pub(crate) fn add_selected_to_queue(&mut self) {
-        if let Some(song) = self.selected_song().cloned() {
-            let item = Self::song_to_queue_item(&song);
-            self.queue.add(item);
+        let Some(song) = self.selected_song().cloned() else { return };
+
+        // 短时间内重复添加同一首歌，多半是连按导致，提示而不重复入队
+        let now = Instant::now();
+        if let Some((last_id, at)) = self.ui.last_queue_add
+            && last_id == song.id
+            && now.duration_since(at) < QUEUE_ADD_DEDUP_WINDOW
+        {
+            self.ui.last_queue_add = Some((song.id, now));
+            self.show_toast(
+                ToastKind::Warn,
+                format!("{}: {}", t!("toast.queue_already_added"), song.title),
+            );
+            return;
         }
+
+        self.snapshot_queue();
+        self.queue.add(Self::song_to_queue_item(&song));
+        self.refresh_preload();
+        self.ui.last_queue_add = Some((song.id, now));
+        self.show_toast(
+            ToastKind::Info,
+            format!("{}: {} ({})", t!("toast.queue_added"), song.title, self.queue.songs.len()),
+        );
+    }
+
+    /// 将当前列表（或预览中的标签/歌单）的全部歌曲追加到队列，已在队列中的歌曲跳过
+    pub(crate) fn add_list_to_queue(&mut self) {
+        let node = self.nav.current().node.clone();
+        if node == NavNode::Queue {
+            return;
+        }
+        let list_node = if self.cache.songs.contains_key(&node) {
+            Some(node)
+        } else {
+            self.preview_data_node()
+        };
+        let Some(songs) = list_node.and_then(|n| self.cache.songs.get(&n)) else { return };
+        if songs.is_empty() {
+            return;
+        }
+
+        let mut queued: std::collections::HashSet<i64> =
+            self.queue.songs.iter().map(|item| item.id).collect();
+        let snapshot = self.queue.clone();
+        let mut added = 0;
+        let mut skipped = 0;
+        for song in songs {
+            if queued.insert(song.id) {
+                self.queue.add(Self::song_to_queue_item(song));
+                added += 1;
+            } else {
+                skipped += 1;
+            }
+        }
+        self.ui.last_queue_add = None;
+        if added > 0 {
+            self.queue_undo.push(&snapshot);
+            self.refresh_preload();
+        }
+
+        let mut message = format!("{}: {added} ({})", t!("toast.queue_list_added"), self.queue.songs.len());
+        if skipped > 0 {
+            message.push_str(&format!(" · {} {skipped}", t!("toast.queue_list_skipped")));
+        }
+        let kind = if added > 0 { ToastKind::Info } else { ToastKind::Warn };
+        self.show_toast(kind, message);
     }
 
     pub(crate) fn remove_from_queue(&mut self) {
@@ -145,8 +536,10 @@ impl App {
             return;
         }
         let sel = self.nav.current().selected;
-        if sel < self.queue.songs.len() {
-            self.queue.remove(sel);
+        if let Some(index) = self.queue_index_at(sel) {
+            self.snapshot_queue();
+            self.queue.remove(index);
+            self.refresh_preload();
             // 修正选中索引
             let len = self.queue.songs.len();
             if len == 0 {
@@ -157,14 +550,93 @@ impl App {
         }
     }
 
-    /// 异步获取歌曲详情 → 下载音频 → 发送 AudioFetched
-    pub(crate) fn start_audio_fetch(&mut self, song_id: i64, title: &str, artist: &str) {
-        self.resume_position_ms = None; // 新歌播放时清除恢复位置
-        self.player.bar.is_loading = true;
-        self.player.bar.title = title.to_string();
-        self.player.bar.artist = artist.to_string();
+    /// 将队列中选中的歌曲直接移到队首或队尾，选中项跟随移动
+    pub(crate) fn move_queue_selected(&mut self, to_top: bool) {
+        if self.nav.current().node != NavNode::Queue {
+            return;
+        }
+        // 随机顺序视图中行号不是队列位置，移动没有直观效果
+        if self.shows_shuffle_order() {
+            self.show_toast(ToastKind::Warn, t!("toast.queue_move_shuffle_view").to_string());
+            return;
+        }
+        let sel = self.nav.current().selected;
+        let target = if to_top { 0 } else { self.queue.songs.len().saturating_sub(1) };
+        let snapshot = self.queue.clone();
+        if self.queue.move_to(sel, target) {
+            self.queue_undo.push(&snapshot);
+            self.refresh_preload();
+            self.nav.current_mut().selected = target;
+            self.after_nav_move();
+        }
+    }
 
-        // 记录播放历史，并使缓存失效以便下次进入时刷新
+    /// 队列中按 O：依次按标题、艺人、时长排序，正在播放与选中的歌曲保持不变
+    pub(crate) fn sort_queue(&mut self) {
+        if self.nav.current().node != NavNode::Queue || self.queue.songs.is_empty() {
+            return;
+        }
+        let sort = self.ui.queue_sort.map_or(QueueSort::Title, QueueSort::next);
+        let selected_id = self.selected_queue_index().map(|i| self.queue.songs[i].id);
+        self.snapshot_queue();
+        self.queue.sort_by(sort);
+        self.refresh_preload();
+        self.ui.queue_sort = Some(sort);
+        let index = selected_id.and_then(|id| self.queue.songs.iter().position(|s| s.id == id));
+        self.reselect_queue_index(index);
+        self.after_nav_move();
+
+        let label = match sort {
+            QueueSort::Title => t!("queue.sort.title"),
+            QueueSort::Artist => t!("queue.sort.artist"),
+            QueueSort::Duration => t!("queue.sort.duration"),
+        };
+        self.show_toast(ToastKind::Info, format!("{} {label}", t!("toast.queue_sorted")));
+    }
+
+    /// 队列改动前调用，记录撤销快照
+    pub(crate) fn snapshot_queue(&mut self) {
+        self.queue_undo.push(&self.queue);
+    }
+
+    /// 按 U：恢复最近一次改动前的队列。正在播放的歌曲仍在恢复后的队列中时继续指向它，播放不中断
+    pub(crate) fn undo_queue(&mut self) {
+        let Some(mut restored) = self.queue_undo.pop() else {
+            self.show_toast(ToastKind::Warn, t!("toast.queue_undo_empty").to_string());
+            return;
+        };
+        if let Some(id) = self.queue.current_song().map(|s| s.id)
+            && let Some(index) = restored.songs.iter().position(|s| s.id == id)
+        {
+            restored.current_index = Some(index);
+        }
+        restored.position_ms = self.queue.position_ms;
+        self.queue = restored;
+        self.refresh_preload();
+
+        if self.nav.current().node == NavNode::Queue {
+            let len = self.queue.songs.len();
+            let sel = self.nav.current().selected;
+            self.nav.current_mut().selected = sel.min(len.saturating_sub(1));
+            self.after_nav_move();
+        }
+        self.show_toast(
+            ToastKind::Info,
+            format!("{} ({})", t!("toast.queue_undone"), self.queue.songs.len()),
+        );
+    }
+
+    /// 上报播放历史；`debug.dry_run` 时只在日志中记录本应发送的请求
+    pub(crate) fn touch_play_history(&mut self, song_id: i64) {
+        if self.settings.debug.dry_run {
+            let path = if self.client.is_authenticated_sync() {
+                "/play_history/touch"
+            } else {
+                "/play_history/touch_anonymous"
+            };
+            self.ui.logs.push(LogLevel::Info, format!("[dry-run] 未发送 POST {path} {{\"song_id\": {song_id}}}"));
+            return;
+        }
         self.cache.songs.remove(&NavNode::History);
         let history_client = self.client.clone();
         tokio::spawn(async move {
@@ -174,17 +646,49 @@ impl App {
                 let _ = history_client.touch_play_history_anonymous(song_id).await;
             }
         });
+    }
+
+    /// 异步获取歌曲详情 → 下载音频 → 发送 AudioFetched
+    pub(crate) fn start_audio_fetch(&mut self, song_id: i64, title: &str, artist: &str) {
+        // 无音频设备时不必下载
+        if !self.player.audio_available {
+            self.show_toast(ToastKind::Warn, t!("toast.no_audio_device").to_string());
+            return;
+        }
+        self.resume_position_ms = None; // 新歌播放时清除恢复位置
+        self.cancel_preload();
+        self.player.pending_skip = None;
+        self.player.auto_advance = false;
+        self.player.bar.phase = LoadPhase::Downloading;
+        self.player.bar.download = None;
+        self.player.fetching_song_id = Some(song_id);
+        self.player.bar.title = title.to_string();
+        self.player.bar.artist = artist.to_string();
+
+        // 记录播放历史，并使缓存失效以便下次进入时刷新（省流模式下不上报）
+        if !self.settings.data_saver {
+            self.touch_play_history(song_id);
+        }
 
         let tx = self.msg_tx.clone();
         let client = self.client.clone();
+        let indicator_after = Duration::from_millis(self.settings.player.buffering_indicator_ms);
+        let limit = self.fetch_limits.audio.clone();
 
         tokio::spawn(async move {
+            let Ok(_permit) = limit.acquire().await else { return };
+            let started = Instant::now();
             // 第一步：获取歌曲详情拿到 audio_url
             let detail = match client.song_detail_by_id(song_id).await {
                 Ok(d) => d,
+                Err(e) if e.downcast_ref::<ApiError>().is_some_and(|a| a.kind() == ApiErrorKind::RegionBlocked) => {
+                    let _ = tx.send(AppMessage::AudioRestricted(song_id));
+                    return;
+                }
                 Err(e) => {
+                    send_api_hint(&tx, &e);
                     let _ = tx.send(AppMessage::AudioFetchError(
-                        format!("获取歌曲详情失败: {e}"),
+                        format!("获取歌曲详情失败: {}", describe_error(&e)),
                     ));
                     return;
                 }
@@ -197,38 +701,101 @@ impl App {
                 return;
             }
 
-            // 第二步：下载音频数据
+            // 第二步：下载音频数据；有上次中断留下的部分数据时用 Range 续传
             let audio_url = &detail.audio_url;
-            match client.get_audio_stream(audio_url).await {
-                Ok(resp) => {
+            let partial = partial::load(song_id);
+            let mut result = client.get_audio_stream(audio_url, partial.as_ref().map(|p| p.data.len() as u64)).await;
+            let mut refetch = false;
+            let resumed = match (&result, partial) {
+                (Ok(resp), Some(p)) if resumes(resp, &p) => Some(p),
+                (Ok(resp), Some(_)) => {
+                    // 服务端不支持 Range 或文件已变化：丢弃部分数据；200 已是完整内容，其余情况整首重新请求
+                    partial::remove(song_id);
+                    refetch = resp.status() != StatusCode::OK;
+                    None
+                }
+                // 请求失败时保留部分数据，下次再续传
+                _ => None,
+            };
+            if refetch {
+                result = client.get_audio_stream(audio_url, None).await;
+            }
+            match result {
+                Ok(mut resp) => {
                     let status = resp.status();
                     if !status.is_success() {
                         let body = resp.text().await.unwrap_or_default();
+                        if is_region_locked(status, &body) {
+                            let _ = tx.send(AppMessage::AudioRestricted(song_id));
+                            return;
+                        }
+                        // 403 也可能是音频地址过期或鉴权问题，不当作地区限制，也不自动跳过
+                        if status == StatusCode::FORBIDDEN {
+                            let _ = tx.send(AppMessage::ApiHint(t!("toast.access_denied").to_string()));
+                        }
                         let _ = tx.send(AppMessage::AudioFetchError(
                             format!("音频请求返回 {status}: {body}"),
                         ));
                         return;
                     }
 
-                    match resp.bytes().await {
-                        Ok(bytes) => {
-                            if bytes.is_empty() {
+                    // 分块读取，超过显示阈值后定期上报下载进度
+                    let was_resumed = resumed.is_some();
+                    let (mut data, total) = match resumed {
+                        Some(p) => (p.data, Some(p.total)),
+                        None => (Vec::new(), resp.content_length()),
+                    };
+                    data.reserve(total.unwrap_or(0).saturating_sub(data.len() as u64) as usize);
+                    // 上次上报的时间与已接收字节，用于计算区间速度
+                    let mut last_report: Option<(Instant, u64)> = None;
+                    let stream_started = Instant::now();
+                    let resumed_from = data.len() as u64;
+                    loop {
+                        match resp.chunk().await {
+                            Ok(Some(chunk)) => {
+                                data.extend_from_slice(&chunk);
+                                let now = Instant::now();
+                                if now.duration_since(started) >= indicator_after
+                                    && last_report.is_none_or(|(t, _)| now.duration_since(t) >= DOWNLOAD_PROGRESS_INTERVAL)
+                                {
+                                    let received = data.len() as u64;
+                                    let (since, base) = last_report.unwrap_or((stream_started, resumed_from));
+                                    let secs = now.duration_since(since).as_secs_f64();
+                                    let bytes_per_sec = if secs > 0.0 {
+                                        ((received - base) as f64 / secs) as u64
+                                    } else {
+                                        0
+                                    };
+                                    last_report = Some((now, received));
+                                    let _ = tx.send(AppMessage::AudioDownloadProgress {
+                                        song_id,
+                                        progress: DownloadProgress { received, total, bytes_per_sec },
+                                    });
+                                }
+                            }
+                            Ok(None) => break,
+                            Err(e) => {
+                                if let Some(total) = total {
+                                    let _ = partial::save(song_id, &data, total);
+                                }
                                 let _ = tx.send(AppMessage::AudioFetchError(
-                                    "音频数据为空".to_string(),
+                                    format!("下载音频失败: {e}"),
                                 ));
                                 return;
                             }
-                            let _ = tx.send(AppMessage::AudioFetched {
-                                detail,
-                                data: bytes.to_vec(),
-                            });
-                        }
-                        Err(e) => {
-                            let _ = tx.send(AppMessage::AudioFetchError(
-                                format!("下载音频失败: {e}"),
-                            ));
                         }
                     }
+
+                    if data.is_empty() {
+                        let _ = tx.send(AppMessage::AudioFetchError(
+                            "音频数据为空".to_string(),
+                        ));
+                        return;
+                    }
+                    if was_resumed {
+                        partial::remove(song_id);
+                    }
+                    let _ = tx.send(AppMessage::AudioFetched { detail, data });
                 }
                 Err(e) => {
                     let _ = tx.send(AppMessage::AudioFetchError(
@@ -239,3 +806,35 @@ impl App {
         });
     }
 }
+
+/// 音频请求被拒绝是否因为地区限制：451，或响应体中的地区限制错误码
+fn is_region_locked(status: StatusCode, body: &str) -> bool {
+    status == StatusCode::UNAVAILABLE_FOR_LEGAL_REASONS
+        || serde_json::from_str::<WebResp<serde_json::Value>>(body)
+            .ok()
+            .and_then(|web| web.into_result().err())
+            .is_some_and(|e| e.kind() == ApiErrorKind::RegionBlocked)
+}
+
+/// 续传请求的响应是否正好接在已有数据之后，且完整长度与记录一致
+fn resumes(resp: &reqwest::Response, partial: &Partial) -> bool {
+    resp.status() == StatusCode::PARTIAL_CONTENT
+        && resp
+            .headers()
+            .get(CONTENT_RANGE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(partial::parse_content_range)
+            == Some((partial.data.len() as u64, partial.total))
+}
+
+/// 历史列表按时间倒序排列：从选中项开始，按当时的收听顺序向更新的记录排队。
+/// 同一首歌只保留第一次出现，避免重复播放造成的杂乱队列。
+fn history_forward_from(songs: &[PublicSongDetail], index: usize) -> Vec<PublicSongDetail> {
+    let mut seen = std::collections::HashSet::new();
+    songs[..=index]
+        .iter()
+        .rev()
+        .filter(|s| seen.insert(s.id))
+        .cloned()
+        .collect()
+}
@@ -0,0 +1,145 @@
+use std::fmt::Write;
+
+use crate::ui::i18n::Lang;
+use crate::ui::log_view::LogLevel;
+use crate::ui::toast::ToastKind;
+
+use super::super::App;
+
+/// 报告中附带的最近日志条数
+const REPORT_LOG_LINES: usize = 30;
+
+impl App {
+    // — 调试报告 —
+
+    /// 汇总版本、终端、配置与最近日志，脱敏后写入文件，便于提交 issue
+    pub(crate) fn export_debug_report(&mut self) {
+        let report = self.build_debug_report();
+        let result = crate::config::paths::debug_report_file()
+            .and_then(|path| std::fs::write(&path, report).map(|_| path).map_err(Into::into));
+        match result {
+            Ok(path) => self.ui.logs.push(
+                LogLevel::Info,
+                format!("调试报告已保存：{}", path.display()),
+            ),
+            Err(e) => self.ui.logs.push(LogLevel::Error, format!("调试报告保存失败：{e}")),
+        }
+    }
+
+    /// 帮助面板中按 e：把快捷键速查表写入 `cheatsheet_path`（默认为配置目录下的 keybindings.md）
+    pub(crate) fn export_cheatsheet(&mut self) {
+        let path = match &self.settings.cheatsheet_path {
+            Some(p) => Ok(std::path::PathBuf::from(p)),
+            None => crate::config::paths::cheatsheet_file(),
+        };
+        let result = path.and_then(|path| {
+            std::fs::write(&path, crate::ui::help::cheatsheet_markdown())
+                .map(|_| path)
+                .map_err(Into::into)
+        });
+        match result {
+            Ok(path) => {
+                self.ui.logs.push(LogLevel::Info, format!("快捷键速查表已保存：{}", path.display()));
+                self.show_toast(ToastKind::Info, t!("toast.cheatsheet_saved").to_string());
+            }
+            Err(e) => self.ui.logs.push(LogLevel::Error, format!("快捷键速查表保存失败：{e}")),
+        }
+    }
+
+    fn build_debug_report(&self) -> String {
+        let mut out = String::new();
+        let env = |key: &str| std::env::var(key).unwrap_or_else(|_| "-".to_string());
+
+        let _ = writeln!(out, "# tokaitui debug report");
+        let _ = writeln!(out, "generated: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S %z"));
+        let _ = writeln!(out, "version: {}", env!("CARGO_PKG_VERSION"));
+        let _ = writeln!(out, "os: {} {}", std::env::consts::OS, std::env::consts::ARCH);
+        let _ = writeln!(out);
+
+        let _ = writeln!(out, "## terminal");
+        let _ = writeln!(out, "TERM: {}", env("TERM"));
+        let _ = writeln!(out, "TERM_PROGRAM: {}", env("TERM_PROGRAM"));
+        let _ = writeln!(out, "KITTY_WINDOW_ID: {}", if std::env::var("KITTY_WINDOW_ID").is_ok() { "set" } else { "-" });
+        let _ = writeln!(out, "TMUX: {}", if std::env::var("TMUX").is_ok() { "set" } else { "-" });
+        let _ = writeln!(out, "graphics: {}", self.cover.protocol.map_or("off", |p| p.name()));
+        if let Ok((cols, rows)) = crossterm::terminal::size() {
+            let _ = writeln!(out, "size: {cols}x{rows}");
+        }
+        let _ = writeln!(out);
+
+        let _ = writeln!(out, "## session");
+        let lang = match self.settings.display.language {
+            Lang::En => "en",
+            Lang::Zh => "zh",
+        };
+        let _ = writeln!(out, "language: {lang}");
+        let _ = writeln!(out, "authenticated: {}", self.client.is_authenticated_sync());
+        let _ = writeln!(out, "queue: {} songs, current {:?}", self.queue.songs.len(), self.queue.current_index);
+        let _ = writeln!(out);
+
+        let _ = writeln!(out, "## config.toml");
+        match toml::to_string_pretty(&self.settings) {
+            Ok(s) => out.push_str(&s),
+            Err(e) => {
+                let _ = writeln!(out, "<serialize error: {e}>");
+            }
+        }
+        let _ = writeln!(out);
+
+        let _ = writeln!(out, "## recent logs");
+        let skip = self.ui.logs.entries.len().saturating_sub(REPORT_LOG_LINES);
+        for entry in self.ui.logs.entries.iter().skip(skip) {
+            let level = match entry.level {
+                LogLevel::Error => "ERROR",
+                LogLevel::Warn => "WARN",
+                LogLevel::Info => "INFO",
+            };
+            let _ = writeln!(out, "[{}] [{level}] {}", entry.time.format("%H:%M:%S"), entry.message);
+        }
+
+        self.redact(out)
+    }
+
+    /// 移除报告中可能出现的凭据：已知 token / 密码 / 邮箱原文，以及任何形似 JWT 的片段
+    fn redact(&self, text: String) -> String {
+        let mut secrets: Vec<String> = Vec::new();
+        if let Ok(Some(auth)) = crate::config::auth_store::load() {
+            secrets.push(auth.access_token);
+            secrets.push(auth.refresh_token);
+        }
+        secrets.push(self.login.password.clone());
+        secrets.push(self.login.email.trim().to_string());
+
+        let mut text = text;
+        for secret in secrets.iter().filter(|s| !s.is_empty()) {
+            text = text.replace(secret.as_str(), "<redacted>");
+        }
+        redact_jwt_like(&text)
+    }
+}
+
+/// 将形如 `xxx.yyy.zzz`（base64url 三段）的片段替换为 `<redacted>`
+fn redact_jwt_like(text: &str) -> String {
+    let is_token_char = |c: char| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '=');
+    let mut out = String::with_capacity(text.len());
+    let mut word = String::new();
+    let flush = |word: &mut String, out: &mut String| {
+        let parts: Vec<&str> = word.split('.').collect();
+        if parts.len() == 3 && parts.iter().all(|p| p.len() >= 8) {
+            out.push_str("<redacted>");
+        } else {
+            out.push_str(word);
+        }
+        word.clear();
+    };
+    for c in text.chars() {
+        if is_token_char(c) {
+            word.push(c);
+        } else {
+            flush(&mut word, &mut out);
+            out.push(c);
+        }
+    }
+    flush(&mut word, &mut out);
+    out
+}
@@ -0,0 +1,99 @@
+use crate::ui::log_view::LogLevel;
+use crate::ui::toast::ToastKind;
+
+use super::super::App;
+
+/// 网页版歌曲页地址前缀，后接 display_id
+const SONG_PAGE_URL: &str = "https://hachimi.world/song/";
+
+/// 剪贴板走 OSC 52，终端不支持时静默忽略（调用方负责写日志兜底）
+pub(super) fn copy_to_clipboard(text: &str) {
+    use std::io::Write;
+    let _ = std::io::stdout().write_all(&crate::ui::clipboard::osc52_copy(text));
+    let _ = std::io::stdout().flush();
+}
+
+impl App {
+    // — 分享 —
+
+    /// 复制带当前播放位置的分享文本：剪贴板走 OSC 52，同时写入日志作为兜底
+    pub(crate) fn copy_share_moment(&mut self) {
+        if !self.player.bar.has_song() {
+            self.show_toast(ToastKind::Warn, t!("player.no_song").to_string());
+            return;
+        }
+
+        let text = self.share_moment_text();
+        copy_to_clipboard(&text);
+        self.ui.logs.push(LogLevel::Info, format!("分享：{text}"));
+        self.show_toast(ToastKind::Info, t!("toast.share_copied").to_string());
+    }
+
+    /// 复制关注歌曲的 id / display_id（附标题与上传者），便于反馈问题；同样写入日志兜底
+    pub(crate) fn copy_song_ids(&mut self) {
+        let Some(song) = self.focused_song() else {
+            return;
+        };
+        let text = format!(
+            "id={} display_id={} \u{2014} {} / {}",
+            song.id, song.display_id, song.title, song.uploader_name,
+        );
+        copy_to_clipboard(&text);
+        self.ui.logs.push(LogLevel::Info, format!("歌曲 ID：{text}"));
+        self.show_toast(ToastKind::Info, t!("toast.ids_copied").to_string());
+    }
+
+    fn share_moment_text(&self) -> String {
+        let bar = &self.player.bar;
+        let secs = bar.current_secs;
+        let mut text = format!(
+            "Now at {}:{:02} of {} \u{2014} {}",
+            secs / 60,
+            secs % 60,
+            bar.title,
+            bar.artist,
+        );
+        // 仅当详情与播放栏是同一首时附带链接（切歌加载中可能尚未更新）
+        if let Some(detail) = &self.player.current_detail
+            && detail.title == bar.title
+            && !detail.display_id.is_empty()
+        {
+            text.push(' ');
+            text.push_str(SONG_PAGE_URL);
+            text.push_str(&detail.display_id);
+        }
+        text
+    }
+
+    // — 外部链接 —
+
+    /// 按 o：只有一条链接时直接打开，多条时等待下一次数字键选择
+    pub(crate) fn begin_open_link(&mut self) {
+        let count = self.selected_song().map_or(0, |s| s.external_links.len());
+        match count {
+            0 => {}
+            1 => self.open_external_link(0),
+            n => {
+                self.ui.pending_link_open = true;
+                self.show_toast(ToastKind::Info, format!("{} (1-{n})", t!("toast.pick_link")));
+            }
+        }
+    }
+
+    /// 打开选中歌曲的第 `index` 条外部链接（从 0 开始），越界时忽略
+    pub(crate) fn open_external_link(&mut self, index: usize) {
+        let url = self
+            .selected_song()
+            .and_then(|s| s.external_links.get(index))
+            .map(|link| link.url.clone());
+        if let Some(url) = url {
+            self.open_url(&url);
+        }
+    }
+
+    pub(crate) fn open_url(&mut self, url: &str) {
+        if let Err(e) = open::that(url) {
+            self.ui.logs.push(LogLevel::Error, format!("打开链接失败：{e}"));
+        }
+    }
+}
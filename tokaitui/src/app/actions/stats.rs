@@ -0,0 +1,43 @@
+use crate::ui::log_view::LogLevel;
+
+use super::super::App;
+
+/// 相邻两次进度事件的位置差超过该值视为 seek，不计入收听时长
+const MAX_PROGRESS_STEP_SECS: u32 = 2;
+
+impl App {
+    // — 收听统计 —
+
+    /// 新歌开始播放
+    pub(crate) fn record_play_started(&mut self) {
+        self.stats.all_time.plays += 1;
+        self.stats.session.plays += 1;
+        self.stats.last_position_secs = None;
+    }
+
+    /// 根据播放进度累计实际收听时长（暂停时无进度事件，seek 造成的跳变不计）
+    pub(crate) fn record_progress(&mut self, position_secs: u32) {
+        if let Some(last) = self.stats.last_position_secs
+            && position_secs > last
+            && position_secs - last <= MAX_PROGRESS_STEP_SECS
+        {
+            let delta = (position_secs - last) as u64;
+            self.stats.all_time.listened_secs += delta;
+            self.stats.session.listened_secs += delta;
+        }
+        self.stats.last_position_secs = Some(position_secs);
+    }
+
+    /// 歌曲播放到结尾，顺带持久化
+    pub(crate) fn record_track_ended(&mut self) {
+        self.stats.all_time.completed += 1;
+        self.stats.session.completed += 1;
+        self.save_stats();
+    }
+
+    pub(crate) fn save_stats(&mut self) {
+        if let Err(e) = self.stats.all_time.save() {
+            self.ui.logs.push(LogLevel::Error, format!("保存收听统计失败：{e}"));
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use crate::player::engine::PlayerEvent;
+
+use super::AppMessage;
+
+/// 每帧最多处理的消息数。通道本身仍是无界的（终端读取线程与各后台任务都同步发送），
+/// 积压超过这个数时剩余消息留到下一帧，洪泛期间界面照常刷新，按键不会排在大量后台结果之后
+pub const MAX_BATCH: usize = 256;
+
+/// 可合并的高频消息：同类只需要最新的一条
+#[derive(PartialEq, Eq)]
+enum CoalesceKey {
+    Tick,
+    Progress,
+    Download(i64),
+}
+
+fn coalesce_key(msg: &AppMessage) -> Option<CoalesceKey> {
+    match msg {
+        AppMessage::PlayerTick => Some(CoalesceKey::Tick),
+        AppMessage::PlayerStateChanged(PlayerEvent::Progress { .. }) => Some(CoalesceKey::Progress),
+        AppMessage::AudioDownloadProgress { song_id, .. } => Some(CoalesceKey::Download(*song_id)),
+        _ => None,
+    }
+}
+
+/// 合并一批消息中的 `PlayerTick`、播放进度与同一首歌的下载进度：
+/// 在两条不可合并的消息之间，每类只保留最后一条。其余消息原样保留且顺序不变，
+/// 因此切歌、播完等事件前后的进度不会互相覆盖
+pub fn coalesce(batch: Vec<AppMessage>) -> Vec<AppMessage> {
+    let mut kept = Vec::with_capacity(batch.len());
+    // 从后往前扫描，`seen` 为当前这一段已保留最新一条的类别
+    let mut seen: Vec<CoalesceKey> = Vec::new();
+    for msg in batch.into_iter().rev() {
+        match coalesce_key(&msg) {
+            Some(key) if seen.contains(&key) => continue,
+            Some(key) => seen.push(key),
+            None => seen.clear(),
+        }
+        kept.push(msg);
+    }
+    kept.reverse();
+    kept
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::player_bar::DownloadProgress;
+
+    fn progress(position_ms: u64) -> AppMessage {
+        AppMessage::PlayerStateChanged(PlayerEvent::Progress { position_ms, duration_secs: 200 })
+    }
+
+    fn download(song_id: i64, received: u64) -> AppMessage {
+        AppMessage::AudioDownloadProgress {
+            song_id,
+            progress: DownloadProgress { received, total: None, bytes_per_sec: 0 },
+        }
+    }
+
+    fn position(msg: &AppMessage) -> Option<u64> {
+        match msg {
+            AppMessage::PlayerStateChanged(PlayerEvent::Progress { position_ms, .. }) => Some(*position_ms),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn flood_collapses_to_latest_of_each_kind() {
+        let mut batch = Vec::new();
+        for i in 0..10_000 {
+            batch.push(AppMessage::PlayerTick);
+            batch.push(progress(i));
+            batch.push(download(1, i));
+            batch.push(download(2, i * 2));
+        }
+
+        let kept = coalesce(batch);
+
+        assert_eq!(kept.len(), 4);
+        assert_eq!(kept.iter().filter(|m| matches!(m, AppMessage::PlayerTick)).count(), 1);
+        assert_eq!(kept.iter().find_map(position), Some(9_999));
+        let downloads: Vec<(i64, u64)> = kept.iter()
+            .filter_map(|m| match m {
+                AppMessage::AudioDownloadProgress { song_id, progress } => Some((*song_id, progress.received)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(downloads, [(1, 9_999), (2, 19_998)]);
+    }
+
+    #[test]
+    fn other_messages_split_runs_and_keep_order() {
+        let batch = vec![
+            progress(1),
+            progress(2),
+            AppMessage::PlayerStateChanged(PlayerEvent::TrackEnded),
+            progress(0),
+            AppMessage::PlayerTick,
+            AppMessage::Error("x".to_string()),
+            AppMessage::PlayerTick,
+        ];
+
+        let kept = coalesce(batch);
+
+        assert_eq!(kept.len(), 6);
+        assert_eq!(position(&kept[0]), Some(2));
+        assert!(matches!(kept[1], AppMessage::PlayerStateChanged(PlayerEvent::TrackEnded)));
+        assert_eq!(position(&kept[2]), Some(0));
+        assert!(matches!(kept[3], AppMessage::PlayerTick));
+        assert!(matches!(kept[4], AppMessage::Error(_)));
+        assert!(matches!(kept[5], AppMessage::PlayerTick));
+    }
+}
@@ -2,27 +2,51 @@ use std::time::Duration;
 
 use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
 
-use crate::config::settings::PlayMode;
 use crate::player::engine::{AudioSource, PlayerEvent};
 use crate::ui::log_view::LogLevel;
 use crate::ui::login::LoginStep;
 use crate::ui::navigation::NavNode;
+use crate::ui::player_bar::LoadPhase;
+use crate::ui::toast::ToastKind;
 
 use super::{App, AppMessage, DataPayload, InputMode};
 
 const VOLUME_STEP: u8 = 5;
-const MAX_VOLUME: u8 = 100;
 const SEEK_STEP_SECS: u32 = 5;
+/// `,` / `.` 精细 seek 步长
+const SEEK_FINE_STEP_SECS: u32 = 1;
+/// `$` 跳到距结尾还剩的秒数
+const SEEK_END_MARGIN_SECS: u32 = 5;
 
 impl App {
     pub(crate) fn handle_event(&mut self, event: Event) {
-        // 终端大小变化：标记需要在下次 draw() 之后重新上传 image data
+        // 终端大小变化：交给图形后端在下次 draw() 之后重新上传/放置
         // （不能在此处写 stdout，ratatui 的 \x1b[2J 清屏发生在下次 draw() 里，会覆盖提前写入的数据）
         if let Event::Resize(_, _) = event {
-            self.cover.needs_cover_reupload = true;
+            if let Some(backend) = self.cover.backend.as_mut() {
+                backend.on_resize();
+            }
             return;
         }
 
+        match event {
+            Event::FocusGained => {
+                self.ui.focused = true;
+                if self.settings.display.refresh_on_focus {
+                    self.refresh_time_sensitive();
+                    if self.player.bar.has_song() {
+                        self.ensure_engine_alive();
+                    }
+                }
+                return;
+            }
+            Event::FocusLost => {
+                self.ui.focused = false;
+                return;
+            }
+            _ => {}
+        }
+
         if let Event::Key(key) = event {
             // 无条件拦截 Ctrl+C，任何状态下都可退出
             if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
@@ -34,6 +58,28 @@ impl App {
                 return;
             }
 
+            // o 之后等待数字选择外部链接：数字键打开对应链接，Esc 取消，其他键照常处理
+            if self.ui.pending_link_open && self.ui.input_mode == InputMode::Normal {
+                self.ui.pending_link_open = false;
+                if let KeyCode::Char(c @ '1'..='9') = key.code {
+                    self.open_external_link(c as usize - '1' as usize);
+                    return;
+                }
+                if key.code == KeyCode::Esc {
+                    return;
+                }
+            }
+
+            // Esc 统一表示「返回」：浮层已在上面关闭；普通模式下逐级返回，
+            // 搜索与登录模式的 Esc 仍由各自的处理函数负责
+            // 迷你播放器中浏览区不可见，不做返回
+            if key.code == KeyCode::Esc && self.ui.input_mode == InputMode::Normal {
+                if !self.showing_mini_player() {
+                    self.go_back();
+                }
+                return;
+            }
+
             match self.ui.input_mode {
                 InputMode::Normal => self.handle_normal_key(key),
                 InputMode::Search => self.handle_search_key(key),
@@ -42,8 +88,17 @@ impl App {
         }
     }
 
-    /// 帮助/日志浮层的键处理，返回 true 表示浮层已拦截事件
+    /// 帮助/统计/日志浮层的键处理，返回 true 表示浮层已拦截事件
     fn handle_overlay_key(&mut self, key: KeyEvent) -> bool {
+        if self.ui.show_quit_confirm {
+            match key.code {
+                KeyCode::Char('y') | KeyCode::Char('q') | KeyCode::Enter => self.running = false,
+                KeyCode::Char('n') | KeyCode::Esc => self.ui.show_quit_confirm = false,
+                _ => {}
+            }
+            return true;
+        }
+
         if self.ui.show_help {
             match (key.modifiers, key.code) {
                 (_, KeyCode::Char('q') | KeyCode::Char('?') | KeyCode::Esc) => {
@@ -56,6 +111,71 @@ impl App {
                 (_, KeyCode::Char('k') | KeyCode::Up) => {
                     self.ui.help_scroll = self.ui.help_scroll.saturating_sub(1);
                 }
+                (_, KeyCode::Char('e')) => self.export_cheatsheet(),
+                _ => {}
+            }
+            return true;
+        }
+
+        if self.ui.show_stats {
+            if matches!(key.code, KeyCode::Char('q') | KeyCode::Char('S') | KeyCode::Esc) {
+                self.ui.show_stats = false;
+            }
+            return true;
+        }
+
+        if self.ui.info_song.is_some() {
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Char('I') | KeyCode::Esc => {
+                    self.ui.info_song = None;
+                    self.ui.info_scroll = 0;
+                }
+                KeyCode::Char('j') | KeyCode::Down => {
+                    self.ui.info_scroll = self.ui.info_scroll.saturating_add(1);
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    self.ui.info_scroll = self.ui.info_scroll.saturating_sub(1);
+                }
+                KeyCode::Char(c @ '1'..='9') => {
+                    let url = self.ui.info_song.as_ref()
+                        .and_then(|s| s.external_links.get(c as usize - '1' as usize))
+                        .map(|link| link.url.clone());
+                    if let Some(url) = url {
+                        self.open_url(&url);
+                    }
+                }
+                _ => {}
+            }
+            return true;
+        }
+
+        if self.ui.show_pinned {
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Char('P') | KeyCode::Esc => self.ui.show_pinned = false,
+                KeyCode::Char('j') | KeyCode::Down if self.ui.pinned_selected + 1 < self.pinned.nodes.len() => {
+                    self.ui.pinned_selected += 1;
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    self.ui.pinned_selected = self.ui.pinned_selected.saturating_sub(1);
+                }
+                KeyCode::Enter | KeyCode::Char('l') => self.jump_to_pinned(),
+                KeyCode::Char('d') => self.unpin_selected(),
+                _ => {}
+            }
+            return true;
+        }
+
+        if self.ui.show_hidden {
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Char('H') | KeyCode::Esc => self.ui.show_hidden = false,
+                KeyCode::Char('j') | KeyCode::Down if self.ui.hidden_selected + 1 < self.hidden.songs.len() => {
+                    self.ui.hidden_selected += 1;
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    self.ui.hidden_selected = self.ui.hidden_selected.saturating_sub(1);
+                }
+                KeyCode::Char('d') => self.unhide_selected(),
+                KeyCode::Char('c') => self.clear_hidden(),
                 _ => {}
             }
             return true;
@@ -70,6 +190,7 @@ impl App {
                 (_, KeyCode::Char('k') | KeyCode::Up) => self.ui.logs.scroll_up(),
                 (_, KeyCode::Char('h') | KeyCode::Left) => self.ui.logs.scroll_left(),
                 (_, KeyCode::Char('l') | KeyCode::Right) => self.ui.logs.scroll_right(),
+                (_, KeyCode::Char('r')) => self.export_debug_report(),
                 _ => {}
             }
             return true;
@@ -79,45 +200,111 @@ impl App {
     }
 
     fn adjust_volume(&mut self, delta: i16) {
-        let vol = (self.player.volume as i16 + delta).clamp(0, MAX_VOLUME as i16) as u8;
-        self.player.volume = vol;
-        self.player.engine.set_volume(vol as f32 / MAX_VOLUME as f32);
+        self.player.volume.adjust(delta);
+        self.player.engine.set_volume(self.player.volume.gain());
+        self.remember_volume();
+    }
+
+    fn toggle_mute(&mut self) {
+        self.player.volume.toggle_mute();
+        self.player.engine.set_volume(self.player.volume.gain());
+    }
+
+    /// 音量（静音时为静音前的音量）写回设置，由自动保存或退出时写入
+    fn remember_volume(&mut self) {
+        let target = self.player.volume.target();
+        if self.settings.player.volume != target {
+            self.settings.player.volume = target;
+            self.autosave.settings_dirty = true;
+        }
+    }
+
+    /// 切换迷你播放器并写入配置，重启后保持
+    pub(crate) fn toggle_mini_player(&mut self) {
+        self.settings.display.mini_player = !self.settings.display.mini_player;
+        if let Err(e) = self.settings.save() {
+            self.ui.logs.push(LogLevel::Warn, format!("保存迷你播放器设置失败：{e}"));
+        }
     }
 
     fn seek_relative(&mut self, delta_secs: i32) {
-        if self.player.bar.has_song() {
-            let new_pos = (self.player.bar.current_secs as i64 + delta_secs as i64)
-                .clamp(0, self.player.bar.total_secs as i64) as u32;
+        if self.player.bar.has_song() && self.ensure_engine_alive() {
+            // 时长未知（0）时只限制下界，交给引擎处理越界
+            let max = match self.player.bar.total_secs {
+                0 => u32::MAX as i64,
+                total => total as i64,
+            };
+            let new_pos = (self.player.bar.current_secs as i64 + delta_secs as i64).clamp(0, max) as u32;
             self.player.engine.seek(Duration::from_secs(new_pos as u64));
         }
     }
 
+    /// 按 0 回到开头
+    fn seek_to_start(&mut self) {
+        if self.player.bar.has_song() && self.ensure_engine_alive() {
+            self.player.engine.seek(Duration::ZERO);
+        }
+    }
+
+    /// 按 $ 跳到结尾前几秒，便于检查切歌过渡；时长未知时无法定位
+    fn seek_near_end(&mut self) {
+        if !self.player.bar.has_song() {
+            return;
+        }
+        let total = self.player.bar.total_secs;
+        if total == 0 {
+            self.show_toast(ToastKind::Warn, t!("toast.duration_unknown").to_string());
+            return;
+        }
+        if self.ensure_engine_alive() {
+            let pos = total.saturating_sub(SEEK_END_MARGIN_SECS);
+            self.player.engine.seek(Duration::from_secs(pos as u64));
+        }
+    }
+
     /// 处理 expanded 和 normal 共享的全局键绑定，返回 true 表示已处理
     fn handle_global_key(&mut self, key: KeyEvent) -> bool {
         match (key.modifiers, key.code) {
-            (_, KeyCode::Char('q')) | (KeyModifiers::CONTROL, KeyCode::Char('c')) => {
-                self.running = false;
+            (_, KeyCode::Char('q')) => {
+                if self.settings.confirm_quit {
+                    self.ui.show_quit_confirm = true;
+                } else {
+                    self.running = false;
+                }
             }
             (_, KeyCode::Char('?')) => self.ui.show_help = true,
             (_, KeyCode::Char('!')) => {
                 self.ui.show_logs = true;
                 self.ui.logs.mark_read();
             }
+            (_, KeyCode::Char('S')) => self.ui.show_stats = true,
+            (_, KeyCode::Char('P')) => self.open_pinned(),
+            (_, KeyCode::Char('*')) => self.toggle_pin(),
+            (_, KeyCode::Char('I')) => {
+                if let Some(song) = self.focused_song() {
+                    self.ui.info_song = Some(song);
+                    self.ui.info_scroll = 0;
+                }
+            }
+            (_, KeyCode::Char('Q')) => self.goto_queue(),
+            (_, KeyCode::Char('M')) => self.toggle_mini_player(),
             (_, KeyCode::Char('L')) => self.logout(),
+            (_, KeyCode::Char('u')) => self.goto_artist(),
+            (_, KeyCode::Char('y')) => self.copy_share_moment(),
+            (_, KeyCode::Char('Y')) => self.copy_song_ids(),
             (_, KeyCode::Char(' ')) => self.toggle_play_pause(),
-            (_, KeyCode::Char('n')) => self.play_next(),
-            (_, KeyCode::Char('N')) => self.play_prev(),
+            (_, KeyCode::Char('n')) => self.skip_next(),
+            (_, KeyCode::Char('N')) => self.skip_prev(),
             (_, KeyCode::Char('+') | KeyCode::Char('=')) => self.adjust_volume(VOLUME_STEP as i16),
             (_, KeyCode::Char('-')) => self.adjust_volume(-(VOLUME_STEP as i16)),
+            (_, KeyCode::Char('m')) => self.toggle_mute(),
             (_, KeyCode::Char('>')) => self.seek_relative(SEEK_STEP_SECS as i32),
             (_, KeyCode::Char('<')) => self.seek_relative(-(SEEK_STEP_SECS as i32)),
-            (_, KeyCode::Char('s')) => {
-                self.settings.player.default_play_mode = match self.settings.player.default_play_mode {
-                    PlayMode::Sequential => PlayMode::Shuffle,
-                    PlayMode::Shuffle => PlayMode::RepeatOne,
-                    PlayMode::RepeatOne => PlayMode::Sequential,
-                };
-            }
+            (_, KeyCode::Char('.')) => self.seek_relative(SEEK_FINE_STEP_SECS as i32),
+            (_, KeyCode::Char(',')) => self.seek_relative(-(SEEK_FINE_STEP_SECS as i32)),
+            (_, KeyCode::Char('0')) => self.seek_to_start(),
+            (_, KeyCode::Char('$')) => self.seek_near_end(),
+            (_, KeyCode::Char('s')) => self.cycle_play_mode(),
             _ => return false,
         }
         true
@@ -127,6 +314,10 @@ impl App {
         if self.handle_global_key(key) {
             return;
         }
+        // 迷你播放器只响应播放控制等全局键
+        if self.showing_mini_player() {
+            return;
+        }
 
         if self.player.expanded {
             // 展开页专属键
@@ -136,11 +327,14 @@ impl App {
                 (_, KeyCode::Char('k') | KeyCode::Up) => { self.nav_up(); }
                 (_, KeyCode::Char('g')) => self.nav_top(),
                 (_, KeyCode::Char('G')) => self.nav_bottom(),
+                (_, KeyCode::Char('J')) => self.scroll_detail(1),
+                (_, KeyCode::Char('K')) => self.scroll_detail(-1),
                 (_, KeyCode::Char('h') | KeyCode::Left) => self.player.expanded = false,
                 (_, KeyCode::Char('l') | KeyCode::Right | KeyCode::Enter) => {
                     self.play_expanded_song();
                 }
                 (_, KeyCode::Char('D')) => self.fetch_danmaku(),
+                (_, KeyCode::Char('f')) => self.toggle_follow_playback(),
                 _ => {}
             }
             return;
@@ -148,23 +342,16 @@ impl App {
 
         // Normal 模式专属键
         match (key.modifiers, key.code) {
-            (_, KeyCode::Char('i')) => {
-                self.player.expanded = true;
-                self.player.follow_playback = self.player.current_detail.is_some();
-                self.schedule_cover_load();
-            }
-            (_, KeyCode::Char('/')) => {
-                if self.nav.current().node != NavNode::Settings {
-                    self.search.clear();
-                    self.ui.input_mode = InputMode::Search;
-                }
+            (_, KeyCode::Char('i')) => self.open_player_view(),
+            (_, KeyCode::Char('/')) if self.nav.current().node != NavNode::Settings => {
+                self.search.clear();
+                self.ui.input_mode = InputMode::Search;
             }
-            (_, KeyCode::Tab) => {
-                if self.nav.contains(&NavNode::SearchResults) {
-                    self.search.search_type = self.search.search_type.next();
-                    self.nav.current_mut().selected = 0;
-                }
+            (_, KeyCode::Tab) if self.nav.contains(&NavNode::SearchResults) => {
+                self.search.search_type = self.search.search_type.next();
+                self.nav.current_mut().selected = 0;
             }
+            (_, KeyCode::Char('x')) => self.clear_search(),
             (_, KeyCode::Char('j') | KeyCode::Down) => { self.nav_down(); }
             (_, KeyCode::Char('k') | KeyCode::Up) => { self.nav_up(); }
             (_, KeyCode::Char('l') | KeyCode::Right | KeyCode::Enter) => self.nav_drill_in(),
@@ -172,14 +359,20 @@ impl App {
             (_, KeyCode::Char('g')) => self.nav_top(),
             (_, KeyCode::Char('G')) => self.nav_bottom(),
             (_, KeyCode::Char('a')) => self.add_selected_to_queue(),
+            (_, KeyCode::Char('A')) => self.add_list_to_queue(),
+            (_, KeyCode::Char('F')) => self.play_all_from_here(),
+            (KeyModifiers::CONTROL, KeyCode::Char('t')) => self.next_favorite_tag(),
+            (_, KeyCode::Char('t')) => self.toggle_favorite_tag(),
+            (_, KeyCode::Char('r')) => self.retry_failed_load(),
             (_, KeyCode::Char('d')) => self.remove_from_queue(),
-            (_, KeyCode::Char('o')) => {
-                if let Some(song) = self.selected_song().cloned() {
-                    if let Some(link) = song.external_links.first() {
-                        let _ = open::that(&link.url);
-                    }
-                }
-            }
+            (_, KeyCode::Char('z')) => self.toggle_queue_order_view(),
+            (_, KeyCode::Char('T')) => self.move_queue_selected(true),
+            (_, KeyCode::Char('B')) => self.move_queue_selected(false),
+            (_, KeyCode::Char('O')) => self.sort_queue(),
+            (_, KeyCode::Char('U')) => self.undo_queue(),
+            (_, KeyCode::Char('X')) => self.hide_selected_song(),
+            (_, KeyCode::Char('H')) => self.open_hidden(),
+            (_, KeyCode::Char('o')) => self.begin_open_link(),
             (_, KeyCode::Char('D')) => self.fetch_danmaku(),
             (_, KeyCode::Char('p')) => {
                 // TODO: 添加到歌单
@@ -195,6 +388,15 @@ impl App {
             }
             (_, KeyCode::Enter) => {
                 self.ui.input_mode = InputMode::Normal;
+                // 空查询时选中的最近搜索建议填入搜索框
+                if self.search.query.is_empty()
+                    && let Some(term) = self.search.suggestion
+                        .and_then(|i| self.search_history.terms.get(i))
+                {
+                    self.search.query = term.clone();
+                    self.search.cursor_pos = term.chars().count();
+                }
+                self.search.suggestion = None;
                 if !self.search.query.trim().is_empty() {
                     self.execute_search();
                     if !self.nav.pop_to(&NavNode::SearchResults) {
@@ -208,29 +410,32 @@ impl App {
             (KeyModifiers::CONTROL, KeyCode::Char('s')) => {
                 self.search.sort = self.search.sort.next();
             }
-            (_, KeyCode::Left) => {
-                if self.search.cursor_pos > 0 {
-                    self.search.cursor_pos -= 1;
-                }
+            // 最近搜索建议：j/k 需要能正常输入，选择只用方向键
+            (_, KeyCode::Down) if self.showing_search_suggestions() => {
+                let last = self.search_history.terms.len() - 1;
+                self.search.suggestion = Some(self.search.suggestion.map_or(0, |i| (i + 1).min(last)));
             }
-            (_, KeyCode::Right) => {
-                if self.search.cursor_pos < self.search.query.chars().count() {
-                    self.search.cursor_pos += 1;
-                }
+            (_, KeyCode::Up) if self.showing_search_suggestions() => {
+                self.search.suggestion = self.search.suggestion.and_then(|i| i.checked_sub(1));
             }
-            (_, KeyCode::Backspace) => {
-                if self.search.cursor_pos > 0 {
-                    self.search.cursor_pos -= 1;
-                    let byte_idx = self.search.query.char_indices()
-                        .nth(self.search.cursor_pos).map(|(i, _)| i).unwrap_or(self.search.query.len());
-                    self.search.query.remove(byte_idx);
-                }
+            (_, KeyCode::Left) if self.search.cursor_pos > 0 => {
+                self.search.cursor_pos -= 1;
+            }
+            (_, KeyCode::Right) if self.search.cursor_pos < self.search.query.chars().count() => {
+                self.search.cursor_pos += 1;
+            }
+            (_, KeyCode::Backspace) if self.search.cursor_pos > 0 => {
+                self.search.cursor_pos -= 1;
+                let byte_idx = self.search.query.char_indices()
+                    .nth(self.search.cursor_pos).map(|(i, _)| i).unwrap_or(self.search.query.len());
+                self.search.query.remove(byte_idx);
             }
             (_, KeyCode::Char(c)) => {
                 let byte_idx = self.search.query.char_indices()
                     .nth(self.search.cursor_pos).map(|(i, _)| i).unwrap_or(self.search.query.len());
                 self.search.query.insert(byte_idx, c);
                 self.search.cursor_pos += 1;
+                self.search.suggestion = None;
             }
             _ => {}
         }
@@ -253,24 +458,21 @@ impl App {
             (_, KeyCode::Char('q')) | (KeyModifiers::CONTROL, KeyCode::Char('c')) => {
                 self.running = false;
             }
+            (_, KeyCode::Esc) if self.login.cancelable => {
+                // 重新登录时可取消，返回原界面
+                self.ui.input_mode = InputMode::Normal;
+            }
+            (_, KeyCode::Esc) => self.continue_anonymously(),
             (_, KeyCode::Tab) => {
                 self.login.toggle_field();
             }
             (_, KeyCode::Enter) => {
                 self.start_captcha();
             }
-            (_, KeyCode::Backspace) => {
-                let (text, cursor) = self.login.current_input();
-                if *cursor > 0 {
-                    *cursor -= 1;
-                    text.remove(*cursor);
-                }
-            }
-            (_, KeyCode::Char(c)) => {
-                let (text, cursor) = self.login.current_input();
-                text.insert(*cursor, c);
-                *cursor += 1;
-            }
+            (_, KeyCode::Left) => self.login.move_cursor_left(),
+            (_, KeyCode::Right) => self.login.move_cursor_right(),
+            (_, KeyCode::Backspace) => self.login.delete_before_cursor(),
+            (_, KeyCode::Char(c)) => self.login.insert_char(c),
             _ => {}
         }
     }
@@ -283,6 +485,7 @@ impl App {
             (_, KeyCode::Esc) => {
                 self.login.step = LoginStep::Input;
                 self.login.captcha_key = None;
+                self.login.captcha_url = None;
                 self.login.error = None;
             }
             (_, KeyCode::Enter) => {
@@ -297,8 +500,29 @@ impl App {
             AppMessage::TermEvent(ev) => {
                 self.handle_event(ev);
             }
+            AppMessage::TermReadRetry(attempt, err) => {
+                self.ui.logs.push(
+                    LogLevel::Warn,
+                    format!("读取终端输入失败（第 {attempt} 次），稍后重试：{err}"),
+                );
+            }
+            AppMessage::TermInputClosed(reason) => {
+                // 输入已不可恢复：正常退出，保存队列与进度
+                self.ui.logs.push(LogLevel::Error, format!("终端输入已断开，退出：{reason}"));
+                self.running = false;
+            }
             AppMessage::PlayerTick => {
-                self.ui.scroll_tick = self.ui.scroll_tick.wrapping_add(1);
+                if self.ui.focused || !self.settings.display.freeze_when_unfocused {
+                    self.ui.scroll_tick = self.ui.scroll_tick.wrapping_add(1);
+                    self.advance_credits_roll();
+                }
+                if self.ui.toast.as_ref().is_some_and(|t| t.is_expired()) {
+                    self.ui.toast = None;
+                }
+                self.flush_pending_preview_load();
+                self.flush_pending_skip();
+                self.flush_captcha_retry();
+                self.flush_autosave();
                 if let Some((url, t)) = self.cover.pending_cover_load.take() {
                     if t.elapsed() >= Duration::from_millis(250) {
                         self.maybe_load_cover(url);
@@ -311,7 +535,7 @@ impl App {
                 match event {
                     PlayerEvent::Playing => {
                         self.player.bar.is_playing = true;
-                        self.player.bar.is_loading = false;
+                        self.player.bar.phase = LoadPhase::Idle;
                     }
                     PlayerEvent::Paused => {
                         self.player.bar.is_playing = false;
@@ -324,99 +548,133 @@ impl App {
                         self.player.bar.total_secs = 0;
                         self.player.parsed_lyrics = crate::ui::lyrics::ParsedLyrics::Empty;
                     }
-                    PlayerEvent::Progress { position_secs, duration_secs } => {
+                    PlayerEvent::Progress { position_ms, duration_secs } => {
+                        let position_secs = (position_ms / 1000) as u32;
+                        self.queue.position_ms = position_ms;
                         self.player.bar.current_secs = position_secs;
                         self.player.bar.total_secs = duration_secs;
+                        self.record_progress(position_secs);
                     }
+                    // 预载的下一首由引擎直接接上时只会收到 NextStarted，两者不会对同一首歌重复切歌
                     PlayerEvent::TrackEnded => {
+                        self.record_track_ended();
                         self.play_next();
                     }
+                    PlayerEvent::NextStarted => self.on_next_track_started(),
                     PlayerEvent::Error(msg) => {
-                        self.player.bar.is_loading = false;
+                        self.player.bar.phase = LoadPhase::Idle;
                         self.ui.logs.push(LogLevel::Error, msg);
                     }
                     PlayerEvent::Loading => {
-                        self.player.bar.is_loading = true;
+                        self.player.bar.phase = LoadPhase::Starting;
                     }
                 }
             }
+            AppMessage::PlayerEngineExited(generation) => {
+                // 重启后旧引擎退出的通知不影响新引擎
+                if generation == self.player.engine_generation {
+                    self.player.bar.is_playing = false;
+                    self.player.bar.phase = LoadPhase::Idle;
+                    self.ui.logs.push(
+                        LogLevel::Error,
+                        "播放引擎已退出，可在设置中重启音频引擎".to_string(),
+                    );
+                    self.show_toast(ToastKind::Warn, t!("toast.engine_dead").to_string());
+                }
+            }
             AppMessage::AudioFetched { detail, data } => {
-                self.player.bar.title = detail.title.clone();
-                self.player.bar.artist = detail.uploader_name.clone();
-                self.player.bar.total_secs = detail.duration_seconds as u32;
-                self.player.bar.current_secs = 0;
-                self.player.bar.is_loading = false;
-                self.player.bar.cover_url = detail.cover_url.clone();
-                self.player.bar.codec = detail
-                    .audio_url
-                    .rsplit('.')
-                    .next()
-                    .unwrap_or("")
-                    .to_string();
+                // 已切到别的歌曲（或连按切歌中），丢弃过期的下载
+                if self.player.fetching_song_id != Some(detail.id) {
+                    return;
+                }
+                if !self.ensure_engine_alive() {
+                    self.player.bar.phase = LoadPhase::Idle;
+                    return;
+                }
+                // 下载完成，等待播放引擎解码启动
+                self.player.restricted_skips = 0;
+                self.player.bar.phase = LoadPhase::Starting;
+                self.player.bar.download = None;
+                self.player.fetching_song_id = None;
                 let duration_secs = detail.duration_seconds as u32;
                 let gain = if self.settings.player.replay_gain {
                     detail.gain
                 } else {
                     None
                 };
-                self.player.parsed_lyrics = crate::ui::lyrics::parse(&detail.lyrics);
-                self.player.current_detail = Some(detail);
-                self.player.engine.play(AudioSource::Buffered(data), duration_secs, gain);
+                self.show_now_playing(detail);
+                self.player.engine.play(
+                    AudioSource::Buffered(data),
+                    duration_secs,
+                    gain,
+                    self.settings.player.trim_silence,
+                );
+                self.record_play_started();
                 self.schedule_cover_load();
+                self.load_playing_covers();
                 if let Some(pos_ms) = self.resume_position_ms.take() {
                     self.player.engine.seek(std::time::Duration::from_millis(pos_ms));
                     self.player.bar.current_secs = (pos_ms / 1000) as u32;
                 }
+                self.preload_next_track();
+            }
+            AppMessage::NextAudioPreloaded { detail, track } => self.on_next_audio_preloaded(detail, track),
+            AppMessage::AudioDownloadProgress { song_id, progress } => {
+                if self.player.bar.phase == LoadPhase::Downloading
+                    && self.player.fetching_song_id == Some(song_id) {
+                    self.player.bar.download = Some(progress);
+                }
             }
+            AppMessage::AudioRestricted(song_id) => self.on_audio_restricted(song_id),
+            AppMessage::AutoSaveFailed(e) => self.on_autosave_failed(e),
             AppMessage::AudioFetchError(err) => {
-                self.player.bar.is_loading = false;
+                self.player.bar.phase = LoadPhase::Idle;
+                self.player.bar.download = None;
+                self.player.fetching_song_id = None;
                 self.ui.logs.push(LogLevel::Error, err);
             }
             AppMessage::DataLoaded(payload) => match payload {
-                DataPayload::Songs(node, songs) => {
+                DataPayload::Songs(node, mut songs) => {
                     self.cache.loading.remove(&node);
-                    if !songs.is_empty() {
-                        self.cache.songs.insert(node, songs);
+                    if node.is_feed() {
+                        self.hidden.filter(&mut songs);
                     }
-                    self.after_nav_move();
+                    self.cache.songs.insert(node, songs);
+                    self.after_data_loaded();
                 }
                 DataPayload::Tags(tags) => {
                     self.cache.loading.remove(&NavNode::Categories);
                     self.cache.tags = Some(tags);
-                    self.after_nav_move();
+                    self.after_data_loaded();
                 }
                 DataPayload::Playlists(playlists) => {
                     self.cache.loading.remove(&NavNode::MyPlaylists);
                     self.cache.playlists = Some(playlists);
-                    self.after_nav_move();
+                    self.after_data_loaded();
                 }
                 DataPayload::SearchUsers(users) => {
                     self.cache.loading.remove(&NavNode::SearchResults);
                     self.cache.search_users = users;
-                    self.after_nav_move();
+                    self.after_data_loaded();
                 }
                 DataPayload::SearchPlaylists(playlists) => {
                     self.cache.loading.remove(&NavNode::SearchResults);
                     self.cache.search_playlists = playlists;
-                    self.after_nav_move();
+                    self.after_data_loaded();
+                }
+                DataPayload::LoadFailed(node, err) => {
+                    self.cache.loading.remove(&node);
+                    self.ui.logs.push(LogLevel::Error, format!("加载「{}」失败：{err}", node.display_name()));
+                    self.cache.failed.insert(node);
                 }
             },
             AppMessage::Error(err) => {
                 self.ui.logs.push(LogLevel::Error, err);
             }
-            AppMessage::CaptchaGenerated(result) => {
-                match result {
-                    Ok((captcha_key, url)) => {
-                        self.login.captcha_key = Some(captcha_key);
-                        self.login.step = LoginStep::WaitingCaptcha;
-                        let _ = open::that(&url);
-                    }
-                    Err(e) => {
-                        self.login.error = Some(e);
-                        self.login.step = LoginStep::Input;
-                    }
-                }
+            AppMessage::ApiHint(hint) => {
+                self.show_toast(ToastKind::Warn, hint);
             }
+            AppMessage::CaptchaGenerated(result) => self.on_captcha_generated(result),
             AppMessage::LoginResult(result) => {
                 match result {
                     Ok(resp) => {
@@ -432,7 +690,14 @@ impl App {
                         self.login.step = LoginStep::Input;
                         self.login.captcha_key = None;
                         self.ui.input_mode = InputMode::Normal;
-                        self.resume_playback();
+                        // 匿名浏览时被拦下的需登录节点，登录后可以正常加载
+                        self.cache.failed.retain(|node| !node.requires_login());
+                        // 重新登录可能换了账号，我的投稿按新 uid 重新加载
+                        self.cache.songs.remove(&NavNode::MyUploads);
+                        // 重新登录时保持当前播放，不重新加载
+                        if self.player.current_detail.is_none() {
+                            self.restore_last_session();
+                        }
                     }
                     Err(e) => {
                         self.login.error = Some(e);
@@ -443,23 +708,46 @@ impl App {
             }
             AppMessage::SongDetailFetched { node, index, detail } => {
                 self.cache.detail_loading.remove(&detail.id);
+                // 正在播放的歌曲若仍是不完整的详情（如来自搜索结果），就地补全歌词与制作信息，无需重新播放
+                if let Some(current) = self.player.current_detail.as_mut()
+                    && current.id == detail.id
+                    && current.partial
+                {
+                    self.player.parsed_lyrics = crate::ui::lyrics::parse(&detail.lyrics);
+                    *current = detail.clone();
+                }
                 if node == NavNode::Queue {
                     self.cache.queue_song_detail.insert(detail.id, detail);
-                } else if let Some(songs) = self.cache.songs.get_mut(&node) {
-                    if index < songs.len() && songs[index].id == detail.id {
-                        songs[index] = detail;
-                    }
+                } else if let Some(songs) = self.cache.songs.get_mut(&node)
+                    && index < songs.len()
+                    && songs[index].id == detail.id
+                {
+                    songs[index] = detail;
                 }
             }
-            AppMessage::CoverReady { url, id, upload_seq } => {
-                use std::io::Write;
-                let _ = std::io::stdout().write_all(&upload_seq);
-                let _ = std::io::stdout().flush();
-                self.cache.covers.mark_loaded(url, id, upload_seq);
+            AppMessage::SongDetailFailed(song_id) => {
+                self.cache.detail_loading.remove(&song_id);
+            }
+            AppMessage::CoverReady { url, id, data } => {
+                self.evict_covers_over_budget(data.len());
+                if let Some(backend) = self.cover.backend.as_mut() {
+                    let _ = backend.on_ready(&mut std::io::stdout(), &data);
+                }
+                self.cache.covers.mark_loaded(url, id, data);
             }
             AppMessage::DanmakuFetched { title, path } => {
                 self.ui.logs.push(LogLevel::Info, format!("弹幕已保存：{path}  ({title})"));
             }
+            AppMessage::ConnectionTested(result) => match result {
+                Ok((name, ms)) => {
+                    self.ui.logs.push(LogLevel::Info, format!("连接测试成功：{name} ({ms} ms)"));
+                    self.show_toast(ToastKind::Info, format!("{} ({ms} ms)", t!("toast.connection_ok")));
+                }
+                Err(e) => {
+                    self.ui.logs.push(LogLevel::Error, format!("连接测试失败：{e}"));
+                    self.show_toast(ToastKind::Warn, t!("toast.connection_failed").to_string());
+                }
+            },
         }
     }
 }
@@ -1,49 +1,86 @@
 mod actions;
+mod batch;
 mod event;
 mod render;
 
 const UI_TICK_MS: u64 = 300;
+/// 读取终端事件连续失败的重试上限，超过后放弃并退出
+const TERM_READ_MAX_RETRIES: u32 = 8;
+/// 重试退避的初始与最大间隔（毫秒），每次失败翻倍
+const TERM_READ_BACKOFF_MS: u64 = 100;
+const TERM_READ_BACKOFF_MAX_MS: u64 = 5_000;
 
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
 use anyhow::Result;
 use crossterm::event::Event;
-use tokio::sync::mpsc;
+use tokio::sync::{Semaphore, mpsc};
 
 use mambocore::MamboClient;
 use crate::config::settings::Settings;
+use crate::config::stats_store::ListeningStats;
+use crate::config::search_history::SearchHistory;
+use crate::config::pinned_store::PinnedNodes;
+use crate::config::favorite_tags::FavoriteTags;
+use crate::config::hidden_songs::HiddenSongs;
 use crate::model::playlist::{PlaylistItem, PlaylistMetadata};
-use crate::model::queue::QueueState;
+use crate::model::queue::{MusicQueueItem, QueueState, QueueUndo};
 use crate::model::song::PublicSongDetail;
 use crate::model::user::PublicUserProfile;
 use crate::player::engine::{PlayerEngine, PlayerEvent};
-use crate::ui::log_view::LogStore;
+use crate::player::volume::VolumeState;
+use crate::ui::graphics::{CoverBackend, GraphicsProtocol};
+use crate::ui::log_view::{LogLevel, LogStore};
 use crate::ui::login::LoginState;
 use crate::ui::lyrics::ParsedLyrics;
 use crate::ui::navigation::{NavNode, NavStack, SearchState};
-use crate::ui::player_bar::PlayerBarState;
+use crate::ui::player_bar::{DownloadProgress, PlayerBarState};
+use crate::ui::toast::{Toast, ToastKind};
 
 /// 异步消息，从后台任务发送到主循环
 pub enum AppMessage {
     /// 终端事件（由持久后台线程读取）
     TermEvent(Event),
+    /// 读取终端事件出错，正在退避重试（第几次, 错误信息）
+    TermReadRetry(u32, String),
+    /// 终端输入已断开或重试耗尽，附带原因
+    TermInputClosed(String),
     /// 播放状态更新（UI 动画驱动）
     PlayerTick,
     /// 播放引擎事件
     PlayerStateChanged(PlayerEvent),
+    /// 播放引擎的事件通道已关闭（线程退出或崩溃），附带引擎代数
+    PlayerEngineExited(u32),
     /// 音频下载完成
     AudioFetched {
         detail: PublicSongDetail,
         data: Vec<u8>,
     },
+    /// 淡入淡出用的下一首已预先下载并解码
+    NextAudioPreloaded {
+        detail: PublicSongDetail,
+        track: crate::player::engine::PreparedTrack,
+    },
     /// 音频下载失败
     AudioFetchError(String),
+    /// 歌曲因地区限制无法播放
+    AudioRestricted(i64),
+    /// 后台自动保存失败
+    AutoSaveFailed(String),
+    /// 音频下载进度（已接收/总字节与当前速度）
+    AudioDownloadProgress {
+        song_id: i64,
+        progress: DownloadProgress,
+    },
     /// API 数据加载完成
     DataLoaded(DataPayload),
     /// 错误通知
     Error(String),
-    /// Captcha 生成结果 (captcha_key, url)
-    CaptchaGenerated(std::result::Result<(String, String), String>),
+    /// 可识别的后端错误（限流、未登录、地区限制等）的本地化提示，以 toast 显示
+    ApiHint(String),
+    /// Captcha 生成结果 (captcha_key, url)；失败时为（错误描述, 是否为可自动重试的网络错误）
+    CaptchaGenerated(std::result::Result<(String, String), (String, bool)>),
     /// 登录结果
     LoginResult(std::result::Result<crate::model::auth::LoginResp, String>),
     /// 歌曲详情补全（搜索结果→完整详情）
@@ -52,14 +89,18 @@ pub enum AppMessage {
         index: usize,
         detail: PublicSongDetail,
     },
-    /// 封面图片已上传到终端内存
+    /// 歌曲详情获取失败，清除加载中标记以便之后重试
+    SongDetailFailed(i64),
+    /// 封面图片已解码，附带图形协议的缓存数据
     CoverReady {
         url: String,
         id: u32,
-        upload_seq: Vec<u8>,
+        data: Vec<u8>,
     },
     /// 弹幕下载完成
     DanmakuFetched { title: String, path: String },
+    /// 连接测试结果 (用户名, 耗时毫秒)
+    ConnectionTested(std::result::Result<(String, u128), String>),
 }
 
 /// 后台加载的数据
@@ -69,6 +110,8 @@ pub enum DataPayload {
     Playlists(Vec<PlaylistItem>),
     SearchUsers(Vec<PublicUserProfile>),
     SearchPlaylists(Vec<PlaylistMetadata>),
+    /// 节点加载失败（与「加载成功但为空」区分），附带错误信息
+    LoadFailed(NavNode, String),
 }
 
 /// 输入模式
@@ -81,22 +124,45 @@ pub enum InputMode {
 
 pub struct PlayerState {
     pub engine: PlayerEngine,
+    /// 启动时是否打开了音频输出设备；为 false 时仍可浏览，播放操作只给出提示
+    pub audio_available: bool,
+    /// 引擎代数，每次重启 +1，用于忽略旧引擎退出的通知
+    pub engine_generation: u32,
     pub bar: PlayerBarState,
     pub expanded: bool,
-    pub volume: u8,
-    pub is_muted: bool,
+    /// 音量与静音前的音量
+    pub volume: VolumeState,
     /// 当前播放歌曲的完整详情（用于歌词等展示）
     pub current_detail: Option<PublicSongDetail>,
     /// 解析后的歌词（用于时间同步滚动）
     pub parsed_lyrics: ParsedLyrics,
     /// 展开页是否跟随播放状态（按 n/N 切歌后跟随，j/k 浏览后取消）
     pub follow_playback: bool,
+    /// 展开页详情的滚动偏移（行）
+    pub detail_scroll: u16,
+    /// 上次渲染时详情可滚动的最大偏移
+    pub detail_max_scroll: u16,
+    /// 自动滚动的步进计数（用户手动滚动后为 None，暂停自动滚动）
+    pub credits_roll_step: Option<u16>,
+    /// 正在下载音频的歌曲 ID，用于丢弃过期的下载进度
+    pub fetching_song_id: Option<i64>,
+    /// 连按 n/N 时的防抖：最终落点与最后一次按键时间，停顿后在 PlayerTick 中开始下载
+    pub pending_skip: Option<(MusicQueueItem, std::time::Instant)>,
+    /// 当前下载由播完自动切歌发起（受限歌曲此时自动跳过）
+    pub auto_advance: bool,
+    /// 自动切歌时连续跳过的受限歌曲数，超过队列长度后停止，避免整个队列受限时无限循环
+    pub restricted_skips: usize,
+    /// 正在预载（或已交给引擎）的下一首歌曲 ID，用于淡入淡出
+    pub preload_song_id: Option<i64>,
+    /// 已交给引擎预载的下一首详情，引擎开始播放它时成为当前歌曲
+    pub preloaded: Option<PublicSongDetail>,
 }
 
-/// 已上传到终端的封面条目
+/// 已就绪的封面条目
 struct CoverEntry {
     id: u32,
-    upload_seq: Vec<u8>,
+    /// 图形协议的缓存数据（Kitty 为上传序列，Sixel 为原始像素），见 `GraphicsProtocol::prepare_cover`
+    data: Vec<u8>,
 }
 
 /// 封面图片缓存：统一管理 URL→ID 映射、上传序列、加载状态
@@ -108,6 +174,8 @@ pub struct CoverCache {
     /// 正在下载的 URL
     loading: HashSet<String>,
     next_id: u32,
+    /// 所有条目缓存数据的总字节数
+    total_bytes: usize,
 }
 
 impl CoverCache {
@@ -117,6 +185,7 @@ impl CoverCache {
             ids: HashMap::new(),
             loading: HashSet::new(),
             next_id: 1,
+            total_bytes: 0,
         }
     }
 
@@ -135,6 +204,11 @@ impl CoverCache {
         self.entries.len()
     }
 
+    /// 已缓存封面数据的总字节数
+    pub fn total_bytes(&self) -> usize {
+        self.total_bytes
+    }
+
     /// 分配新 image ID
     pub fn alloc_id(&mut self) -> u32 {
         let id = self.next_id;
@@ -148,10 +222,13 @@ impl CoverCache {
     }
 
     /// 封面就绪，记录 ID 和上传序列
-    pub fn mark_loaded(&mut self, url: String, id: u32, upload_seq: Vec<u8>) {
+    pub fn mark_loaded(&mut self, url: String, id: u32, data: Vec<u8>) {
         self.loading.remove(&url);
         self.ids.insert(url.clone(), id);
-        self.entries.insert(url, CoverEntry { id, upload_seq });
+        self.total_bytes += data.len();
+        if let Some(old) = self.entries.insert(url, CoverEntry { id, data }) {
+            self.total_bytes -= old.data.len();
+        }
     }
 
     /// 淘汰任意一条旧记录，返回 (url, image_id)
@@ -159,18 +236,42 @@ impl CoverCache {
         let url = self.entries.keys().next()?.to_owned();
         let entry = self.entries.remove(&url)?;
         self.ids.remove(&url);
+        self.total_bytes -= entry.data.len();
         Some((url, entry.id))
     }
 
-    /// 所有已上传序列（供终端缩放后重传）
-    pub fn all_upload_seqs(&self) -> impl Iterator<Item = &[u8]> {
-        self.entries.values().map(|e| e.upload_seq.as_slice())
+    /// 所有封面的 (image ID, 缓存数据)，供图形协议放置与缩放后重传
+    pub fn all_data(&self) -> Vec<(u32, &[u8])> {
+        self.entries.values().map(|e| (e.id, e.data.as_slice())).collect()
     }
 
     /// URL → image ID 映射，供渲染层借用
     pub fn id_map(&self) -> &HashMap<String, u32> {
         &self.ids
     }
+
+    /// 正在下载的 URL 集合，供渲染层预留封面位置
+    pub fn loading_set(&self) -> &HashSet<String> {
+        &self.loading
+    }
+}
+
+/// 选中焦点：决定预览数据、详情补全、封面加载的全部输入。
+/// 任一字段变化（移动选中项，或新数据让光标下的内容变了）才需要重新触发这些后处理
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct NavFocus {
+    pub node: NavNode,
+    pub selected: usize,
+    pub preview: Option<NavNode>,
+    pub song_id: Option<i64>,
+    pub cover_url: Option<String>,
+}
+
+impl NavFocus {
+    /// 与上次执行后处理时的焦点相比是否变化；从未执行过也算变化
+    pub(crate) fn changed_since(&self, last: Option<&NavFocus>) -> bool {
+        last != Some(self)
+    }
 }
 
 pub struct DataCache {
@@ -180,6 +281,12 @@ pub struct DataCache {
     pub search_users: Vec<PublicUserProfile>,
     pub search_playlists: Vec<PlaylistMetadata>,
     pub loading: HashSet<NavNode>,
+    /// 加载失败、等待按 r 重试的节点（不会自动重新请求）
+    pub failed: HashSet<NavNode>,
+    /// 防抖中的预览数据加载：选中项移动时覆盖，停留足够久后在 PlayerTick 中触发
+    pub(crate) pending_preview_load: Option<(NavNode, std::time::Instant)>,
+    /// 上一次执行 `after_nav_move` 时的选中焦点，数据到达后焦点未变则跳过重复处理
+    pub(crate) last_nav_focus: Option<NavFocus>,
     /// 正在补全详情的歌曲 ID
     pub(crate) detail_loading: HashSet<i64>,
     /// 队列项的完整歌曲详情缓存（按歌曲 ID）
@@ -192,15 +299,88 @@ pub struct UiState {
     pub show_help: bool,
     pub help_scroll: u16,
     pub show_logs: bool,
+    pub show_stats: bool,
+    /// 歌曲信息浮层（按 I 打开时的歌曲快照）与滚动位置
+    pub info_song: Option<PublicSongDetail>,
+    pub info_scroll: u16,
+    /// 固定节点浮层与其中选中的条目
+    pub show_pinned: bool,
+    pub pinned_selected: usize,
+    /// 已隐藏歌曲浮层与其中选中的条目
+    pub show_hidden: bool,
+    pub hidden_selected: usize,
+    /// 按 o 后等待数字键选择要打开的外部链接
+    pub pending_link_open: bool,
+    /// 退出确认浮层
+    pub show_quit_confirm: bool,
+    /// 随机模式下队列按实际播放顺序显示（按 z 切回队列顺序）
+    pub queue_shuffle_view: bool,
+    /// 最近一次队列排序方式，再按 O 时切换到下一种
+    pub queue_sort: Option<crate::model::queue::QueueSort>,
     pub logs: LogStore,
     pub scroll_tick: u16,
+    /// 当前显示的短暂提示
+    pub toast: Option<Toast>,
+    /// 最近一次加入队列的歌曲 ID 与时间，用于识别连按重复添加
+    pub last_queue_add: Option<(i64, std::time::Instant)>,
+    /// 按 u 跳转艺人主页的轮换状态：(可跳转的艺人 uid 列表, 当前下标)
+    pub artist_jump: Option<(Vec<i64>, usize)>,
+    /// 最近一次写入的窗口标题（None 表示从未设置过，退出时无需恢复）
+    pub window_title: Option<String>,
+    /// 终端是否处于焦点（仅在开启焦点相关设置时由焦点事件更新）
+    pub focused: bool,
+}
+
+/// 收听统计：累计值持久化到 `stats.json`，本次会话单独计数
+pub struct StatsState {
+    pub all_time: ListeningStats,
+    pub session: ListeningStats,
+    /// 上一次进度事件的位置，用于累计实际收听时长
+    pub last_position_secs: Option<u32>,
 }
 
 pub struct CoverState {
-    pub kitty_supported: bool,
+    /// 封面使用的图形协议，终端不支持时为 None（不下载封面）
+    pub protocol: Option<GraphicsProtocol>,
+    pub backend: Option<Box<dyn CoverBackend>>,
     pub pending_cover_load: Option<(String, std::time::Instant)>,
-    pub active_cover_ids: Vec<u32>,
-    pub needs_cover_reupload: bool,
+    /// Sixel 图片无法单独删除，放置变化时在下次 draw 前整屏重绘
+    pub needs_full_redraw: bool,
+}
+
+impl CoverState {
+    pub fn supported(&self) -> bool {
+        self.protocol.is_some()
+    }
+}
+
+/// 定期自动保存（见 `Settings::autosave_secs`）
+pub(crate) struct AutoSaveState {
+    pub last_at: std::time::Instant,
+    /// 设置有尚未写入的改动（如音量）
+    pub settings_dirty: bool,
+    /// 上次写入的队列内容，未变化时不重写
+    pub last_queue: Option<String>,
+}
+
+/// 后台网络请求的并发额度，在 spawn 出的任务内获取。
+/// 音频下载单独一份更大的额度，快速浏览时大量封面/详情/列表请求不会拖慢播放。
+#[derive(Clone)]
+pub struct FetchLimits {
+    /// 音频下载（含取详情拿 audio_url），额度为 `max_concurrency` 的两倍
+    pub audio: Arc<Semaphore>,
+    /// 封面、歌曲详情、列表加载，额度为 `max_concurrency`
+    pub background: Arc<Semaphore>,
+}
+
+impl FetchLimits {
+    fn new(max_concurrency: usize) -> Self {
+        let max = max_concurrency.max(1);
+        Self {
+            audio: Arc::new(Semaphore::new(max * 2)),
+            background: Arc::new(Semaphore::new(max)),
+        }
+    }
 }
 
 pub struct App {
@@ -209,12 +389,20 @@ pub struct App {
     pub client: MamboClient,
     pub player: PlayerState,
     pub queue: QueueState,
+    pub(crate) queue_undo: QueueUndo,
     pub cache: DataCache,
     pub nav: NavStack,
     pub search: SearchState,
+    pub search_history: SearchHistory,
+    pub pinned: PinnedNodes,
+    pub favorite_tags: FavoriteTags,
+    pub hidden: HiddenSongs,
     pub ui: UiState,
     pub cover: CoverState,
     pub login: LoginState,
+    pub stats: StatsState,
+    pub(crate) fetch_limits: FetchLimits,
+    pub(crate) autosave: AutoSaveState,
     pub username: Option<String>,
     pub msg_tx: mpsc::UnboundedSender<AppMessage>,
     msg_rx: mpsc::UnboundedReceiver<AppMessage>,
@@ -276,15 +464,20 @@ impl App {
         crate::ui::i18n::set_lang(settings.display.language);
 
         let volume = settings.player.volume;
-        let input_mode = if has_auth {
+        let input_mode = if has_auth || settings.allow_anonymous {
             InputMode::Normal
         } else {
             InputMode::Login
         };
 
-        // 创建播放引擎
-        let engine = PlayerEngine::spawn()?;
-        engine.set_volume(volume as f32 / 100.0);
+        // 创建播放引擎；没有音频设备（无头环境、容器）时以无音频模式启动，浏览不受影响
+        let (engine, audio_error) = match PlayerEngine::spawn() {
+            Ok(engine) => (engine, None),
+            Err(e) => (PlayerEngine::unavailable(), Some(e)),
+        };
+        let audio_available = audio_error.is_none();
+        engine.set_volume(VolumeState::new(volume).gain());
+        engine.set_crossfade(std::time::Duration::from_secs(settings.player.crossfade_secs.into()));
 
         // 加载或创建播放队列
         let queue = QueueState::load_persisted().unwrap_or_else(|_| QueueState::new());
@@ -295,37 +488,144 @@ impl App {
             None
         };
 
+        // 启动时记录实际使用的目录，便于确认环境变量覆盖是否生效
+        let mut logs = LogStore::new();
+        let dir_str = |dir: Result<std::path::PathBuf>| match dir {
+            Ok(d) => d.display().to_string(),
+            Err(e) => format!("<{e}>"),
+        };
+        logs.push(
+            LogLevel::Info,
+            format!(
+                "state dir: {}  cache dir: {}",
+                dir_str(crate::config::paths::config_dir()),
+                dir_str(crate::config::paths::cache_dir()),
+            ),
+        );
+        let graphics = crate::ui::graphics::detect(settings.display.graphics);
+        logs.push(
+            LogLevel::Info,
+            match graphics {
+                Some((protocol, var)) => format!("{} graphics: enabled (detected via {var})", protocol.name()),
+                None => "graphics: disabled (no kitty / sixel terminal detected)".to_string(),
+            },
+        );
+        let tmux_passthrough =
+            crate::ui::kitty::detect_tmux_passthrough(settings.display.tmux_passthrough);
+        crate::ui::kitty::set_tmux_passthrough(tmux_passthrough);
+        if tmux_passthrough {
+            logs.push(LogLevel::Info, "kitty graphics: wrapping sequences for tmux passthrough".to_string());
+        }
+        logs.mark_read();
+
+        for name in crate::ui::theme::set_accents(
+            &settings.display.selection_color,
+            &settings.display.now_playing_color,
+        ) {
+            logs.push(LogLevel::Warn, format!("无法识别的颜色：{name}，使用默认值"));
+        }
+
+        if let Some(e) = audio_error {
+            logs.push(LogLevel::Warn, format!("{e}，以无音频模式启动，仅可浏览"));
+        }
+
+        // 透传配置问题以未读警告提示（仅 Kitty 需要透传）
+        let uses_kitty = graphics.is_some_and(|(p, _)| p == GraphicsProtocol::Kitty);
+        if uses_kitty && tmux_passthrough && crate::ui::kitty::tmux_allows_passthrough() == Some(false) {
+            logs.push(
+                LogLevel::Warn,
+                "tmux allow-passthrough 未开启，封面无法显示：请执行 `tmux set -g allow-passthrough on`".to_string(),
+            );
+        } else if uses_kitty && !tmux_passthrough && std::env::var_os("STY").is_some() {
+            logs.push(LogLevel::Warn, "GNU screen 不支持透传 Kitty 图形序列，封面可能无法显示".to_string());
+        }
+
+        let all_time_stats = ListeningStats::load().unwrap_or_else(|e| {
+            logs.push(LogLevel::Warn, format!("读取收听统计失败，将重新计数：{e}"));
+            ListeningStats::default()
+        });
+
+        let search_history = SearchHistory::load().unwrap_or_else(|e| {
+            logs.push(LogLevel::Warn, format!("读取搜索历史失败：{e}"));
+            SearchHistory::default()
+        });
+        let pinned = PinnedNodes::load().unwrap_or_else(|e| {
+            logs.push(LogLevel::Warn, format!("读取固定节点失败：{e}"));
+            PinnedNodes::default()
+        });
+        let favorite_tags = FavoriteTags::load().unwrap_or_else(|e| {
+            logs.push(LogLevel::Warn, format!("读取收藏标签失败：{e}"));
+            FavoriteTags::default()
+        });
+        let hidden = HiddenSongs::load().unwrap_or_else(|e| {
+            logs.push(LogLevel::Warn, format!("读取隐藏列表失败：{e}"));
+            HiddenSongs::default()
+        });
+
+        let fetch_limits = FetchLimits::new(settings.server.max_concurrency);
+
         Ok(Self {
             running: true,
             settings,
             client,
             nav: NavStack::new(),
             search: SearchState::new(),
+            search_history,
+            pinned,
+            favorite_tags,
+            hidden,
             ui: UiState {
                 input_mode,
                 show_help: false,
                 help_scroll: 0,
                 show_logs: false,
-                logs: LogStore::new(),
+                show_stats: false,
+                info_song: None,
+                info_scroll: 0,
+                show_pinned: false,
+                pinned_selected: 0,
+                show_hidden: false,
+                hidden_selected: 0,
+                pending_link_open: false,
+                show_quit_confirm: false,
+                queue_shuffle_view: true,
+                queue_sort: None,
+                logs,
                 scroll_tick: 0,
+                toast: None,
+                last_queue_add: None,
+                artist_jump: None,
+                window_title: None,
+                focused: true,
             },
             cover: CoverState {
-                kitty_supported: crate::ui::kitty::is_supported(),
+                protocol: graphics.map(|(p, _)| p),
+                backend: graphics.map(|(p, _)| p.backend()),
                 pending_cover_load: None,
-                active_cover_ids: Vec::new(),
-                needs_cover_reupload: false,
+                needs_full_redraw: false,
             },
             player: PlayerState {
                 engine,
+                audio_available,
+                engine_generation: 0,
                 bar: PlayerBarState::default(),
                 expanded: false,
-                volume,
-                is_muted: false,
+                volume: VolumeState::new(volume),
                 current_detail: None,
                 parsed_lyrics: ParsedLyrics::Empty,
                 follow_playback: true,
+                detail_scroll: 0,
+                detail_max_scroll: 0,
+                credits_roll_step: Some(0),
+                fetching_song_id: None,
+                pending_skip: None,
+                auto_advance: false,
+                restricted_skips: 0,
+                preload_song_id: None,
+                preloaded: None,
             },
             queue,
+            queue_undo: QueueUndo::default(),
             cache: DataCache {
                 songs: HashMap::new(),
                 tags: None,
@@ -333,11 +633,25 @@ impl App {
                 search_users: Vec::new(),
                 search_playlists: Vec::new(),
                 loading: HashSet::new(),
+                failed: HashSet::new(),
+                pending_preview_load: None,
+                last_nav_focus: None,
                 detail_loading: HashSet::new(),
                 queue_song_detail: HashMap::new(),
                 covers: CoverCache::new(),
             },
             login: LoginState::new(),
+            stats: StatsState {
+                all_time: all_time_stats,
+                session: ListeningStats::default(),
+                last_position_secs: None,
+            },
+            fetch_limits,
+            autosave: AutoSaveState {
+                last_at: std::time::Instant::now(),
+                settings_dirty: false,
+                last_queue: None,
+            },
             username: saved_username,
             msg_tx,
             msg_rx,
@@ -345,16 +659,61 @@ impl App {
         })
     }
 
+    /// 显示一条短暂提示（覆盖上一条）
+    pub(crate) fn show_toast(&mut self, kind: ToastKind, message: String) {
+        self.ui.toast = Some(Toast::new(kind, message));
+    }
+
+    /// 监听播放引擎事件，转发为 AppMessage；通道关闭时通知主循环
+    pub(crate) fn forward_player_events(&mut self) {
+        if !self.player.audio_available {
+            return;
+        }
+        let player_tx = self.msg_tx.clone();
+        let mut player_rx = self.player.engine.take_event_receiver();
+        let generation = self.player.engine_generation;
+        tokio::spawn(async move {
+            while let Some(event) = player_rx.recv().await {
+                if player_tx.send(AppMessage::PlayerStateChanged(event)).is_err() {
+                    return;
+                }
+            }
+            let _ = player_tx.send(AppMessage::PlayerEngineExited(generation));
+        });
+    }
+
     pub async fn run(&mut self) -> Result<()> {
-        let mut terminal = ratatui::init();
+        let (mut terminal, alternate) = init_terminal(self.settings.display.alternate_screen)?;
+        if self.settings.display.alternate_screen && !alternate {
+            self.ui.logs.push(LogLevel::Warn, "终端不支持备用屏幕，改为在当前屏幕上绘制".to_string());
+        }
+
+        // 只在需要时开启焦点上报，避免不支持的终端收到多余的转义序列
+        let display = &self.settings.display;
+        let focus_reporting = display.refresh_on_focus || display.freeze_when_unfocused;
+        if focus_reporting {
+            let _ = crossterm::execute!(std::io::stdout(), crossterm::event::EnableFocusChange);
+        }
 
         let result = self.main_loop(&mut terminal).await;
 
-        // 退出时同步进度并持久化队列
-        self.queue.position_ms = (self.player.bar.current_secs as u64) * 1000;
+        // 退出时持久化队列（`position_ms` 由播放进度事件按毫秒实时更新）、未保存的设置与收听统计
         let _ = self.queue.persist();
+        if self.autosave.settings_dirty {
+            let _ = self.settings.save();
+        }
+        let _ = self.stats.all_time.save();
 
-        ratatui::restore();
+        if self.ui.window_title.is_some() {
+            use std::io::Write;
+            let _ = std::io::stdout().write_all(crate::ui::window_title::POP_TITLE);
+            let _ = std::io::stdout().flush();
+        }
+        if focus_reporting {
+            let _ = crossterm::execute!(std::io::stdout(), crossterm::event::DisableFocusChange);
+        }
+        drop(terminal);
+        restore_terminal(alternate);
 
         result
     }
@@ -365,18 +724,7 @@ impl App {
     ) -> Result<()> {
         // 启动持久的事件读取线程，避免 select! + spawn_blocking 丢事件
         let event_tx = self.msg_tx.clone();
-        std::thread::spawn(move || {
-            loop {
-                match crossterm::event::read() {
-                    Ok(ev) => {
-                        if event_tx.send(AppMessage::TermEvent(ev)).is_err() {
-                            break;
-                        }
-                    }
-                    Err(_) => break,
-                }
-            }
-        });
+        std::thread::spawn(move || read_term_events(event_tx));
 
         // UI 定时 tick（驱动文字滚动动画、播放进度等）
         let tick_tx = self.msg_tx.clone();
@@ -391,43 +739,155 @@ impl App {
             }
         });
 
-        // 监听播放引擎事件，转发为 AppMessage
-        let player_tx = self.msg_tx.clone();
-        let mut player_rx = self.player.engine.take_event_receiver();
-        tokio::spawn(async move {
-            while let Some(event) = player_rx.recv().await {
-                if player_tx.send(AppMessage::PlayerStateChanged(event)).is_err() {
-                    break;
-                }
-            }
-        });
+        self.forward_player_events();
 
-        // 启动时仅恢复播放栏 UI，不自动播放
+        // 已登录时恢复上次的队列，是否自动播放由设置决定
         if self.resume_position_ms.is_some() {
-            if let Some(song) = self.queue.current_song() {
-                self.player.bar.title = song.name.clone();
-                self.player.bar.artist = song.artist.clone();
-                self.player.bar.total_secs = song.duration_secs as u32;
-                self.player.bar.current_secs =
-                    (self.resume_position_ms.unwrap_or(0) / 1000) as u32;
-                self.player.bar.cover_url = song.cover_url.clone();
-            }
+            self.restore_last_session();
         }
 
+        // 按设置在后台预取队列中当前项附近的歌曲详情，减少启动后切歌的等待
+        self.prefetch_queue_details();
+
         while self.running {
+            if std::mem::take(&mut self.cover.needs_full_redraw) {
+                terminal.clear()?;
+            }
             terminal.draw(|f| self.render(f))?;
             // draw 结束后，将本帧收集的封面放置请求写入终端（光标定位放置，无 cursor-position 歧义）
             let _ = self.render_cover_placements();
+            let _ = self.sync_window_title();
 
             // 等待至少一条消息
-            if let Some(msg) = self.msg_rx.recv().await {
-                self.handle_message(msg).await;
+            let Some(first) = self.msg_rx.recv().await else { continue };
+            // 批量处理已积压的消息（最多 MAX_BATCH 条），避免每条消息都触发一次 draw；
+            // 高频的 tick 与进度只处理最新一条，见 `batch::coalesce`
+            let mut pending = vec![first];
+            while pending.len() < batch::MAX_BATCH
+                && let Ok(msg) = self.msg_rx.try_recv()
+            {
+                pending.push(msg);
             }
-            // 批量处理所有已积压的消息，避免每条消息都触发一次 draw
-            while let Ok(msg) = self.msg_rx.try_recv() {
+            for msg in batch::coalesce(pending) {
                 self.handle_message(msg).await;
             }
         }
         Ok(())
     }
 }
+
+/// 进入 raw mode 并尝试切换到备用屏幕，返回终端与是否已进入备用屏幕。
+/// 备用屏幕切换失败（或 `want_alternate` 为 false）时在当前屏幕上全屏绘制；
+/// raw mode 都无法开启时恢复终端并返回错误，不进入半初始化的界面
+fn init_terminal(want_alternate: bool) -> Result<(ratatui::DefaultTerminal, bool)> {
+    use anyhow::Context;
+    use crossterm::terminal::{EnterAlternateScreen, enable_raw_mode};
+
+    enable_raw_mode().context("终端不支持 raw mode，无法启动界面")?;
+    let alternate = want_alternate
+        && std::env::var("TERM").as_deref() != Ok("dumb")
+        && crossterm::execute!(std::io::stdout(), EnterAlternateScreen).is_ok();
+
+    // panic 时按实际进入的模式恢复终端
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        restore_terminal(alternate);
+        hook(info);
+    }));
+
+    match ratatui::Terminal::new(ratatui::backend::CrosstermBackend::new(std::io::stdout())) {
+        Ok(terminal) => Ok((terminal, alternate)),
+        Err(e) => {
+            restore_terminal(alternate);
+            Err(e).context("无法获取终端尺寸")
+        }
+    }
+}
+
+/// 退出 raw mode，并按进入时的模式离开备用屏幕或清掉在当前屏幕上绘制的界面
+fn restore_terminal(alternate: bool) {
+    use crossterm::terminal::{Clear, ClearType, LeaveAlternateScreen, disable_raw_mode};
+
+    let _ = disable_raw_mode();
+    let mut stdout = std::io::stdout();
+    let _ = if alternate {
+        crossterm::execute!(stdout, LeaveAlternateScreen)
+    } else {
+        crossterm::execute!(stdout, Clear(ClearType::All), crossterm::cursor::MoveTo(0, 0))
+    };
+    let _ = crossterm::execute!(stdout, crossterm::cursor::Show);
+}
+
+/// 终端事件读取循环。
+/// SSH 链路抖动等暂时性错误按指数退避重试，保证播放不因终端短暂异常而中断；
+/// 终端真正断开（EOF/管道关闭）或连续失败超过上限时通知主循环退出。
+fn read_term_events(event_tx: mpsc::UnboundedSender<AppMessage>) {
+    use std::io::ErrorKind;
+
+    let mut failures = 0u32;
+    loop {
+        match crossterm::event::read() {
+            Ok(ev) => {
+                failures = 0;
+                if event_tx.send(AppMessage::TermEvent(ev)).is_err() {
+                    return;
+                }
+            }
+            Err(e) if matches!(
+                e.kind(),
+                ErrorKind::UnexpectedEof | ErrorKind::BrokenPipe | ErrorKind::NotConnected
+            ) => {
+                let _ = event_tx.send(AppMessage::TermInputClosed(e.to_string()));
+                return;
+            }
+            Err(e) => {
+                failures += 1;
+                if failures > TERM_READ_MAX_RETRIES {
+                    let _ = event_tx.send(AppMessage::TermInputClosed(e.to_string()));
+                    return;
+                }
+                if event_tx.send(AppMessage::TermReadRetry(failures, e.to_string())).is_err() {
+                    return;
+                }
+                let backoff = TERM_READ_BACKOFF_MS
+                    .saturating_mul(1 << (failures - 1))
+                    .min(TERM_READ_BACKOFF_MAX_MS);
+                std::thread::sleep(std::time::Duration::from_millis(backoff));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NavFocus;
+    use crate::ui::navigation::NavNode;
+
+    fn on_categories(cover_url: Option<&str>) -> NavFocus {
+        NavFocus {
+            node: NavNode::Categories,
+            selected: 0,
+            preview: Some(NavNode::Tag { name: "VOCALOID".to_string() }),
+            song_id: None,
+            cover_url: cover_url.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn unchanged_focus_skips_post_processing() {
+        // 进入分类列表时已执行过一次后处理
+        let mut last = Some(on_categories(None));
+        let mut runs = 0;
+        // 一批 30 条与光标无关的 DataLoaded，最后一条是选中标签的歌曲（带来预览封面）
+        let burst = std::iter::repeat_n(on_categories(None), 30).chain([on_categories(Some("cover"))]);
+        for now in burst {
+            if now.changed_since(last.as_ref()) {
+                runs += 1;
+                last = Some(now);
+            }
+        }
+        // 去重前每条消息都执行一次 after_nav_move，共 31 次
+        assert_eq!(runs, 1);
+        assert!(on_categories(None).changed_since(None));
+    }
+}
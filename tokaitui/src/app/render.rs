@@ -1,50 +1,53 @@
 use ratatui::{
     Frame,
-    layout::{Constraint, Layout, Rect},
+    layout::{Alignment, Constraint, Layout, Rect},
     style::{Color, Style},
     text::{Line, Span},
     widgets::Paragraph,
 };
 use unicode_width::UnicodeWidthStr;
 
-use crate::ui::constants::{HEADER_HEIGHT, PLAYER_BAR_HEIGHT, SEARCH_BAR_HEIGHT};
+use crate::config::settings::UiLayout;
+use crate::ui::constants::{BAR_THUMBNAIL_WIDTH, BREADCRUMB_HEIGHT, HEADER_HEIGHT, PLAYER_BAR_HEIGHT, SEARCH_BAR_HEIGHT};
+use crate::ui::header::{SegmentRank, fit_segments};
 use crate::ui::navigation::NavNode;
 
 use super::{App, InputMode};
 
 impl App {
     pub(crate) fn render(&mut self, frame: &mut Frame) {
+        // 迷你播放器不显示顶栏，内容区全部留给正在播放信息
+        let mini = self.showing_mini_player();
         let chunks = Layout::vertical([
-                Constraint::Length(HEADER_HEIGHT),
+                Constraint::Length(if mini { 0 } else { HEADER_HEIGHT }),
                 Constraint::Min(1),
                 Constraint::Length(PLAYER_BAR_HEIGHT),
             ])
             .split(frame.area());
 
-        self.render_header(frame, chunks[0]);
+        if !mini {
+            self.render_header(frame, chunks[0]);
+        }
 
         // 浮层打开时跳过底层内容渲染，避免 Kitty 图片协议残留
-        let has_overlay = self.ui.show_help || self.ui.show_logs;
+        let has_overlay = self.ui.show_help
+            || self.ui.show_logs
+            || self.ui.show_quit_confirm
+            || self.ui.info_song.is_some()
+            || self.ui.show_pinned
+            || self.ui.show_hidden;
 
         match self.ui.input_mode {
             InputMode::Login => {
                 crate::ui::login::render(frame, chunks[1], &self.login);
             }
             _ if !has_overlay => {
-                if self.player.expanded {
+                if mini {
+                    crate::ui::mini_player::render(frame, chunks[1], &self.player.bar, &self.player.parsed_lyrics);
+                } else if self.player.expanded {
                     self.render_player_view(frame, chunks[1]);
-                } else if self.nav.current().node == NavNode::Settings {
-                    self.render_settings(frame, chunks[1]);
-                } else if self.ui.input_mode == InputMode::Search
-                    || self.nav.contains(&NavNode::SearchResults)
-                {
-                    // 搜索模式或搜索结果导航中：顶部搜索栏 + 下方 miller
-                    let search_chunks = Layout::vertical([Constraint::Length(SEARCH_BAR_HEIGHT), Constraint::Min(1)])
-                        .split(chunks[1]);
-                    self.render_search_bar(frame, search_chunks[0]);
-                    self.render_miller(frame, search_chunks[1]);
                 } else {
-                    self.render_miller(frame, chunks[1]);
+                    self.render_browser(frame, chunks[1]);
                 }
             }
             _ => {}
@@ -52,20 +55,129 @@ impl App {
 
         if !has_overlay {
             self.render_player_bar(frame, chunks[2]);
+            if let Some(toast) = &self.ui.toast {
+                crate::ui::toast::render(frame, chunks[1], toast);
+            }
         }
 
         if self.ui.show_logs {
             crate::ui::log_view::render(frame, frame.area(), &self.ui.logs);
         }
 
+        if self.ui.show_stats {
+            crate::ui::stats_view::render(
+                frame,
+                frame.area(),
+                &self.stats.session,
+                &self.stats.all_time,
+            );
+        }
+
+        if let Some(song) = &self.ui.info_song {
+            crate::ui::info_view::render(frame, frame.area(), song, self.ui.info_scroll);
+        }
+
+        if self.ui.show_pinned {
+            crate::ui::pinned_view::render(
+                frame,
+                frame.area(),
+                &self.pinned.nodes,
+                self.ui.pinned_selected,
+            );
+        }
+
+        if self.ui.show_hidden {
+            crate::ui::hidden_view::render(
+                frame,
+                frame.area(),
+                &self.hidden.songs,
+                self.ui.hidden_selected,
+            );
+        }
+
         if self.ui.show_help {
             crate::ui::help::render(frame, frame.area(), self.ui.help_scroll);
         }
+
+        if self.ui.show_quit_confirm {
+            crate::ui::confirm::render(frame, frame.area(), t!("confirm.quit.title"), t!("confirm.quit.message"));
+        }
     }
 
-    fn render_header(&self, frame: &mut Frame, area: ratatui::layout::Rect) {
-        use ratatui::layout::Alignment;
+    /// 浏览区（设置页、搜索栏与分栏）按 `display.layout` 选择布局
+    fn render_browser(&mut self, frame: &mut Frame, area: Rect) {
+        match self.settings.display.layout {
+            UiLayout::Classic => self.render_browser_classic(frame, area),
+            UiLayout::Modern => self.render_browser_modern(frame, area),
+        }
+    }
 
+    /// 经典布局：面包屑在顶栏，搜索栏只在搜索模式或搜索结果导航中出现
+    fn render_browser_classic(&mut self, frame: &mut Frame, area: Rect) {
+        if self.nav.current().node == NavNode::Settings {
+            self.render_settings(frame, area);
+        } else if self.ui.input_mode == InputMode::Search
+            || self.nav.contains(&NavNode::SearchResults)
+        {
+            // 搜索模式或搜索结果导航中：顶部搜索栏 + 下方 miller
+            let search_chunks = Layout::vertical([Constraint::Length(SEARCH_BAR_HEIGHT), Constraint::Min(1)])
+                .split(area);
+            self.render_search_bar(frame, search_chunks[0]);
+            self.render_search_body(frame, search_chunks[1]);
+        } else {
+            self.render_miller(frame, area);
+        }
+    }
+
+    /// 新版布局：面包屑独占一行，搜索栏常驻在分栏上方（设置页除外）
+    fn render_browser_modern(&mut self, frame: &mut Frame, area: Rect) {
+        let rows = Layout::vertical([Constraint::Length(BREADCRUMB_HEIGHT), Constraint::Min(1)])
+            .split(area);
+        self.render_breadcrumb(frame, rows[0]);
+        if self.nav.current().node == NavNode::Settings {
+            self.render_settings(frame, rows[1]);
+            return;
+        }
+        let search_chunks = Layout::vertical([Constraint::Length(SEARCH_BAR_HEIGHT), Constraint::Min(1)])
+            .split(rows[1]);
+        self.render_search_bar(frame, search_chunks[0]);
+        self.render_search_body(frame, search_chunks[1]);
+    }
+
+    /// 搜索栏下方：空搜索框时列出最近搜索词，否则为 miller
+    fn render_search_body(&mut self, frame: &mut Frame, area: Rect) {
+        if self.showing_search_suggestions() {
+            self.render_search_suggestions(frame, area);
+        } else {
+            self.render_miller(frame, area);
+        }
+    }
+
+    /// 新版布局的面包屑行，过长时从左侧截断
+    fn render_breadcrumb(&self, frame: &mut Frame, area: Rect) {
+        let crumb = self.nav.breadcrumb();
+        let crumb = if crumb.is_empty() { NavNode::Root.display_name().to_string() } else { crumb };
+        let max = (area.width as usize).saturating_sub(2);
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                format!("  {}", crate::ui::format::truncate_left_with_dots(&crumb, max)),
+                crate::ui::theme::Theme::active(),
+            )),
+            area,
+        );
+    }
+
+    /// 迷你播放器模式（登录界面不受影响）
+    pub(crate) fn showing_mini_player(&self) -> bool {
+        self.settings.display.mini_player && self.ui.input_mode != InputMode::Login
+    }
+
+    /// 终端宽度低于阈值时使用单栏布局
+    fn is_compact(&self, frame: &Frame) -> bool {
+        frame.area().width < self.settings.display.compact_width
+    }
+
+    fn render_header(&self, frame: &mut Frame, area: ratatui::layout::Rect) {
         let status = if let Some(name) = &self.username {
             Span::styled(
                 format!("  {name}"),
@@ -88,13 +200,14 @@ impl App {
         // 右侧色块段
         let mode_str = match self.settings.player.default_play_mode {
             crate::config::settings::PlayMode::Sequential => " [>] ",
+            crate::config::settings::PlayMode::RepeatAll => " [o] ",
             crate::config::settings::PlayMode::Shuffle => " [x] ",
             crate::config::settings::PlayMode::RepeatOne => " [1] ",
         };
-        let vol_str = if self.player.is_muted {
-            " vol -- ".to_string()
+        let vol_str = if self.player.volume.is_muted() {
+            format!(" vol -- ({}%) ", self.player.volume.target())
         } else {
-            format!(" vol {}% ", self.player.volume)
+            format!(" vol {}% ", self.player.volume.level())
         };
         let now = chrono::Local::now();
         let time_str = now.format(" %H:%M ").to_string();
@@ -102,26 +215,54 @@ impl App {
         let block_bg = Style::default().fg(Color::Black).bg(Color::DarkGray);
         let block_accent = Style::default().fg(Color::Black).bg(Color::Cyan);
 
-        let mut right_spans: Vec<Span> = Vec::new();
+        let mut segments: Vec<(Span, SegmentRank)> = Vec::new();
 
         if self.ui.logs.unread_count > 0 {
-            right_spans.push(Span::styled(
+            segments.push((Span::styled(
                 format!(" ! {} ", self.ui.logs.unread_count),
                 Style::default().fg(Color::White).bg(Color::Red),
-            ));
+            ), SegmentRank::Pinned));
         }
-        right_spans.push(Span::styled(mode_str, block_bg));
-        right_spans.push(Span::styled(vol_str, block_accent));
-        right_spans.push(Span::styled(time_str.clone(), block_bg));
+        if !self.player.audio_available {
+            segments.push((Span::styled(
+                format!(" {} ", t!("app.no_audio")),
+                crate::ui::theme::Theme::warning_badge(),
+            ), SegmentRank::Badge));
+        }
+        if self.settings.data_saver {
+            segments.push((Span::styled(
+                format!(" {} ", t!("app.data_saver")),
+                Style::default().fg(Color::Black).bg(Color::Yellow),
+            ), SegmentRank::Badge));
+        }
+        segments.push((Span::styled(mode_str, block_bg), SegmentRank::Mode));
+        segments.push((Span::styled(vol_str, block_accent), SegmentRank::Volume));
+        segments.push((Span::styled(time_str, block_bg), SegmentRank::Clock));
+
+        // 窄终端：标题必须完整可见，右侧色块按优先级省略（时钟 → 模式 → 音量 → 状态标记）
+        let right_max = (area.width as usize).saturating_sub(title_span.content.width());
+        let right_spans = fit_segments(segments, right_max);
 
         let right_width: u16 = right_spans
             .iter()
             .map(|s| s.content.width() as u16)
             .sum();
 
-        // 左侧
-        let left = Line::from(vec![title_span, status]);
-        let left_p = Paragraph::new(left);
+        // 左侧：标题 + 登录状态 + 面包屑（过长时从左侧截断）
+        let left_max = area.width.saturating_sub(right_width) as usize;
+        let used = title_span.content.width() + status.content.width();
+        let crumb_max = left_max.saturating_sub(used + 4);
+        let crumb = self.nav.breadcrumb();
+        let mut left_spans = vec![title_span, status];
+        // 新版布局的面包屑另占一行，顶栏不再重复
+        if self.settings.display.layout == UiLayout::Classic && !crumb.is_empty() && crumb_max > 2 {
+            left_spans.push(Span::styled("  \u{00b7}  ", crate::ui::theme::Theme::secondary()));
+            left_spans.push(Span::styled(
+                crate::ui::format::truncate_left_with_dots(&crumb, crumb_max),
+                crate::ui::theme::Theme::active(),
+            ));
+        }
+        let left_p = Paragraph::new(Line::from(left_spans));
 
         let right_p = Paragraph::new(Line::from(right_spans))
             .alignment(Alignment::Right);
@@ -134,18 +275,30 @@ impl App {
     }
 
     fn render_miller(&mut self, frame: &mut Frame, area: ratatui::layout::Rect) {
+        let compact = self.is_compact(frame);
+        let queue_order = self.queue_display_order();
         let data = crate::ui::miller::ColumnData {
             song_cache: &self.cache.songs,
             tag_cache: self.cache.tags.as_deref().unwrap_or_default(),
+            favorite_tags: &self.favorite_tags,
             playlist_cache: self.cache.playlists.as_deref().unwrap_or_default(),
             queue: &self.queue,
             queue_detail: &self.cache.queue_song_detail,
+            queue_order: &queue_order,
             loading: &self.cache.loading,
+            failed: &self.cache.failed,
+            authenticated: self.client.is_authenticated_sync(),
             settings: &self.settings,
             search_type: self.search.search_type,
             search_users: &self.cache.search_users,
             search_playlists: &self.cache.search_playlists,
             covers: self.cache.covers.id_map(),
+            covers_loading: self.cache.covers.loading_set(),
+            pending_cover: self.cover.pending_cover_load.as_ref().map(|(url, _)| url.as_str()),
+            cover_placeholders: self.cover.supported(),
+            playing_song_id: self.player.current_detail.as_ref().map(|d| d.id),
+            is_playing: self.player.bar.is_playing,
+            anim_tick: self.ui.scroll_tick,
         };
         crate::ui::miller::render(
             frame,
@@ -153,6 +306,7 @@ impl App {
             &self.nav,
             &data,
             self.ui.scroll_tick,
+            compact,
         );
     }
 
@@ -185,8 +339,12 @@ impl App {
             }
             spans.push(Span::raw(after));
         } else {
-            // 非编辑模式：仅显示查询文本
-            spans.push(Span::raw(query.clone()));
+            // 非编辑模式：仅显示查询文本；常驻搜索栏为空时提示按 / 搜索
+            if query.is_empty() {
+                spans.push(Span::styled(t!("search.placeholder"), crate::ui::theme::Theme::secondary()));
+            } else {
+                spans.push(Span::raw(query.clone()));
+            }
         }
 
         // 右侧排序标签
@@ -209,58 +367,99 @@ impl App {
         frame.render_widget(right_p, cols[1]);
     }
 
+    /// 空搜索框下方列出最近搜索词，↑/↓ 选择、Enter 填入并搜索
+    fn render_search_suggestions(&self, frame: &mut Frame, area: Rect) {
+        use crate::ui::theme::Theme;
+
+        let mut lines = vec![Line::from(Span::styled(
+            format!(" {}", t!("search.recent")),
+            Theme::secondary(),
+        ))];
+        for (i, term) in self.search_history.terms.iter().enumerate() {
+            let selected = self.search.suggestion == Some(i);
+            let marker = if selected { "▸ " } else { "  " };
+            lines.push(Line::from(Span::styled(
+                format!(" {marker}{term}"),
+                Theme::list_item_style(selected, true),
+            )));
+        }
+        frame.render_widget(Paragraph::new(lines), area);
+    }
+
     fn render_player_bar(&self, frame: &mut Frame, area: ratatui::layout::Rect) {
-        crate::ui::player_bar::render(frame, area, &self.player.bar);
+        // 单曲循环或回绕到自身时下一首就是当前歌曲，不提示
+        let next = self.queue
+            .peek_next(&self.settings.player.default_play_mode, self.settings.player.repeat_all_from)
+            .filter(|next| Some(next.id) != self.queue.current_song().map(|s| s.id))
+            .map(|next| next.name.as_str());
+        // 关闭后本帧不再登记放置，Kitty 后端按上帧记录删除小封面
+        let mut area = area;
+        if self.bar_thumbnail_enabled()
+            && area.width > BAR_THUMBNAIL_WIDTH
+            && let Some(&id) = self.cache.covers.id_map().get(&self.player.bar.cover_url)
+        {
+            let thumb = Rect { width: BAR_THUMBNAIL_WIDTH, ..area };
+            frame.render_widget(crate::ui::cover_widget::CoverWidget { image_id: id }, thumb);
+            area.x += BAR_THUMBNAIL_WIDTH;
+            area.width -= BAR_THUMBNAIL_WIDTH;
+        }
+        crate::ui::player_bar::render(frame, area, &self.player.bar, next);
     }
 
-    /// draw 结束后，将本帧收集的封面放置请求写入 stdout
-    /// 每帧先删除所有上帧 placement（用 d=i 保留 image data），再重新放置本帧封面。
-    /// 这样可以正确处理：切换曲目、miller↔player_view 切换导致的位置变化、无封面时的清除。
-    pub(crate) fn render_cover_placements(&mut self) -> anyhow::Result<()> {
+    /// 按设置把窗口标题同步为正在播放的歌曲；标题未变化时不写终端。
+    /// 与封面放置一样在 draw 之后写入，不会插进 ratatui 的帧输出中间
+    pub(crate) fn sync_window_title(&mut self) -> anyhow::Result<()> {
         use std::io::Write;
-        use crate::ui::cover_widget::PENDING_PLACEMENTS;
-
-        let placements: Vec<(u32, ratatui::layout::Rect)> =
-            PENDING_PLACEMENTS.with(|p| p.borrow_mut().drain(..).collect());
+        use crate::ui::window_title;
 
-        let new_ids: Vec<u32> = placements.iter().map(|(id, _)| *id).collect();
-
-        if self.cover.active_cover_ids.is_empty() && placements.is_empty() && !self.cover.needs_cover_reupload {
+        let display = &self.settings.display;
+        let desired = if !display.set_window_title {
+            None
+        } else if self.player.bar.has_song() {
+            Some(window_title::format_title(
+                &display.window_title_format,
+                &self.player.bar.title,
+                &self.player.bar.artist,
+            ))
+        } else {
+            Some(window_title::IDLE_TITLE.to_string())
+        };
+        if desired == self.ui.window_title {
             return Ok(());
         }
 
         let mut out = std::io::stdout().lock();
-
-        // 终端缩放后 image data 被清除，需先重新上传再放置
-        if self.cover.needs_cover_reupload {
-            for seq in self.cache.covers.all_upload_seqs() {
-                out.write_all(seq)?;
+        match (&self.ui.window_title, &desired) {
+            // 关闭设置：恢复原标题
+            (Some(_), None) => out.write_all(window_title::POP_TITLE)?,
+            (None, Some(title)) => {
+                out.write_all(window_title::PUSH_TITLE)?;
+                out.write_all(&window_title::set_title_seq(title))?;
             }
-            self.cover.needs_cover_reupload = false;
-        }
-
-        if self.cover.active_cover_ids.is_empty() && placements.is_empty() {
-            out.flush()?;
-            return Ok(());
+            (_, Some(title)) => out.write_all(&window_title::set_title_seq(title))?,
+            (None, None) => {}
         }
+        out.flush()?;
+        self.ui.window_title = desired;
+        Ok(())
+    }
 
-        out.write_all(b"\x1b7")?;
+    /// draw 结束后，将本帧收集的封面放置请求交给图形后端写入 stdout
+    /// 每帧都按本帧放置重新输出，可以正确处理：切换曲目、miller↔player_view 切换导致的位置变化、无封面时的清除。
+    pub(crate) fn render_cover_placements(&mut self) -> anyhow::Result<()> {
+        use crate::ui::cover_widget::PENDING_PLACEMENTS;
 
-        // 删除所有上帧 placement（d=i 小写：保留 image data，避免 re-place 时数据不存在）
-        for &id in &self.cover.active_cover_ids {
-            out.write_all(&crate::ui::kitty::delete_placement(id))?;
-        }
+        let placements: Vec<(u32, ratatui::layout::Rect)> =
+            PENDING_PLACEMENTS.with(|p| p.borrow_mut().drain(..).collect());
 
-        // 放置本帧所有封面
-        for (id, rect) in &placements {
-            write!(out, "\x1b[{};{}H", rect.y + 1, rect.x + 1)?;
-            out.write_all(&crate::ui::kitty::place_at_cursor(*id, rect.width, rect.height))?;
+        let Some(backend) = self.cover.backend.as_mut() else {
+            return Ok(());
+        };
+        let covers = self.cache.covers.all_data();
+        let mut out = std::io::stdout().lock();
+        if backend.place(&mut out, &placements, &covers)? {
+            self.cover.needs_full_redraw = true;
         }
-
-        out.write_all(b"\x1b8")?;
-        out.flush()?;
-
-        self.cover.active_cover_ids = new_ids;
         Ok(())
     }
 
@@ -269,6 +468,12 @@ impl App {
         use ratatui::widgets::{List, ListItem};
         use crate::ui::constants::{MILLER_PARENT_PCT, MILLER_CURRENT_PCT, MILLER_PREVIEW_PCT};
 
+        let selected = self.nav.current().selected;
+        if self.is_compact(frame) {
+            crate::ui::settings_view::render_list(frame, area, &self.settings, selected);
+            return;
+        }
+
         let cols = Layout::horizontal([
                 Constraint::Percentage(MILLER_PARENT_PCT),
                 Constraint::Percentage(MILLER_CURRENT_PCT),
@@ -296,7 +501,6 @@ impl App {
         }
 
         // Center: settings items
-        let selected = self.nav.current().selected;
         crate::ui::settings_view::render_list(frame, cols[1], &self.settings, selected);
 
         // Right: hint
@@ -310,7 +514,7 @@ impl App {
 
         let browsed_detail: Option<crate::model::song::PublicSongDetail> = if node == NavNode::Queue {
             // 优先使用完整详情，回退到队列项基本信息
-            self.queue.songs.get(sel_idx).map(|item| {
+            self.queue_index_at(sel_idx).and_then(|i| self.queue.songs.get(i)).map(|item| {
                 self.cache.queue_song_detail.get(&item.id).cloned()
                     .unwrap_or_else(|| item.to_song_detail())
             })
@@ -345,7 +549,24 @@ impl App {
         };
 
         let is_playing = self.player.current_detail.as_ref()
-            .map_or(false, |p| p.id == detail.id);
+            .is_some_and(|p| p.id == detail.id);
+
+        if self.settings.display.cover_backdrop
+            && self.cover.protocol.is_some_and(|p| p.supports_backdrop())
+            && let Some(&id) = self.cache.covers.id_map().get(&detail.cover_url)
+        {
+            // 按终端字符约 1:2 的宽高比居中，避免方形封面被拉伸
+            let width = area.width.min(area.height.saturating_mul(2));
+            let backdrop = Rect {
+                x: area.x + (area.width - width) / 2,
+                width,
+                ..area
+            };
+            frame.render_widget(
+                crate::ui::cover_widget::CoverWidget { image_id: crate::ui::kitty::backdrop_id(id) },
+                backdrop,
+            );
+        }
 
         let playback = if is_playing {
             Some(crate::ui::player_view::PlaybackInfo {
@@ -356,12 +577,30 @@ impl App {
             None
         };
 
-        crate::ui::player_view::render(
+        self.player.detail_max_scroll = crate::ui::player_view::render(
             frame,
             area,
             &detail,
             playback,
             self.cache.covers.id_map(),
+            crate::ui::player_view::ViewOptions {
+                placeholders: self.cover.supported(),
+                cover_scale: self.settings.display.player_cover_scale,
+                scroll: self.player.detail_scroll,
+            },
         );
+
+        // 右上角标出当前模式，解释为何展示的不是播放中歌曲
+        let (mark, label) = if self.player.follow_playback {
+            ("●", t!("player.following"))
+        } else {
+            ("○", t!("player.browsing"))
+        };
+        let indicator = Paragraph::new(Span::styled(
+            format!("{mark} {label} [f] "),
+            crate::ui::theme::Theme::secondary(),
+        ))
+        .alignment(Alignment::Right);
+        frame.render_widget(indicator, Rect { height: area.height.min(1), ..area });
     }
 }
@@ -0,0 +1,74 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use super::paths;
+
+/// 收藏的标签名，按收藏顺序排列，供 Ctrl+T 依次跳转
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FavoriteTags {
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl FavoriteTags {
+    pub fn load() -> Result<Self> {
+        let path = paths::favorite_tags_file()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(&path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = paths::favorite_tags_file()?;
+        paths::write_atomic(&path, serde_json::to_string_pretty(self)?.as_bytes())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.tags.iter().any(|t| t == name)
+    }
+
+    /// 已收藏则取消，否则追加到末尾；返回操作后是否处于收藏状态
+    pub fn toggle(&mut self, name: &str) -> bool {
+        if let Some(pos) = self.tags.iter().position(|t| t == name) {
+            self.tags.remove(pos);
+            false
+        } else {
+            self.tags.push(name.to_string());
+            true
+        }
+    }
+
+    /// `current` 之后的下一个收藏标签（到末尾回到第一个）；`current` 不是收藏标签时从第一个开始
+    pub fn next_after(&self, current: Option<&str>) -> Option<&str> {
+        let next = current
+            .and_then(|name| self.tags.iter().position(|t| t == name))
+            .map_or(0, |i| (i + 1) % self.tags.len());
+        self.tags.get(next).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FavoriteTags;
+
+    #[test]
+    fn toggle_adds_then_removes() {
+        let mut favorites = FavoriteTags::default();
+        assert!(favorites.toggle("VOCALOID"));
+        assert!(favorites.toggle("哈基米"));
+        assert!(!favorites.toggle("VOCALOID"));
+        assert_eq!(favorites.tags, ["哈基米"]);
+    }
+
+    #[test]
+    fn next_after_cycles_in_order() {
+        let favorites = FavoriteTags { tags: vec!["a".into(), "b".into(), "c".into()] };
+        assert_eq!(favorites.next_after(None), Some("a"));
+        assert_eq!(favorites.next_after(Some("b")), Some("c"));
+        assert_eq!(favorites.next_after(Some("c")), Some("a"));
+        assert_eq!(favorites.next_after(Some("other")), Some("a"));
+        assert_eq!(FavoriteTags::default().next_after(Some("a")), None);
+    }
+}
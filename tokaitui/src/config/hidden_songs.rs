@@ -0,0 +1,76 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::model::song::PublicSongDetail;
+
+use super::paths;
+
+/// 被隐藏的歌曲；保存标题与作者以便在列表中辨认
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HiddenSong {
+    pub id: i64,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub artist: String,
+}
+
+/// 本地屏蔽列表：推荐、最新、标签与搜索结果中不再出现这些歌曲，最近隐藏的在前
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HiddenSongs {
+    #[serde(default)]
+    pub songs: Vec<HiddenSong>,
+}
+
+impl HiddenSongs {
+    pub fn load() -> Result<Self> {
+        let path = paths::hidden_songs_file()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(&path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = paths::hidden_songs_file()?;
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn contains(&self, id: i64) -> bool {
+        self.songs.iter().any(|s| s.id == id)
+    }
+
+    /// 隐藏一首歌；已在列表中时返回 false
+    pub fn hide(&mut self, song: &PublicSongDetail) -> bool {
+        if self.contains(song.id) {
+            return false;
+        }
+        self.songs.insert(0, HiddenSong {
+            id: song.id,
+            title: song.title.clone(),
+            artist: song.uploader_name.clone(),
+        });
+        true
+    }
+
+    /// 从歌曲列表中去掉被隐藏的歌曲
+    pub fn filter(&self, songs: &mut Vec<PublicSongDetail>) {
+        if !self.songs.is_empty() {
+            songs.retain(|s| !self.contains(s.id));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HiddenSong, HiddenSongs};
+
+    #[test]
+    fn old_entries_without_titles_still_load() {
+        let hidden: HiddenSongs = serde_json::from_str(r#"{"songs":[{"id":3}]}"#).unwrap();
+        assert!(hidden.contains(3));
+        assert_eq!(hidden.songs[0], HiddenSong { id: 3, title: String::new(), artist: String::new() });
+    }
+}
@@ -1,3 +1,8 @@
 pub mod settings;
 pub mod auth_store;
 pub mod paths;
+pub mod stats_store;
+pub mod search_history;
+pub mod pinned_store;
+pub mod favorite_tags;
+pub mod hidden_songs;
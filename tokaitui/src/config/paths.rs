@@ -1,31 +1,56 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 
+/// 覆盖状态目录（config / auth / queue），便于便携安装或测试
+pub const STATE_DIR_ENV: &str = "HACHIMI_STATE_DIR";
+/// 覆盖缓存目录（日志、音频缓存）
+pub const CACHE_DIR_ENV: &str = "HACHIMI_CACHE_DIR";
+
+/// 读取目录覆盖环境变量，未设置或为空时返回 None
+fn env_override(var: &str) -> Option<PathBuf> {
+    std::env::var_os(var)
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+}
+
 pub fn config_dir() -> Result<PathBuf> {
-    let dir = dirs::config_dir()
-        .context("无法获取配置目录")?
-        .join("tokaitui");
+    let dir = match env_override(STATE_DIR_ENV) {
+        Some(dir) => dir,
+        None => dirs::config_dir()
+            .context("无法获取配置目录")?
+            .join("tokaitui"),
+    };
     std::fs::create_dir_all(&dir)?;
     Ok(dir)
 }
 
-#[allow(dead_code)] // TODO: 缓存目录
 pub fn cache_dir() -> Result<PathBuf> {
-    let dir = dirs::cache_dir()
-        .context("无法获取缓存目录")?
-        .join("tokaitui");
+    let dir = match env_override(CACHE_DIR_ENV) {
+        Some(dir) => dir,
+        None => dirs::cache_dir()
+            .context("无法获取缓存目录")?
+            .join("tokaitui"),
+    };
     std::fs::create_dir_all(&dir)?;
     Ok(dir)
 }
 
-#[allow(dead_code)] // TODO: 音频缓存
 pub fn audio_cache_dir() -> Result<PathBuf> {
     let dir = cache_dir()?.join("audio");
     std::fs::create_dir_all(&dir)?;
     Ok(dir)
 }
 
+/// 先写入同目录的临时文件再重命名，写到一半崩溃时原文件保持完整
+pub fn write_atomic(path: &Path, content: &[u8]) -> Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    std::fs::write(&tmp, content)?;
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
+
 pub fn config_file() -> Result<PathBuf> {
     Ok(config_dir()?.join("config.toml"))
 }
@@ -34,12 +59,44 @@ pub fn auth_file() -> Result<PathBuf> {
     Ok(config_dir()?.join("auth.json"))
 }
 
+pub fn log_file() -> Result<PathBuf> {
+    Ok(cache_dir()?.join("hachimi.log"))
+}
+
 pub fn queue_file() -> Result<PathBuf> {
     Ok(config_dir()?.join("queue.json"))
 }
 
+pub fn stats_file() -> Result<PathBuf> {
+    Ok(config_dir()?.join("stats.json"))
+}
+
+pub fn search_history_file() -> Result<PathBuf> {
+    Ok(config_dir()?.join("search_history.json"))
+}
+
+pub fn pinned_file() -> Result<PathBuf> {
+    Ok(config_dir()?.join("pinned.json"))
+}
+
+pub fn favorite_tags_file() -> Result<PathBuf> {
+    Ok(config_dir()?.join("favorite_tags.json"))
+}
+
+pub fn hidden_songs_file() -> Result<PathBuf> {
+    Ok(config_dir()?.join("hidden_songs.json"))
+}
+
 pub fn danmaku_dir() -> Result<PathBuf> {
     let dir = config_dir()?.join("danmaku");
     std::fs::create_dir_all(&dir)?;
     Ok(dir)
 }
+
+pub fn cheatsheet_file() -> Result<PathBuf> {
+    Ok(config_dir()?.join("keybindings.md"))
+}
+
+pub fn debug_report_file() -> Result<PathBuf> {
+    Ok(config_dir()?.join("debug_report.txt"))
+}
@@ -0,0 +1,65 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::ui::navigation::NavNode;
+
+use super::paths;
+
+/// 固定的快捷节点（标签、歌单、用户），按固定顺序排列
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PinnedNodes {
+    #[serde(default)]
+    pub nodes: Vec<NavNode>,
+}
+
+impl PinnedNodes {
+    pub fn load() -> Result<Self> {
+        let path = paths::pinned_file()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(&path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = paths::pinned_file()?;
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// 已固定则取消，否则追加到末尾；返回操作后是否处于固定状态
+    pub fn toggle(&mut self, node: NavNode) -> bool {
+        if let Some(pos) = self.nodes.iter().position(|n| *n == node) {
+            self.nodes.remove(pos);
+            false
+        } else {
+            self.nodes.push(node);
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NavNode, PinnedNodes};
+
+    #[test]
+    fn toggle_pins_then_unpins() {
+        let mut pinned = PinnedNodes::default();
+        assert!(pinned.toggle(NavNode::Tag { name: "VOCALOID".into() }));
+        assert!(pinned.toggle(NavNode::PlaylistDetail { id: 7 }));
+        assert!(!pinned.toggle(NavNode::Tag { name: "VOCALOID".into() }));
+        assert_eq!(pinned.nodes, [NavNode::PlaylistDetail { id: 7 }]);
+    }
+
+    #[test]
+    fn nodes_round_trip_through_json() {
+        let pinned = PinnedNodes {
+            nodes: vec![NavNode::Tag { name: "哈基米".into() }, NavNode::UserDetail { id: 42 }],
+        };
+        let json = serde_json::to_string(&pinned).unwrap();
+        let back: PinnedNodes = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.nodes, pinned.nodes);
+    }
+}
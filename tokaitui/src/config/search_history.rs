@@ -0,0 +1,65 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use super::paths;
+
+/// 最多保留的搜索词条数
+pub const MAX_TERMS: usize = 20;
+
+/// 最近搜索过的关键词，最新的在前
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchHistory {
+    #[serde(default)]
+    pub terms: Vec<String>,
+}
+
+impl SearchHistory {
+    pub fn load() -> Result<Self> {
+        let path = paths::search_history_file()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(&path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = paths::search_history_file()?;
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// 记录一次搜索：已有的相同词条移到最前，超出上限时丢弃最旧的
+    pub fn record(&mut self, term: &str) {
+        if term.is_empty() {
+            return;
+        }
+        self.terms.retain(|t| t != term);
+        self.terms.insert(0, term.to_string());
+        self.terms.truncate(MAX_TERMS);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MAX_TERMS, SearchHistory};
+
+    #[test]
+    fn record_moves_repeated_term_to_front() {
+        let mut history = SearchHistory::default();
+        history.record("a");
+        history.record("b");
+        history.record("a");
+        assert_eq!(history.terms, ["a", "b"]);
+    }
+
+    #[test]
+    fn record_caps_history_length() {
+        let mut history = SearchHistory::default();
+        for i in 0..MAX_TERMS + 5 {
+            history.record(&i.to_string());
+        }
+        assert_eq!(history.terms.len(), MAX_TERMS);
+        assert_eq!(history.terms[0], (MAX_TERMS + 4).to_string());
+    }
+}
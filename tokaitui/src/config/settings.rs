@@ -2,6 +2,7 @@ use anyhow::Result;
 use serde::{Deserialize, Serialize};
 
 use super::paths;
+use crate::ui::graphics::GraphicsMode;
 use crate::ui::i18n::Lang;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -12,6 +13,46 @@ pub struct Settings {
     pub cache: CacheSettings,
     #[serde(default)]
     pub display: DisplaySettings,
+    #[serde(default)]
+    pub server: ServerSettings,
+    #[serde(default)]
+    pub debug: DebugSettings,
+    /// 省流模式：不下载封面、不预取、不上报播放历史
+    #[serde(default)]
+    pub data_saver: bool,
+    /// 按 q 退出前弹出确认（Ctrl+C 仍直接退出）
+    #[serde(default)]
+    pub confirm_quit: bool,
+    /// 在导航根层按 h / ← 时的行为（根层没有上一级可返回）
+    #[serde(default)]
+    pub root_back: RootBack,
+    /// 未登录时直接以匿名身份进入浏览，不显示登录界面
+    #[serde(default)]
+    pub allow_anonymous: bool,
+    /// 帮助面板中按 e 导出快捷键速查表的位置，未设置时写入配置目录下的 keybindings.md
+    #[serde(default)]
+    pub cheatsheet_path: Option<String>,
+    /// 每隔多少秒自动保存播放队列与改动过的设置（防止崩溃或被强制结束时丢失），0 为只在退出时保存
+    #[serde(default = "default_autosave_secs")]
+    pub autosave_secs: u64,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            player: PlayerSettings::default(),
+            cache: CacheSettings::default(),
+            display: DisplaySettings::default(),
+            server: ServerSettings::default(),
+            debug: DebugSettings::default(),
+            data_saver: false,
+            confirm_quit: false,
+            root_back: RootBack::default(),
+            allow_anonymous: false,
+            cheatsheet_path: None,
+            autosave_secs: default_autosave_secs(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +63,34 @@ pub struct PlayerSettings {
     pub replay_gain: bool,
     #[serde(default)]
     pub default_play_mode: PlayMode,
+    #[serde(default)]
+    pub repeat_all_from: RepeatAllFrom,
+    #[serde(default)]
+    pub enter_action: EnterAction,
+    /// 搜索结果中按 Enter 的行为；未设置时沿用 `enter_action`
+    #[serde(default)]
+    pub search_enter_action: Option<EnterAction>,
+    /// 「播放整个列表」时是否替换队列；关闭后列表插入到当前歌曲之后，原队列保留
+    #[serde(default = "default_true")]
+    pub replace_queue_on_play: bool,
+    /// 队列为空时按空格播放当前列表的选中项（无选中时为第一项）
+    #[serde(default = "default_true")]
+    pub space_plays_list: bool,
+    /// 启动后在后台预取当前队列项前后几首的歌曲详情
+    #[serde(default)]
+    pub prefetch_queue: bool,
+    /// 启动或重新登录后是否自动继续播放上次的队列
+    #[serde(default)]
+    pub autoplay_on_start: AutoplayOnStart,
+    /// 音频下载超过该时长（毫秒）仍未完成时，在播放栏显示下载进度
+    #[serde(default = "default_buffering_indicator_ms")]
+    pub buffering_indicator_ms: u64,
+    /// 播放前去掉首尾静音（需启用 `trim-silence` 编译特性）
+    #[serde(default)]
+    pub trim_silence: bool,
+    /// 自动切歌时的淡入淡出秒数，0 为关闭；开启后播放中会预先下载下一首
+    #[serde(default)]
+    pub crossfade_secs: u32,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -29,10 +98,145 @@ pub struct PlayerSettings {
 pub enum PlayMode {
     #[default]
     Sequential,
+    /// 播完最后一首后回绕，回绕位置见 `RepeatAllFrom`
+    RepeatAll,
     Shuffle,
     RepeatOne,
 }
 
+impl PlayMode {
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Sequential => Self::RepeatAll,
+            Self::RepeatAll => Self::Shuffle,
+            Self::Shuffle => Self::RepeatOne,
+            Self::RepeatOne => Self::Sequential,
+        }
+    }
+}
+
+/// 列表循环回绕时从哪里重新开始
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RepeatAllFrom {
+    /// 从队列第一首开始，循环整个队列
+    #[default]
+    QueueStart,
+    /// 从最近一次开始播放的那首开始，只循环之后的部分
+    PlayStart,
+}
+
+impl RepeatAllFrom {
+    pub fn next(self) -> Self {
+        match self {
+            Self::QueueStart => Self::PlayStart,
+            Self::PlayStart => Self::QueueStart,
+        }
+    }
+}
+
+/// 在歌曲列表上按 Enter 的行为
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnterAction {
+    /// 用整个列表替换队列并播放选中歌曲
+    #[default]
+    PlayList,
+    /// 只播放选中歌曲，不改动队列
+    PlayOne,
+    /// 追加到队列末尾（已在队列中则直接跳转）并播放
+    AddAndPlay,
+}
+
+impl EnterAction {
+    pub fn next(self) -> Self {
+        match self {
+            Self::PlayList => Self::PlayOne,
+            Self::PlayOne => Self::AddAndPlay,
+            Self::AddAndPlay => Self::PlayList,
+        }
+    }
+}
+
+/// 导航根层按 h / ← 的行为
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RootBack {
+    /// 不做任何事
+    #[default]
+    Ignore,
+    /// 切换到迷你播放器
+    MiniPlayer,
+    /// 打开展开播放器视图
+    PlayerView,
+}
+
+/// 启动（或登录后）恢复上次队列时的播放行为
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AutoplayOnStart {
+    /// 只恢复播放栏与进度，保持暂停
+    #[default]
+    Off,
+    /// 从上次的位置继续播放
+    Resume,
+}
+
+impl AutoplayOnStart {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Off => Self::Resume,
+            Self::Resume => Self::Off,
+        }
+    }
+}
+
+/// 浏览区的布局
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UiLayout {
+    /// 面包屑在顶栏中，搜索栏只在搜索时出现
+    #[default]
+    Classic,
+    /// 面包屑独占一行，搜索栏常驻在分栏上方
+    Modern,
+}
+
+impl UiLayout {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Classic => Self::Modern,
+            Self::Modern => Self::Classic,
+        }
+    }
+}
+
+/// 哪些场景下载封面（省流模式下一律不下载）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CoverPolicy {
+    /// 从不下载封面
+    Off,
+    /// 只下载正在播放歌曲的封面（开始播放时下载，展开页跟随播放时显示）
+    PlayingOnly,
+    /// 浏览时下载选中项的封面，以及展开页中正在播放的封面
+    #[default]
+    Browse,
+    /// 在 Browse 基础上，开始播放时预先下载当前与下一首的封面
+    Prefetch,
+}
+
+impl CoverPolicy {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Off => Self::PlayingOnly,
+            Self::PlayingOnly => Self::Browse,
+            Self::Browse => Self::Prefetch,
+            Self::Prefetch => Self::Off,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CacheSettings {
     #[serde(default = "default_cache_size")]
@@ -45,8 +249,89 @@ pub struct DisplaySettings {
     pub kids_mode: bool,
     #[serde(default)]
     pub language: Lang,
+    /// 浏览视图预览栏的封面缩放（%）
+    #[serde(default = "default_cover_scale")]
+    pub preview_cover_scale: u8,
+    /// 展开播放器视图的封面缩放（%）
     #[serde(default = "default_cover_scale")]
-    pub cover_scale: u8,
+    pub player_cover_scale: u8,
+    /// 旧版的单一 `cover_scale`，读取时迁移到上面两项，保存时不再写出
+    #[serde(default, rename = "cover_scale", skip_serializing)]
+    legacy_cover_scale: Option<u8>,
+    #[serde(default)]
+    pub cover_policy: CoverPolicy,
+    /// 展开页把播放中封面调暗后铺在歌词与详情下方（仅 Kitty 图形协议）
+    #[serde(default)]
+    pub cover_backdrop: bool,
+    /// 封面缓存（Kitty 上传序列 / Sixel 像素）占用内存的上限（MB），超出时淘汰旧封面
+    #[serde(default = "default_cover_memory_mb")]
+    pub cover_memory_mb: u64,
+    /// 播放栏左侧显示正在播放歌曲的小封面（仅 Kitty 图形协议），与展开页封面互不影响
+    #[serde(default)]
+    pub bar_thumbnail: bool,
+    /// 展开页自动滚动歌曲详情（制作人员名单）
+    #[serde(default)]
+    pub credits_roll: bool,
+    /// 封面图形协议：auto 按终端检测（Kitty 优先），也可强制 kitty / sixel 或 off 关闭封面
+    #[serde(default)]
+    pub graphics: GraphicsMode,
+    /// 强制开启/关闭 tmux 图形透传，不设置时按 `TMUX` 环境变量自动检测
+    #[serde(default)]
+    pub tmux_passthrough: Option<bool>,
+    /// 预览栏对列表节点也展示封面与统计信息，而不只是标题列表
+    #[serde(default)]
+    pub rich_preview: bool,
+    /// 选中行的超长标题滚动显示；关闭后与其他行一样截断
+    #[serde(default = "default_true")]
+    pub marquee_enabled: bool,
+    /// 选中行的背景色（颜色名、`#rrggbb` 或 256 色序号）
+    #[serde(default = "default_selection_color")]
+    pub selection_color: String,
+    /// 正在播放行的标记与标题颜色，格式同上
+    #[serde(default = "default_now_playing_color")]
+    pub now_playing_color: String,
+    /// 在备用屏幕中运行；关闭或终端不支持时直接在当前屏幕上绘制，退出时清屏
+    #[serde(default = "default_true")]
+    pub alternate_screen: bool,
+    /// 迷你播放器：隐藏浏览区，只显示正在播放的歌曲、当前歌词与播放栏（按 M 切换）
+    #[serde(default)]
+    pub mini_player: bool,
+    /// 浏览区布局：经典（classic）或新版（modern，常驻搜索栏与独立面包屑行）
+    #[serde(default)]
+    pub layout: UiLayout,
+    /// 终端宽度小于该列数时切换为单栏布局
+    #[serde(default = "default_compact_width")]
+    pub compact_width: u16,
+    /// 选中项停留多久（毫秒）后才加载预览栏的列表数据，0 为立即加载
+    #[serde(default = "default_preview_debounce_ms")]
+    pub preview_debounce_ms: u64,
+    /// 把终端窗口标题设为正在播放的歌曲，退出时恢复
+    #[serde(default)]
+    pub set_window_title: bool,
+    /// 窗口标题格式，支持 `{title}`、`{artist}`
+    #[serde(default = "default_window_title_format")]
+    pub window_title_format: String,
+    /// 终端重新获得焦点时刷新最新、推荐等随时间变化的列表，并检查播放引擎
+    #[serde(default)]
+    pub refresh_on_focus: bool,
+    /// 终端失去焦点时暂停标题滚动等动画，节省 CPU
+    #[serde(default)]
+    pub freeze_when_unfocused: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerSettings {
+    /// 封面、详情等后台请求的最大并发数；音频下载另有同等额度，不与之争抢
+    #[serde(default = "default_max_concurrency")]
+    pub max_concurrency: usize,
+}
+
+/// 调试选项，只影响本地行为
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DebugSettings {
+    /// 不发送上报类请求（播放历史等），改为在日志中记录本应发送的内容
+    #[serde(default)]
+    pub dry_run: bool,
 }
 
 fn default_volume() -> u8 {
@@ -61,15 +346,32 @@ fn default_cache_size() -> u64 {
 fn default_cover_scale() -> u8 {
     100
 }
-
-impl Default for Settings {
-    fn default() -> Self {
-        Self {
-            player: PlayerSettings::default(),
-            cache: CacheSettings::default(),
-            display: DisplaySettings::default(),
-        }
-    }
+fn default_cover_memory_mb() -> u64 {
+    64
+}
+fn default_buffering_indicator_ms() -> u64 {
+    500
+}
+fn default_selection_color() -> String {
+    "dark_gray".to_string()
+}
+fn default_now_playing_color() -> String {
+    "green".to_string()
+}
+fn default_compact_width() -> u16 {
+    60
+}
+fn default_preview_debounce_ms() -> u64 {
+    200
+}
+fn default_window_title_format() -> String {
+    crate::ui::window_title::DEFAULT_FORMAT.to_string()
+}
+fn default_autosave_secs() -> u64 {
+    30
+}
+fn default_max_concurrency() -> usize {
+    4
 }
 
 impl Default for PlayerSettings {
@@ -78,6 +380,16 @@ impl Default for PlayerSettings {
             volume: default_volume(),
             replay_gain: true,
             default_play_mode: PlayMode::default(),
+            repeat_all_from: RepeatAllFrom::default(),
+            enter_action: EnterAction::default(),
+            search_enter_action: None,
+            replace_queue_on_play: true,
+            space_plays_list: true,
+            prefetch_queue: false,
+            autoplay_on_start: AutoplayOnStart::default(),
+            buffering_indicator_ms: default_buffering_indicator_ms(),
+            trim_silence: false,
+            crossfade_secs: 0,
         }
     }
 }
@@ -90,12 +402,55 @@ impl Default for CacheSettings {
     }
 }
 
+impl DisplaySettings {
+    /// 旧配置只有一个 `cover_scale`：两处仍为默认值时都沿用它
+    fn migrate_cover_scale(&mut self) {
+        let Some(scale) = self.legacy_cover_scale.take() else { return };
+        if self.preview_cover_scale == default_cover_scale() {
+            self.preview_cover_scale = scale;
+        }
+        if self.player_cover_scale == default_cover_scale() {
+            self.player_cover_scale = scale;
+        }
+    }
+}
+
 impl Default for DisplaySettings {
     fn default() -> Self {
         Self {
             kids_mode: false,
             language: Lang::default(),
-            cover_scale: default_cover_scale(),
+            preview_cover_scale: default_cover_scale(),
+            player_cover_scale: default_cover_scale(),
+            legacy_cover_scale: None,
+            cover_policy: CoverPolicy::default(),
+            cover_backdrop: false,
+            cover_memory_mb: default_cover_memory_mb(),
+            bar_thumbnail: false,
+            credits_roll: false,
+            graphics: GraphicsMode::default(),
+            tmux_passthrough: None,
+            rich_preview: false,
+            marquee_enabled: true,
+            selection_color: default_selection_color(),
+            now_playing_color: default_now_playing_color(),
+            alternate_screen: true,
+            mini_player: false,
+            layout: UiLayout::default(),
+            compact_width: default_compact_width(),
+            preview_debounce_ms: default_preview_debounce_ms(),
+            set_window_title: false,
+            window_title_format: default_window_title_format(),
+            refresh_on_focus: false,
+            freeze_when_unfocused: false,
+        }
+    }
+}
+
+impl Default for ServerSettings {
+    fn default() -> Self {
+        Self {
+            max_concurrency: default_max_concurrency(),
         }
     }
 }
@@ -105,7 +460,9 @@ impl Settings {
         let path = paths::config_file()?;
         if path.exists() {
             let content = std::fs::read_to_string(&path)?;
-            Ok(toml::from_str(&content)?)
+            let mut settings: Self = toml::from_str(&content)?;
+            settings.display.migrate_cover_scale();
+            Ok(settings)
         } else {
             let settings = Self::default();
             settings.save()?;
@@ -116,7 +473,20 @@ impl Settings {
     pub fn save(&self) -> Result<()> {
         let path = paths::config_file()?;
         let content = toml::to_string_pretty(self)?;
-        std::fs::write(path, content)?;
-        Ok(())
+        paths::write_atomic(&path, content.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PlayMode, Settings};
+
+    #[test]
+    fn play_mode_survives_save_and_load() {
+        let mut settings = Settings::default();
+        settings.player.default_play_mode = PlayMode::Sequential.next().next();
+        let saved = toml::to_string_pretty(&settings).unwrap();
+        let loaded: Settings = toml::from_str(&saved).unwrap();
+        assert!(matches!(loaded.player.default_play_mode, PlayMode::Shuffle));
     }
 }
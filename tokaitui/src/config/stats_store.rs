@@ -0,0 +1,41 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use super::paths;
+
+/// 统计文件格式版本。新增字段一律带 `#[serde(default)]`，旧版本文件可直接读取
+pub const STATS_VERSION: u32 = 1;
+
+/// 本地收听统计，只由播放事件推算，与服务端无关
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListeningStats {
+    #[serde(default)]
+    pub version: u32,
+    /// 开始播放的次数
+    #[serde(default)]
+    pub plays: u64,
+    /// 播放到结尾的次数
+    #[serde(default)]
+    pub completed: u64,
+    /// 累计收听时长（秒，不含暂停与跳过的部分）
+    #[serde(default)]
+    pub listened_secs: u64,
+}
+
+impl ListeningStats {
+    pub fn load() -> Result<Self> {
+        let path = paths::stats_file()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(&path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = paths::stats_file()?;
+        let stamped = Self { version: STATS_VERSION, ..self.clone() };
+        std::fs::write(path, serde_json::to_string_pretty(&stamped)?)?;
+        Ok(())
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::VecDeque;
+
 use serde::{Deserialize, Serialize};
 
 use crate::model::song::PublicSongDetail;
@@ -46,11 +48,35 @@ impl MusicQueueItem {
     }
 }
 
+/// 队列排序依据，按 O 依次切换
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueueSort {
+    Title,
+    Artist,
+    Duration,
+}
+
+impl QueueSort {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Title => Self::Artist,
+            Self::Artist => Self::Duration,
+            Self::Duration => Self::Title,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueueState {
     pub current_index: Option<usize>,
     pub position_ms: u64,
     pub songs: Vec<MusicQueueItem>,
+    /// 随机播放顺序的种子（见 `player/queue.rs` 的 `shuffle_order`）
+    #[serde(default)]
+    pub shuffle_seed: u64,
+    /// 最近一次开始播放的歌曲 ID，列表循环可选择从这里回绕
+    #[serde(default)]
+    pub play_start_id: Option<i64>,
 }
 
 impl QueueState {
@@ -59,6 +85,8 @@ impl QueueState {
             current_index: None,
             position_ms: 0,
             songs: Vec::new(),
+            shuffle_seed: 0,
+            play_start_id: None,
         }
     }
 
@@ -66,24 +94,13 @@ impl QueueState {
         self.current_index.and_then(|i| self.songs.get(i))
     }
 
-    pub fn next(&mut self) -> Option<&MusicQueueItem> {
-        if let Some(idx) = self.current_index {
-            if idx + 1 < self.songs.len() {
-                self.current_index = Some(idx + 1);
-                self.position_ms = 0;
-                return self.songs.get(idx + 1);
-            }
-        }
-        None
-    }
-
     pub fn prev(&mut self) -> Option<&MusicQueueItem> {
-        if let Some(idx) = self.current_index {
-            if idx > 0 {
-                self.current_index = Some(idx - 1);
-                self.position_ms = 0;
-                return self.songs.get(idx - 1);
-            }
+        if let Some(idx) = self.current_index
+            && idx > 0
+        {
+            self.current_index = Some(idx - 1);
+            self.position_ms = 0;
+            return self.songs.get(idx - 1);
         }
         None
     }
@@ -95,14 +112,6 @@ impl QueueState {
         }
     }
 
-    pub fn insert_next(&mut self, item: MusicQueueItem) {
-        let pos = self.current_index.map_or(0, |i| i + 1);
-        self.songs.insert(pos, item);
-        if self.current_index.is_none() {
-            self.current_index = Some(0);
-        }
-    }
-
     pub fn remove(&mut self, index: usize) {
         if index < self.songs.len() {
             self.songs.remove(index);
@@ -110,6 +119,8 @@ impl QueueState {
                 if index < curr {
                     self.current_index = Some(curr - 1);
                 } else if index == curr {
+                    // 正在播放的歌曲被移除：指向原本的下一首（移除的是最后一首时指向新的末尾），从头播放
+                    self.position_ms = 0;
                     if self.songs.is_empty() {
                         self.current_index = None;
                     } else if curr >= self.songs.len() {
@@ -120,9 +131,335 @@ impl QueueState {
         }
     }
 
+    /// 将 `index` 处的歌曲移动到 `target`，同步修正 `current_index`。
+    /// 越界或位置不变时返回 false。
+    pub fn move_to(&mut self, index: usize, target: usize) -> bool {
+        let len = self.songs.len();
+        if index >= len || target >= len || index == target {
+            return false;
+        }
+        let item = self.songs.remove(index);
+        self.songs.insert(target, item);
+        if let Some(curr) = self.current_index {
+            self.current_index = Some(if curr == index {
+                target
+            } else if index < curr && curr <= target {
+                curr - 1
+            } else if target <= curr && curr < index {
+                curr + 1
+            } else {
+                curr
+            });
+        }
+        true
+    }
+
+    /// 按 `key` 稳定排序整个队列，`current_index` 跟随正在播放的那一项。
+    /// 随机顺序由歌曲 ID 派生，排序后无需重新生成
+    pub fn sort_by(&mut self, key: QueueSort) {
+        let mut indexed: Vec<(usize, MusicQueueItem)> =
+            std::mem::take(&mut self.songs).into_iter().enumerate().collect();
+        match key {
+            QueueSort::Title => indexed.sort_by_cached_key(|(_, s)| s.name.to_lowercase()),
+            QueueSort::Artist => indexed.sort_by_cached_key(|(_, s)| {
+                (s.artist.to_lowercase(), s.name.to_lowercase())
+            }),
+            QueueSort::Duration => indexed.sort_by_key(|(_, s)| s.duration_secs),
+        }
+        self.current_index = self.current_index
+            .and_then(|curr| indexed.iter().position(|(i, _)| *i == curr));
+        self.songs = indexed.into_iter().map(|(_, s)| s).collect();
+    }
+
+    /// 把当前歌曲记为列表循环的起点
+    pub fn mark_play_start(&mut self) {
+        self.play_start_id = self.current_song().map(|s| s.id);
+    }
+
+    /// 用 `items` 替换整个队列，并从 `items[index]` 开始播放
+    pub fn replace_with(&mut self, items: Vec<MusicQueueItem>, index: usize) {
+        self.clear();
+        self.songs = items;
+        self.current_index = (index < self.songs.len()).then_some(index);
+    }
+
+    /// 把 `items` 插入到当前歌曲之后（无当前歌曲时追加到末尾），已在队列中的歌曲不重复插入，
+    /// 然后切换到 `items[index]` 对应的队列项。`index` 越界时不做任何改动并返回 false
+    pub fn insert_list_after_current(&mut self, items: Vec<MusicQueueItem>, index: usize) -> bool {
+        let Some(target_id) = items.get(index).map(|item| item.id) else { return false };
+        let mut pos = self.current_index.map_or(self.songs.len(), |i| i + 1);
+        for item in items {
+            if self.songs.iter().any(|q| q.id == item.id) {
+                continue;
+            }
+            self.songs.insert(pos, item);
+            pos += 1;
+        }
+        self.current_index = self.songs.iter().position(|q| q.id == target_id);
+        self.position_ms = 0;
+        true
+    }
+
+    /// 切换到 `item` 对应的队列项：已在队列中则直接跳转，否则追加到末尾。其余歌曲与顺序不变
+    pub fn select_or_add(&mut self, item: MusicQueueItem) {
+        let index = match self.songs.iter().position(|q| q.id == item.id) {
+            Some(index) => index,
+            None => {
+                self.songs.push(item);
+                self.songs.len() - 1
+            }
+        };
+        self.current_index = Some(index);
+        self.position_ms = 0;
+    }
+
     pub fn clear(&mut self) {
         self.songs.clear();
         self.current_index = None;
         self.position_ms = 0;
+        self.play_start_id = None;
+    }
+}
+
+/// 最多保留的队列撤销快照数
+pub const QUEUE_UNDO_LIMIT: usize = 5;
+
+/// 队列改动前的快照，按 U 逐次撤销；超出上限时丢弃最旧的
+#[derive(Debug, Default)]
+pub struct QueueUndo {
+    snapshots: VecDeque<QueueState>,
+}
+
+impl QueueUndo {
+    pub fn push(&mut self, state: &QueueState) {
+        if self.snapshots.len() == QUEUE_UNDO_LIMIT {
+            self.snapshots.pop_front();
+        }
+        self.snapshots.push_back(state.clone());
+    }
+
+    pub fn pop(&mut self) -> Option<QueueState> {
+        self.snapshots.pop_back()
+    }
+}
+
+/// 队列测试共用的构造函数（model 与 player 的队列测试都用）
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use super::{MusicQueueItem, QueueState};
+
+    pub(crate) fn item(id: i64) -> MusicQueueItem {
+        MusicQueueItem {
+            id,
+            display_id: format!("JM-{id}"),
+            name: format!("song {id}"),
+            artist: String::new(),
+            duration_secs: 0,
+            cover_url: String::new(),
+            explicit: None,
+            audio_url: String::new(),
+            gain: None,
+        }
+    }
+
+    pub(crate) fn queue_of(ids: &[i64], current: Option<usize>) -> QueueState {
+        let mut queue = QueueState::new();
+        queue.songs = ids.iter().copied().map(item).collect();
+        queue.current_index = current;
+        queue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fixtures::{item, queue_of};
+    use super::{QUEUE_UNDO_LIMIT, QueueSort, QueueState, QueueUndo};
+
+    fn ids(queue: &QueueState) -> Vec<i64> {
+        queue.songs.iter().map(|s| s.id).collect()
+    }
+
+    #[test]
+    fn replace_with_points_at_selected_song() {
+        let mut queue = queue_of(&[1, 2, 3], Some(2));
+        queue.position_ms = 5_000;
+        queue.replace_with(vec![item(10), item(11), item(12)], 1);
+        assert_eq!(ids(&queue), [10, 11, 12]);
+        assert_eq!(queue.current_index, Some(1));
+        assert_eq!(queue.current_song().map(|s| s.id), Some(11));
+        assert_eq!(queue.position_ms, 0);
+
+        queue.replace_with(vec![item(20)], 3);
+        assert_eq!(queue.current_index, None);
+    }
+
+    #[test]
+    fn insert_list_keeps_queue_and_plays_selected() {
+        let mut queue = queue_of(&[1, 2, 3], Some(1));
+        assert!(queue.insert_list_after_current(vec![item(10), item(11), item(12)], 2));
+        assert_eq!(ids(&queue), [1, 2, 10, 11, 12, 3]);
+        assert_eq!(queue.current_song().map(|s| s.id), Some(12));
+    }
+
+    #[test]
+    fn insert_list_skips_songs_already_queued() {
+        let mut queue = queue_of(&[1, 2, 3], Some(0));
+        assert!(queue.insert_list_after_current(vec![item(3), item(10), item(1)], 2));
+        assert_eq!(ids(&queue), [1, 10, 2, 3]);
+        assert_eq!(queue.current_index, Some(0));
+
+        assert!(queue.insert_list_after_current(vec![item(3), item(11)], 0));
+        assert_eq!(ids(&queue), [1, 11, 10, 2, 3]);
+        assert_eq!(queue.current_song().map(|s| s.id), Some(3));
+    }
+
+    #[test]
+    fn insert_list_into_empty_queue() {
+        let mut queue = QueueState::new();
+        assert!(queue.insert_list_after_current(vec![item(10), item(11)], 1));
+        assert_eq!(ids(&queue), [10, 11]);
+        assert_eq!(queue.current_index, Some(1));
+
+        assert!(!queue.insert_list_after_current(vec![item(12)], 5));
+        assert_eq!(ids(&queue), [10, 11]);
+    }
+
+    #[test]
+    fn select_or_add_appends_and_keeps_queue() {
+        let mut queue = queue_of(&[1, 2, 3], Some(0));
+        queue.select_or_add(item(10));
+        assert_eq!(ids(&queue), [1, 2, 3, 10]);
+        assert_eq!(queue.current_song().map(|s| s.id), Some(10));
+    }
+
+    #[test]
+    fn select_or_add_jumps_to_queued_song() {
+        let mut queue = queue_of(&[1, 2, 3], Some(0));
+        queue.position_ms = 5_000;
+        queue.select_or_add(item(2));
+        assert_eq!(ids(&queue), [1, 2, 3]);
+        assert_eq!(queue.current_index, Some(1));
+        assert_eq!(queue.position_ms, 0);
+    }
+
+    #[test]
+    fn sort_by_keeps_current_song() {
+        let mut queue = queue_of(&[1, 2, 3, 4], Some(1));
+        for (song, (name, secs)) in queue.songs.iter_mut().zip([("b", 200), ("D", 100), ("a", 300), ("c", 100)]) {
+            song.name = name.to_string();
+            song.duration_secs = secs;
+        }
+
+        queue.sort_by(QueueSort::Title);
+        assert_eq!(ids(&queue), [3, 1, 4, 2]);
+        assert_eq!(queue.current_song().map(|s| s.id), Some(2));
+
+        // 时长相同时保持原有相对顺序
+        queue.sort_by(QueueSort::Duration);
+        assert_eq!(ids(&queue), [4, 2, 1, 3]);
+        assert_eq!(queue.current_index, Some(1));
+    }
+
+    #[test]
+    fn move_to_shifts_songs_between_positions() {
+        let mut queue = queue_of(&[1, 2, 3, 4], Some(0));
+        assert!(queue.move_to(3, 1));
+        assert_eq!(ids(&queue), [1, 4, 2, 3]);
+        assert!(queue.move_to(1, 3));
+        assert_eq!(ids(&queue), [1, 2, 3, 4]);
+        assert_eq!(queue.current_index, Some(0));
+    }
+
+    #[test]
+    fn move_to_keeps_pointing_at_playing_song() {
+        // 移动的正是正在播放的歌曲
+        let mut queue = queue_of(&[1, 2, 3, 4], Some(1));
+        assert!(queue.move_to(1, 3));
+        assert_eq!(queue.current_index, Some(3));
+        assert!(queue.move_to(3, 0));
+        assert_eq!(queue.current_index, Some(0));
+        assert_eq!(queue.current_song().map(|s| s.id), Some(2));
+
+        // 从前面越过当前歌曲向后移动，当前歌曲前移一位；反向则后移一位
+        let mut queue = queue_of(&[1, 2, 3, 4], Some(2));
+        assert!(queue.move_to(0, 3));
+        assert_eq!(queue.current_song().map(|s| s.id), Some(3));
+        assert_eq!(queue.current_index, Some(1));
+        assert!(queue.move_to(3, 0));
+        assert_eq!(queue.current_song().map(|s| s.id), Some(3));
+        assert_eq!(queue.current_index, Some(2));
+
+        // 不跨过当前歌曲时不变
+        assert!(queue.move_to(0, 1));
+        assert_eq!(queue.current_index, Some(2));
+    }
+
+    #[test]
+    fn move_to_rejects_out_of_range_and_no_op_moves() {
+        let mut queue = queue_of(&[1, 2, 3], Some(1));
+        assert!(!queue.move_to(3, 0));
+        assert!(!queue.move_to(0, 3));
+        assert!(!queue.move_to(2, 2));
+        assert_eq!(ids(&queue), [1, 2, 3]);
+        assert_eq!(queue.current_index, Some(1));
+
+        let mut empty = QueueState::new();
+        assert!(!empty.move_to(0, 0));
+    }
+
+    #[test]
+    fn add_starts_at_first_song() {
+        let mut queue = QueueState::new();
+        queue.add(item(1));
+        queue.add(item(2));
+        assert_eq!(queue.current_index, Some(0));
+    }
+
+    #[test]
+    fn remove_keeps_pointing_at_playing_song() {
+        let mut queue = queue_of(&[1, 2, 3, 4], Some(2));
+        queue.remove(0);
+        assert_eq!(queue.current_song().map(|s| s.id), Some(3));
+        queue.remove(2);
+        assert_eq!(queue.current_song().map(|s| s.id), Some(3));
+        queue.remove(7);
+        assert_eq!(ids(&queue), [2, 3]);
+    }
+
+    #[test]
+    fn removing_playing_song_moves_to_next_one() {
+        let mut queue = queue_of(&[1, 2, 3], Some(1));
+        queue.position_ms = 5_000;
+        queue.remove(1);
+        assert_eq!(queue.current_song().map(|s| s.id), Some(3));
+        assert_eq!(queue.position_ms, 0);
+
+        // 移除的是最后一首时指向新的末尾
+        queue.remove(1);
+        assert_eq!(queue.current_song().map(|s| s.id), Some(1));
+        queue.remove(0);
+        assert_eq!(queue.current_index, None);
+    }
+
+    #[test]
+    fn clear_forgets_play_start() {
+        let mut queue = queue_of(&[1, 2], Some(1));
+        queue.mark_play_start();
+        queue.clear();
+        assert!(queue.songs.is_empty());
+        assert_eq!(queue.current_index, None);
+        assert_eq!(queue.play_start_id, None);
+    }
+
+    #[test]
+    fn undo_keeps_only_the_latest_snapshots() {
+        let mut undo = QueueUndo::default();
+        for id in 0..QUEUE_UNDO_LIMIT as i64 + 2 {
+            undo.push(&queue_of(&[id], Some(0)));
+        }
+        for id in (2..QUEUE_UNDO_LIMIT as i64 + 2).rev() {
+            assert_eq!(undo.pop().map(|q| ids(&q)), Some(vec![id]));
+        }
+        assert!(undo.pop().is_none());
     }
 }
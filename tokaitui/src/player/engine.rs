@@ -1,33 +1,44 @@
 use std::io::Cursor;
 use std::time::Duration;
 
-use anyhow::Result;
-use rodio::{Decoder, OutputStreamBuilder, Sink};
+use anyhow::{Result, anyhow};
+use rodio::{Decoder, OutputStreamBuilder, Sink, Source};
 use tokio::sync::mpsc;
 
+use super::silence;
+
 /// 播放引擎发给 UI 的事件
 #[derive(Debug, Clone)]
 pub enum PlayerEvent {
     Playing,
     Paused,
     Stopped,
-    Progress { position_secs: u32, duration_secs: u32 },
+    /// 播放位置（毫秒精度，退出时原样持久化）与总时长；接口时长为 0 时为解码得到的时长，仍未知时为 0
+    Progress { position_ms: u64, duration_secs: u32 },
     Error(String),
     TrackEnded,
-    #[allow(dead_code)] // TODO: 加载状态指示
+    /// 预载的下一首已开始播放（淡入或在上一首结束时无缝接上），上一首不再发送 `TrackEnded`
+    NextStarted,
+    /// 已收到音频数据，开始解码
     Loading,
 }
 
 /// UI 发给播放引擎的命令
 #[derive(Debug)]
 pub enum PlayerCommand {
-    Play(AudioSource, u32, Option<f32>), // (source, duration_secs, gain_db)
+    Play(AudioSource, u32, Option<f32>, bool), // (source, duration_secs, gain_db, trim_silence)
     Pause,
     Resume,
     #[allow(dead_code)] // TODO: 停止播放命令
     Stop,
     Seek(Duration),
     SetVolume(f32),
+    /// 预载已在播放线程外解码好的下一首（见 [`prepare`]）；当前歌曲临近结束时淡入
+    Preload(PreparedTrack),
+    /// 丢弃预载的下一首
+    ClearPreload,
+    /// 淡入淡出时长，0 为不淡入淡出（预载的歌曲仍在上一首结束时无缝接上）
+    SetCrossfade(Duration),
 }
 
 /// 音频来源
@@ -43,19 +54,32 @@ pub struct PlayerEngine {
 }
 
 impl PlayerEngine {
+    /// 启动播放线程，等待音频输出设备打开后返回；没有可用设备时返回错误
     pub fn spawn() -> Result<Self> {
         let (cmd_tx, cmd_rx) = mpsc::unbounded_channel();
         let (event_tx, event_rx) = mpsc::unbounded_channel();
+        let (ready_tx, ready_rx) = std::sync::mpsc::sync_channel(1);
 
         std::thread::spawn(move || {
-            player_thread(cmd_rx, event_tx);
+            player_thread(cmd_rx, event_tx, ready_tx);
         });
 
-        Ok(Self { cmd_tx, event_rx: Some(event_rx) })
+        match ready_rx.recv() {
+            Ok(Ok(())) => Ok(Self { cmd_tx, event_rx: Some(event_rx) }),
+            Ok(Err(e)) => Err(anyhow!("无法打开音频输出设备：{e}")),
+            Err(_) => Err(anyhow!("播放线程意外退出")),
+        }
+    }
+
+    /// 无音频设备时的占位引擎：命令通道已关闭，`is_alive()` 恒为 false
+    pub fn unavailable() -> Self {
+        let (cmd_tx, _) = mpsc::unbounded_channel();
+        Self { cmd_tx, event_rx: None }
     }
 
-    pub fn play(&self, source: AudioSource, duration_secs: u32, gain_db: Option<f32>) {
-        let _ = self.cmd_tx.send(PlayerCommand::Play(source, duration_secs, gain_db));
+    /// `trim_silence` 时先扫描首尾静音，只播放中间的有效区间
+    pub fn play(&self, source: AudioSource, duration_secs: u32, gain_db: Option<f32>, trim_silence: bool) {
+        let _ = self.cmd_tx.send(PlayerCommand::Play(source, duration_secs, gain_db, trim_silence));
     }
 
     pub fn pause(&self) {
@@ -66,7 +90,6 @@ impl PlayerEngine {
         let _ = self.cmd_tx.send(PlayerCommand::Resume);
     }
 
-    #[allow(dead_code)] // TODO: 停止播放
     pub fn stop(&self) {
         let _ = self.cmd_tx.send(PlayerCommand::Stop);
     }
@@ -79,108 +102,267 @@ impl PlayerEngine {
         let _ = self.cmd_tx.send(PlayerCommand::SetVolume(volume));
     }
 
+    /// 交给引擎预载的下一首；当前歌曲剩余不足淡入淡出时长时开始混入，引擎随后发送 `NextStarted`
+    pub fn preload(&self, track: PreparedTrack) {
+        let _ = self.cmd_tx.send(PlayerCommand::Preload(track));
+    }
+
+    pub fn clear_preload(&self) {
+        let _ = self.cmd_tx.send(PlayerCommand::ClearPreload);
+    }
+
+    pub fn set_crossfade(&self, duration: Duration) {
+        let _ = self.cmd_tx.send(PlayerCommand::SetCrossfade(duration));
+    }
+
+    /// 播放线程是否仍在运行（线程退出后命令通道即关闭）
+    pub fn is_alive(&self) -> bool {
+        !self.cmd_tx.is_closed()
+    }
+
     pub fn take_event_receiver(&mut self) -> mpsc::UnboundedReceiver<PlayerEvent> {
         self.event_rx.take().expect("event receiver already taken")
     }
 }
 
+/// 解码完成、等待放入 sink 的曲目
+pub struct PreparedTrack {
+    source: Box<dyn Source + Send>,
+    info: TrackInfo,
+}
+
+impl std::fmt::Debug for PreparedTrack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PreparedTrack")
+            .field("duration_secs", &self.info.duration_secs)
+            .field("len", &self.info.len)
+            .finish_non_exhaustive()
+    }
+}
+
+/// 正在播放的曲目的时长与增益
+#[derive(Default, Clone, Copy)]
+struct TrackInfo {
+    /// 上报给 UI 的时长：接口时长为 0 时改用解码得到的时长
+    duration_secs: u32,
+    /// 实际播放的长度（裁剪静音后），用于判断何时开始淡入淡出；未知时只能在播完时衔接
+    len: Option<Duration>,
+    gain_db: Option<f32>,
+}
+
+/// 构造解码器；`trim_silence` 时先完整解码一遍扫描首尾静音，耗时与歌曲长度成正比，
+/// 预载时应在播放线程之外调用（如 `spawn_blocking`），以免阻塞进度上报与命令处理
+pub fn prepare(source: AudioSource, duration_secs: u32, gain_db: Option<f32>, trim_silence: bool) -> Result<PreparedTrack, String> {
+    match source {
+        AudioSource::Buffered(data) => {
+            let trim = if trim_silence { silence::scan(&data) } else { None };
+            let decoder = Decoder::new(Cursor::new(data)).map_err(|e| e.to_string())?;
+            let total = decoder.total_duration();
+            let duration_secs = if duration_secs == 0 {
+                total.map_or(0, |d| d.as_secs() as u32)
+            } else {
+                duration_secs
+            };
+            let (source, len): (Box<dyn Source + Send>, _) = match trim {
+                Some(t) => (
+                    Box::new(decoder.skip_duration(t.start).take_duration(t.end - t.start)),
+                    Some(t.end - t.start),
+                ),
+                None => (
+                    Box::new(decoder),
+                    total.or((duration_secs > 0).then_some(Duration::from_secs(duration_secs.into()))),
+                ),
+            };
+            Ok(PreparedTrack { source, info: TrackInfo { duration_secs, len, gain_db } })
+        }
+    }
+}
+
+fn effective_volume(user_volume: f32, gain_db: Option<f32>) -> f32 {
+    match gain_db {
+        Some(db) => (user_volume * 10_f32.powf(db / 20.0)).clamp(0.0, 2.0),
+        None => user_volume,
+    }
+}
+
 fn player_thread(
     mut cmd_rx: mpsc::UnboundedReceiver<PlayerCommand>,
     event_tx: mpsc::UnboundedSender<PlayerEvent>,
+    ready_tx: std::sync::mpsc::SyncSender<Result<(), String>>,
 ) {
-    let Ok(_stream) = OutputStreamBuilder::open_default_stream() else {
-        let _ = event_tx.send(PlayerEvent::Error("无法打开音频输出设备".to_string()));
-        return;
+    let stream = match OutputStreamBuilder::open_default_stream() {
+        Ok(stream) => stream,
+        Err(e) => {
+            let _ = ready_tx.send(Err(e.to_string()));
+            return;
+        }
     };
+    let _ = ready_tx.send(Ok(()));
 
-    let sink = Sink::connect_new(_stream.mixer());
+    let mut sink = Sink::connect_new(stream.mixer());
     sink.pause();
 
     let mut has_source = false;
-    let mut duration_secs: u32 = 0;
+    let mut current = TrackInfo::default();
     let mut user_volume: f32 = 1.0;
-    let mut gain_db: Option<f32> = None;
-
-    let effective_volume = |uv: f32, g: Option<f32>| -> f32 {
-        match g {
-            Some(db) => (uv * 10_f32.powf(db / 20.0)).clamp(0.0, 2.0),
-            None => uv,
-        }
-    };
+    let mut crossfade = Duration::ZERO;
+    // 预载的下一首
+    let mut next: Option<PreparedTrack> = None;
+    // 淡出中的上一首及其增益；新歌始终在 `sink` 中，进度与播完检测只看它
+    let mut fading: Option<(Sink, Option<f32>)> = None;
 
     loop {
         // 非阻塞检查命令
         match cmd_rx.try_recv() {
             Ok(cmd) => match cmd {
-                PlayerCommand::Play(source, dur, gain) => {
+                PlayerCommand::Play(source, dur, gain, trim_silence) => {
+                    let _ = event_tx.send(PlayerEvent::Loading);
+                    stop_fading(&mut fading);
+                    next = None;
                     sink.stop();
-                    duration_secs = dur;
-                    gain_db = gain;
-                    match source {
-                        AudioSource::Buffered(data) => {
-                            let cursor = Cursor::new(data);
-                            match Decoder::new(cursor) {
-                                Ok(decoder) => {
-                                    sink.append(decoder);
-                                    sink.set_volume(effective_volume(user_volume, gain_db));
-                                    sink.play();
-                                    has_source = true;
-                                    let _ = event_tx.send(PlayerEvent::Playing);
-                                }
-                                Err(e) => {
-                                    let _ = event_tx.send(PlayerEvent::Error(
-                                        format!("解码失败: {e}"),
-                                    ));
-                                }
-                            }
+                    match prepare(source, dur, gain, trim_silence) {
+                        Ok(track) => {
+                            current = track.info;
+                            sink.append(track.source);
+                            sink.set_volume(effective_volume(user_volume, current.gain_db));
+                            sink.play();
+                            has_source = true;
+                            let _ = event_tx.send(PlayerEvent::Playing);
+                        }
+                        Err(e) => {
+                            let _ = event_tx.send(PlayerEvent::Error(
+                                format!("解码失败: {e}"),
+                            ));
                         }
                     }
                 }
                 PlayerCommand::Pause => {
                     sink.pause();
+                    if let Some((old, _)) = &fading {
+                        old.pause();
+                    }
+                    // 暂停后不再定时上报，补报一次暂停时的精确位置
+                    send_progress(&event_tx, &sink, current.duration_secs);
                     let _ = event_tx.send(PlayerEvent::Paused);
                 }
                 PlayerCommand::Resume => {
                     sink.play();
+                    if let Some((old, _)) = &fading {
+                        old.play();
+                    }
                     let _ = event_tx.send(PlayerEvent::Playing);
                 }
                 PlayerCommand::Stop => {
+                    stop_fading(&mut fading);
+                    next = None;
                     sink.stop();
                     has_source = false;
-                    gain_db = None;
+                    current.gain_db = None;
                     let _ = event_tx.send(PlayerEvent::Stopped);
                 }
                 PlayerCommand::Seek(pos) => {
-                    if let Err(e) = sink.try_seek(pos) {
-                        let _ = event_tx.send(PlayerEvent::Error(
-                            format!("Seek 失败: {e}"),
-                        ));
+                    // 淡入中 seek 时直接结束淡出，新歌恢复正常音量
+                    if fading.is_some() {
+                        stop_fading(&mut fading);
+                        sink.set_volume(effective_volume(user_volume, current.gain_db));
+                    }
+                    match sink.try_seek(pos) {
+                        // 暂停中 seek 也要立即反映新位置
+                        Ok(()) => send_progress(&event_tx, &sink, current.duration_secs),
+                        Err(e) => {
+                            let _ = event_tx.send(PlayerEvent::Error(
+                                format!("Seek 失败: {e}"),
+                            ));
+                        }
                     }
                 }
                 PlayerCommand::SetVolume(vol) => {
                     user_volume = vol;
-                    sink.set_volume(effective_volume(user_volume, gain_db));
+                    // 淡入淡出中的音量由下方按进度重新计算
+                    if fading.is_none() {
+                        sink.set_volume(effective_volume(user_volume, current.gain_db));
+                    }
                 }
+                PlayerCommand::Preload(track) => next = Some(track),
+                PlayerCommand::ClearPreload => next = None,
+                PlayerCommand::SetCrossfade(duration) => crossfade = duration,
             },
             Err(mpsc::error::TryRecvError::Empty) => {}
             Err(mpsc::error::TryRecvError::Disconnected) => break,
         }
 
+        // 当前歌曲剩余不足淡入淡出时长：新开一个 sink 播放预载的下一首，旧 sink 转为淡出
+        if has_source
+            && fading.is_none()
+            && !crossfade.is_zero()
+            && !sink.is_paused()
+            && current.len.is_some_and(|len| len.saturating_sub(sink.get_pos()) <= crossfade)
+            && let Some(track) = next.take()
+        {
+            let old = std::mem::replace(&mut sink, Sink::connect_new(stream.mixer()));
+            fading = Some((old, current.gain_db));
+            current = track.info;
+            sink.append(track.source);
+            sink.set_volume(0.0);
+            sink.play();
+            let _ = event_tx.send(PlayerEvent::NextStarted);
+        }
+
+        // 按新歌的播放位置线性调整两边音量，旧歌播完或淡入完成后结束淡出
+        let fade_done = if let Some((old, old_gain)) = &fading {
+            let progress = if crossfade.is_zero() {
+                1.0
+            } else {
+                (sink.get_pos().as_secs_f32() / crossfade.as_secs_f32()).min(1.0)
+            };
+            if progress < 1.0 && !old.empty() {
+                old.set_volume(effective_volume(user_volume, *old_gain) * (1.0 - progress));
+                sink.set_volume(effective_volume(user_volume, current.gain_db) * progress);
+                false
+            } else {
+                true
+            }
+        } else {
+            false
+        };
+        if fade_done {
+            stop_fading(&mut fading);
+            sink.set_volume(effective_volume(user_volume, current.gain_db));
+        }
+
         // 上报播放进度
         if has_source && !sink.empty() && !sink.is_paused() {
-            let pos = sink.get_pos().as_secs() as u32;
-            let _ = event_tx.send(PlayerEvent::Progress {
-                position_secs: pos,
-                duration_secs,
-            });
+            send_progress(&event_tx, &sink, current.duration_secs);
         }
 
-        // 检测播放结束
+        // 检测播放结束：有预载的下一首时直接接上，否则通知 UI 切歌
         if has_source && sink.empty() {
-            has_source = false;
-            let _ = event_tx.send(PlayerEvent::TrackEnded);
+            if let Some(track) = next.take() {
+                current = track.info;
+                sink.append(track.source);
+                sink.set_volume(effective_volume(user_volume, current.gain_db));
+                sink.play();
+                let _ = event_tx.send(PlayerEvent::NextStarted);
+            } else {
+                has_source = false;
+                let _ = event_tx.send(PlayerEvent::TrackEnded);
+            }
         }
 
         std::thread::sleep(Duration::from_millis(50));
     }
 }
+
+/// 立即停掉淡出中的上一首
+fn stop_fading(fading: &mut Option<(Sink, Option<f32>)>) {
+    if let Some((old, _)) = fading.take() {
+        old.stop();
+    }
+}
+
+fn send_progress(event_tx: &mpsc::UnboundedSender<PlayerEvent>, sink: &Sink, duration_secs: u32) {
+    let _ = event_tx.send(PlayerEvent::Progress {
+        position_ms: sink.get_pos().as_millis() as u64,
+        duration_secs,
+    });
+}
@@ -1,2 +1,5 @@
 pub mod engine;
+pub mod partial;
 pub mod queue;
+pub mod silence;
+pub mod volume;
@@ -0,0 +1,102 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::config::paths;
+
+/// 最多保留几首歌的未完成下载，超出时删除最旧的
+const MAX_PARTIALS: usize = 8;
+
+/// 中断下载留下的音频数据：`<id>.part` 为已收到的字节，`<id>.json` 记录完整长度
+pub struct Partial {
+    pub data: Vec<u8>,
+    pub total: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PartialMeta {
+    total: u64,
+}
+
+fn files(song_id: i64) -> Result<(PathBuf, PathBuf)> {
+    let dir = paths::audio_cache_dir()?;
+    Ok((dir.join(format!("{song_id}.part")), dir.join(format!("{song_id}.json"))))
+}
+
+/// 读取某首歌的未完成下载；缺少长度记录或数据与记录不符时删除并返回 None
+pub fn load(song_id: i64) -> Option<Partial> {
+    let (part, meta) = files(song_id).ok()?;
+    if !part.exists() {
+        return None;
+    }
+    let loaded = std::fs::read_to_string(&meta)
+        .ok()
+        .and_then(|s| serde_json::from_str::<PartialMeta>(&s).ok())
+        .zip(std::fs::read(&part).ok())
+        .filter(|(m, data)| !data.is_empty() && (data.len() as u64) < m.total);
+    match loaded {
+        Some((m, data)) => Some(Partial { data, total: m.total }),
+        None => {
+            remove(song_id);
+            None
+        }
+    }
+}
+
+/// 保存下载中断时已收到的数据，供下次用 Range 续传
+pub fn save(song_id: i64, data: &[u8], total: u64) -> Result<()> {
+    if data.is_empty() || data.len() as u64 >= total {
+        return Ok(());
+    }
+    let (part, meta) = files(song_id)?;
+    std::fs::write(&part, data)?;
+    std::fs::write(&meta, serde_json::to_string(&PartialMeta { total })?)?;
+    prune()
+}
+
+pub fn remove(song_id: i64) {
+    if let Ok((part, meta)) = files(song_id) {
+        let _ = std::fs::remove_file(part);
+        let _ = std::fs::remove_file(meta);
+    }
+}
+
+/// 只保留最近的 `MAX_PARTIALS` 个未完成下载
+fn prune() -> Result<()> {
+    let mut parts: Vec<(std::time::SystemTime, PathBuf)> = std::fs::read_dir(paths::audio_cache_dir()?)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "part"))
+        .filter_map(|p| Some((std::fs::metadata(&p).ok()?.modified().ok()?, p)))
+        .collect();
+    if parts.len() <= MAX_PARTIALS {
+        return Ok(());
+    }
+    parts.sort_by_key(|r| std::cmp::Reverse(r.0));
+    for (_, part) in parts.drain(MAX_PARTIALS..) {
+        let _ = std::fs::remove_file(part.with_extension("json"));
+        let _ = std::fs::remove_file(part);
+    }
+    Ok(())
+}
+
+/// 解析 206 响应的 `Content-Range: bytes <start>-<end>/<total>`，返回（起点, 总长）
+pub fn parse_content_range(value: &str) -> Option<(u64, u64)> {
+    let (range, total) = value.strip_prefix("bytes ")?.split_once('/')?;
+    let (start, _) = range.split_once('-')?;
+    Some((start.trim().parse().ok()?, total.trim().parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_content_range;
+
+    #[test]
+    fn parses_content_range() {
+        assert_eq!(parse_content_range("bytes 100-999/1000"), Some((100, 1000)));
+        // 总长未知时无法确认续传位置
+        assert_eq!(parse_content_range("bytes 100-999/*"), None);
+        assert_eq!(parse_content_range("items 0-1/2"), None);
+    }
+}
@@ -1,6 +1,6 @@
 use anyhow::Result;
 
-use crate::config::settings::PlayMode;
+use crate::config::settings::{PlayMode, RepeatAllFrom};
 use crate::model::queue::{MusicQueueItem, QueueState};
 use crate::config::paths;
 
@@ -16,40 +16,312 @@ impl QueueState {
     }
 
     pub fn persist(&self) -> Result<()> {
-        let path = paths::queue_file()?;
-        let content = serde_json::to_string_pretty(self)?;
-        std::fs::write(path, content)?;
-        Ok(())
+        Self::write_persisted(&serde_json::to_string_pretty(self)?)
+    }
+
+    /// 写入已序列化的队列；自动保存在主线程序列化后交给阻塞线程写入
+    pub fn write_persisted(content: &str) -> Result<()> {
+        paths::write_atomic(&paths::queue_file()?, content.as_bytes())
+    }
+
+    /// 按播放模式前进一首并返回它；没有下一首时返回 None 且不改动位置。
+    /// 结果只取决于队列状态（含 `shuffle_seed`），与 `peek_next` 的预览一致
+    pub fn next_with_mode(&mut self, mode: &PlayMode, repeat_from: RepeatAllFrom) -> Option<&MusicQueueItem> {
+        let (new_idx, new_seed) = self.next_index(mode, repeat_from)?;
+        if let Some(seed) = new_seed {
+            self.shuffle_seed = seed;
+        }
+        self.current_index = Some(new_idx);
+        self.position_ms = 0;
+        self.songs.get(new_idx)
+    }
+
+    /// 预览按播放模式接下来会播放的歌曲，不改动队列
+    pub fn peek_next(&self, mode: &PlayMode, repeat_from: RepeatAllFrom) -> Option<&MusicQueueItem> {
+        let (idx, _) = self.next_index(mode, repeat_from)?;
+        self.songs.get(idx)
     }
 
-    pub fn next_with_mode(&mut self, mode: &PlayMode) -> Option<&MusicQueueItem> {
+    /// 下一首的队列下标；随机模式一轮播完时附带新一轮的种子
+    fn next_index(&self, mode: &PlayMode, repeat_from: RepeatAllFrom) -> Option<(usize, Option<u64>)> {
         match mode {
-            PlayMode::Sequential => self.next(),
-            PlayMode::RepeatOne => {
-                self.position_ms = 0;
-                self.current_song()
+            PlayMode::Sequential => {
+                let cur = self.current_index?;
+                (cur + 1 < self.songs.len()).then_some((cur + 1, None))
             }
-            PlayMode::Shuffle => {
+            PlayMode::RepeatAll => {
                 if self.songs.is_empty() {
                     return None;
                 }
-                use rand::RngExt;
-                let mut rng = rand::rng();
-                let new_idx = rng.random_range(0..self.songs.len());
-                self.current_index = Some(new_idx);
-                self.position_ms = 0;
-                self.songs.get(new_idx)
+                let new_idx = match self.current_index {
+                    Some(cur) if cur + 1 < self.songs.len() => cur + 1,
+                    _ => self.repeat_start_index(repeat_from),
+                };
+                Some((new_idx, None))
+            }
+            PlayMode::RepeatOne => {
+                let cur = self.current_index?;
+                (cur < self.songs.len()).then_some((cur, None))
+            }
+            PlayMode::Shuffle => {
+                let order = self.shuffle_order();
+                let pos = self
+                    .current_index
+                    .and_then(|cur| order.iter().position(|&i| i == cur));
+                match pos {
+                    Some(p) if p + 1 < order.len() => Some((order[p + 1], None)),
+                    Some(_) => {
+                        // 一轮播完：由旧种子派生新顺序重新开始，避免同一首连续播放两次
+                        let seed = shuffle_key(self.shuffle_seed, 0);
+                        let order = self.order_for_seed(seed);
+                        let idx = match order.as_slice() {
+                            [first, second, ..] if Some(*first) == self.current_index => *second,
+                            _ => order[0],
+                        };
+                        Some((idx, Some(seed)))
+                    }
+                    None => Some((*order.first()?, None)),
+                }
             }
         }
     }
 
-    pub fn prev_with_mode(&mut self, mode: &PlayMode) -> Option<&MusicQueueItem> {
+    /// 按播放模式后退一首：随机模式沿本轮顺序后退，到本轮开头为止；
+    /// 列表循环在回绕起点处绕到最后一首
+    pub fn prev_with_mode(&mut self, mode: &PlayMode, repeat_from: RepeatAllFrom) -> Option<&MusicQueueItem> {
         match mode {
             PlayMode::RepeatOne => {
                 self.position_ms = 0;
                 self.current_song()
             }
-            _ => self.prev(),
+            PlayMode::Shuffle => {
+                let order = self.shuffle_order();
+                let pos = order.iter().position(|&i| Some(i) == self.current_index)?;
+                let prev_idx = *order.get(pos.checked_sub(1)?)?;
+                self.current_index = Some(prev_idx);
+                self.position_ms = 0;
+                self.songs.get(prev_idx)
+            }
+            PlayMode::RepeatAll => {
+                let cur = self.current_index?;
+                if cur != self.repeat_start_index(repeat_from) {
+                    return self.prev();
+                }
+                let last = self.songs.len().checked_sub(1)?;
+                self.current_index = Some(last);
+                self.position_ms = 0;
+                self.songs.get(last)
+            }
+            PlayMode::Sequential => self.prev(),
+        }
+    }
+
+    /// 列表循环回绕到的位置：起点歌曲已被移出队列时退回队列开头
+    fn repeat_start_index(&self, repeat_from: RepeatAllFrom) -> usize {
+        match repeat_from {
+            RepeatAllFrom::QueueStart => 0,
+            RepeatAllFrom::PlayStart => self
+                .play_start_id
+                .and_then(|id| self.songs.iter().position(|s| s.id == id))
+                .unwrap_or(0),
+        }
+    }
+
+    /// 随机模式下的播放顺序（队列下标列表）。
+    /// 排序键由种子与歌曲 ID 派生，增删歌曲不会打乱其余歌曲的相对顺序。
+    pub fn shuffle_order(&self) -> Vec<usize> {
+        self.order_for_seed(self.shuffle_seed)
+    }
+
+    fn order_for_seed(&self, seed: u64) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.songs.len()).collect();
+        order.sort_by_key(|&i| (shuffle_key(seed, self.songs[i].id), i));
+        order
+    }
+
+    /// 随机生成新的顺序（开始播放新列表时调用）
+    pub fn reshuffle(&mut self) {
+        use rand::RngExt;
+        self.shuffle_seed = rand::rng().random();
+    }
+}
+
+/// splitmix64 混合种子与歌曲 ID
+fn shuffle_key(seed: u64, id: i64) -> u64 {
+    let mut x = (seed ^ id as u64).wrapping_add(0x9e37_79b9_7f4a_7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::settings::{PlayMode, RepeatAllFrom};
+    use crate::model::queue::QueueState;
+    use crate::model::queue::fixtures::{item, queue_of};
+
+    fn next_id(queue: &mut QueueState, from: RepeatAllFrom) -> Option<i64> {
+        queue.next_with_mode(&PlayMode::RepeatAll, from).map(|s| s.id)
+    }
+
+    fn step(queue: &mut QueueState, mode: PlayMode) -> Option<i64> {
+        queue.next_with_mode(&mode, RepeatAllFrom::QueueStart).map(|s| s.id)
+    }
+
+    fn back(queue: &mut QueueState, mode: PlayMode, from: RepeatAllFrom) -> Option<i64> {
+        queue.prev_with_mode(&mode, from).map(|s| s.id)
+    }
+
+    const MODES: [PlayMode; 4] = [PlayMode::Sequential, PlayMode::RepeatAll, PlayMode::Shuffle, PlayMode::RepeatOne];
+
+    #[test]
+    fn sequential_stops_at_both_ends() {
+        let mut queue = queue_of(&[0, 1, 2], Some(1));
+        queue.position_ms = 4_000;
+        assert_eq!(step(&mut queue, PlayMode::Sequential), Some(2));
+        assert_eq!(queue.position_ms, 0);
+        assert_eq!(step(&mut queue, PlayMode::Sequential), None);
+        assert_eq!(queue.current_index, Some(2));
+
+        let mut queue = queue_of(&[0, 1, 2], Some(0));
+        assert_eq!(back(&mut queue, PlayMode::Sequential, RepeatAllFrom::QueueStart), None);
+        assert_eq!(queue.current_index, Some(0));
+    }
+
+    #[test]
+    fn repeat_one_replays_current_song() {
+        let mut queue = queue_of(&[0, 1, 2], Some(1));
+        queue.position_ms = 4_000;
+        assert_eq!(step(&mut queue, PlayMode::RepeatOne), Some(1));
+        assert_eq!(back(&mut queue, PlayMode::RepeatOne, RepeatAllFrom::QueueStart), Some(1));
+        assert_eq!(queue.position_ms, 0);
+    }
+
+    #[test]
+    fn repeat_all_prev_wraps_at_repeat_start() {
+        let mut queue = queue_of(&[0, 1, 2, 3], Some(0));
+        assert_eq!(back(&mut queue, PlayMode::RepeatAll, RepeatAllFrom::QueueStart), Some(3));
+        assert_eq!(back(&mut queue, PlayMode::RepeatAll, RepeatAllFrom::QueueStart), Some(2));
+
+        let mut queue = queue_of(&[0, 1, 2, 3], Some(2));
+        queue.mark_play_start();
+        assert_eq!(back(&mut queue, PlayMode::RepeatAll, RepeatAllFrom::PlayStart), Some(3));
+        assert_eq!(back(&mut queue, PlayMode::RepeatAll, RepeatAllFrom::PlayStart), Some(2));
+    }
+
+    #[test]
+    fn shuffle_plays_every_song_once_per_round() {
+        let mut queue = queue_of(&[0, 1, 2, 3, 4, 5], Some(0));
+        queue.shuffle_seed = 42;
+        let order = queue.shuffle_order();
+        queue.current_index = Some(order[0]);
+        let mut played = vec![order[0] as i64];
+        for _ in 1..order.len() {
+            played.extend(step(&mut queue, PlayMode::Shuffle));
+        }
+        assert_eq!(played, order.iter().map(|&i| i as i64).collect::<Vec<_>>());
+
+        // 沿本轮顺序后退，到本轮开头为止
+        for &i in order[..order.len() - 1].iter().rev() {
+            assert_eq!(back(&mut queue, PlayMode::Shuffle, RepeatAllFrom::QueueStart), Some(i as i64));
         }
+        assert_eq!(back(&mut queue, PlayMode::Shuffle, RepeatAllFrom::QueueStart), None);
+        assert_eq!(queue.current_index, Some(order[0]));
+    }
+
+    #[test]
+    fn shuffle_new_round_is_deterministic_and_never_repeats() {
+        for seed in 0..50 {
+            let mut queue = queue_of(&[0, 1, 2, 3, 4], Some(0));
+            queue.shuffle_seed = seed;
+            let last = *queue.shuffle_order().last().unwrap();
+            queue.current_index = Some(last);
+
+            // 预览的下一首必须与真正切歌的结果一致
+            let predicted = queue.peek_next(&PlayMode::Shuffle, RepeatAllFrom::QueueStart).map(|s| s.id);
+            assert_eq!(step(&mut queue, PlayMode::Shuffle), predicted);
+            assert_ne!(predicted, Some(last as i64));
+            assert_ne!(queue.shuffle_seed, seed);
+        }
+    }
+
+    #[test]
+    fn shuffle_order_keeps_its_course_when_a_song_is_appended() {
+        let mut queue = queue_of(&[0, 1, 2, 3, 4], Some(2));
+        queue.shuffle_seed = 7;
+        let shuffled_ids = |q: &QueueState| -> Vec<i64> {
+            q.shuffle_order().into_iter().map(|i| q.songs[i].id).collect()
+        };
+        let before = shuffled_ids(&queue);
+
+        // 搜索结果「追加并播放」：原有歌曲的随机顺序不变，新歌加入本轮并成为当前歌曲
+        queue.select_or_add(item(99));
+        let after = shuffled_ids(&queue);
+        assert_eq!(after.iter().copied().filter(|&id| id != 99).collect::<Vec<_>>(), before);
+        assert!(after.contains(&99));
+        assert_eq!(queue.current_song().map(|s| s.id), Some(99));
+        assert_eq!(queue.shuffle_seed, 7);
+    }
+
+    #[test]
+    fn every_mode_handles_empty_and_single_song_queues() {
+        for mode in MODES {
+            let mut empty = QueueState::new();
+            assert_eq!(step(&mut empty, mode.clone()), None);
+            assert_eq!(back(&mut empty, mode.clone(), RepeatAllFrom::QueueStart), None);
+            assert_eq!(empty.current_index, None);
+
+            let mut single = queue_of(&[0], Some(0));
+            let expected = (!matches!(mode, PlayMode::Sequential)).then_some(0);
+            assert_eq!(step(&mut single, mode.clone()), expected, "{mode:?}");
+            assert_eq!(single.current_index, Some(0));
+        }
+    }
+
+    #[test]
+    fn repeat_all_wraps_to_queue_start_by_default() {
+        let mut queue = queue_of(&[0, 1, 2, 3], Some(1));
+        queue.mark_play_start();
+        assert_eq!(next_id(&mut queue, RepeatAllFrom::QueueStart), Some(2));
+        assert_eq!(next_id(&mut queue, RepeatAllFrom::QueueStart), Some(3));
+        assert_eq!(next_id(&mut queue, RepeatAllFrom::QueueStart), Some(0));
+        assert_eq!(queue.current_index, Some(0));
+    }
+
+    #[test]
+    fn repeat_all_wraps_to_play_start() {
+        let mut queue = queue_of(&[0, 1, 2, 3], Some(2));
+        queue.mark_play_start();
+        assert_eq!(next_id(&mut queue, RepeatAllFrom::PlayStart), Some(3));
+        assert_eq!(next_id(&mut queue, RepeatAllFrom::PlayStart), Some(2));
+        assert_eq!(next_id(&mut queue, RepeatAllFrom::PlayStart), Some(3));
+        assert_eq!(queue.current_index, Some(3));
+    }
+
+    #[test]
+    fn repeat_all_play_start_follows_moved_song() {
+        let mut queue = queue_of(&[0, 1, 2, 3], Some(2));
+        queue.mark_play_start();
+        // 起点歌曲（id 2）被移到队首，回绕按 ID 找到新位置
+        queue.move_to(2, 0);
+        queue.current_index = Some(3);
+        assert_eq!(next_id(&mut queue, RepeatAllFrom::PlayStart), Some(2));
+        assert_eq!(queue.current_index, Some(0));
+
+        // 起点歌曲被移除后退回队列开头
+        queue.remove(0);
+        queue.current_index = Some(2);
+        assert_eq!(next_id(&mut queue, RepeatAllFrom::PlayStart), Some(0));
+    }
+
+    #[test]
+    fn repeat_all_single_song_and_empty_queue() {
+        let mut queue = queue_of(&[0], Some(0));
+        assert_eq!(next_id(&mut queue, RepeatAllFrom::PlayStart), Some(0));
+        assert_eq!(queue.current_index, Some(0));
+
+        let mut empty = QueueState::new();
+        assert_eq!(next_id(&mut empty, RepeatAllFrom::QueueStart), None);
+        assert_eq!(empty.current_index, None);
     }
 }
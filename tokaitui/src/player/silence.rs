@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+/// 幅度低于该值（约 -60 dBFS）的采样视为静音
+const SILENCE_THRESHOLD: f32 = 0.001;
+
+/// 去掉首尾静音后的有效播放区间
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Trim {
+    pub start: Duration,
+    pub end: Duration,
+}
+
+/// 扫描已完整下载的音频，返回首尾静音之间的区间；没有可裁剪的静音、整首静音或解码失败时返回 None
+#[cfg(feature = "trim-silence")]
+pub fn scan(data: &[u8]) -> Option<Trim> {
+    use rodio::{Decoder, Source};
+
+    // 需要完整解码一遍，只在 Buffered 音频上做
+    let decoder = Decoder::new(std::io::Cursor::new(data.to_vec())).ok()?;
+    let channels = decoder.channels() as usize;
+    let sample_rate = decoder.sample_rate();
+    let (first, last, total) = loud_frames(decoder, channels)?;
+    if first == 0 && last + 1 == total {
+        return None;
+    }
+    let at = |frame: usize| Duration::from_secs_f64(frame as f64 / sample_rate as f64);
+    Some(Trim { start: at(first), end: at(last + 1) })
+}
+
+/// 未启用 `trim-silence` 特性时不做扫描
+#[cfg(not(feature = "trim-silence"))]
+pub fn scan(_data: &[u8]) -> Option<Trim> {
+    None
+}
+
+/// 返回（第一个非静音帧, 最后一个非静音帧, 总帧数）；整段静音时返回 None
+#[cfg_attr(not(feature = "trim-silence"), allow(dead_code))]
+fn loud_frames(samples: impl Iterator<Item = f32>, channels: usize) -> Option<(usize, usize, usize)> {
+    let channels = channels.max(1);
+    let mut first = None;
+    let mut last = 0;
+    let mut count = 0;
+    for (i, sample) in samples.enumerate() {
+        count = i + 1;
+        if sample.abs() >= SILENCE_THRESHOLD {
+            let frame = i / channels;
+            first.get_or_insert(frame);
+            last = frame;
+        }
+    }
+    Some((first?, last, count.div_ceil(channels)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::loud_frames;
+
+    #[test]
+    fn finds_loud_region_in_interleaved_frames() {
+        // 双声道：2 帧静音、2 帧有声、1 帧静音
+        let samples = [0.0, 0.0, 0.0, 0.0, 0.5, 0.0, 0.0, -0.3, 0.0, 0.0];
+        assert_eq!(loud_frames(samples.into_iter(), 2), Some((2, 3, 5)));
+    }
+
+    #[test]
+    fn all_silent_is_not_trimmed() {
+        assert_eq!(loud_frames([0.0; 8].into_iter(), 2), None);
+    }
+}
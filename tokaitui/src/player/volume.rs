@@ -0,0 +1,107 @@
+pub const MAX_VOLUME: u8 = 100;
+
+/// 音量与静音状态。
+/// 静音时 `level` 为 0，静音前的音量保存在 `before_mute`，取消静音时原样恢复。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VolumeState {
+    level: u8,
+    before_mute: Option<u8>,
+}
+
+impl VolumeState {
+    pub fn new(level: u8) -> Self {
+        Self { level: level.min(MAX_VOLUME), before_mute: None }
+    }
+
+    /// 实际输出的音量（静音时为 0）
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    /// 用户设定的音量：静音时为静音前的音量
+    pub fn target(&self) -> u8 {
+        self.before_mute.unwrap_or(self.level)
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.before_mute.is_some()
+    }
+
+    /// 输出给引擎的音量比例
+    pub fn gain(&self) -> f32 {
+        self.level as f32 / MAX_VOLUME as f32
+    }
+
+    /// 调整音量。静音时只调整静音前的音量并保持静音，取消静音后按调整后的值恢复。
+    pub fn adjust(&mut self, delta: i16) {
+        let next = |v: u8| (v as i16 + delta).clamp(0, MAX_VOLUME as i16) as u8;
+        match self.before_mute.as_mut() {
+            Some(saved) => *saved = next(*saved),
+            None => self.level = next(self.level),
+        }
+    }
+
+    /// 切换静音
+    pub fn toggle_mute(&mut self) {
+        match self.before_mute.take() {
+            Some(saved) => self.level = saved,
+            None => {
+                self.before_mute = Some(self.level);
+                self.level = 0;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VolumeState;
+
+    #[test]
+    fn mute_then_unmute_restores_previous_level() {
+        let mut vol = VolumeState::new(60);
+        vol.toggle_mute();
+        assert!(vol.is_muted());
+        assert_eq!(vol.level(), 0);
+        assert_eq!(vol.target(), 60);
+        vol.toggle_mute();
+        assert!(!vol.is_muted());
+        assert_eq!(vol.level(), 60);
+    }
+
+    #[test]
+    fn adjusting_while_muted_changes_restored_level_only() {
+        let mut vol = VolumeState::new(60);
+        vol.toggle_mute();
+        vol.adjust(5);
+        vol.adjust(5);
+        assert!(vol.is_muted());
+        assert_eq!(vol.level(), 0);
+        vol.toggle_mute();
+        assert_eq!(vol.level(), 70);
+
+        vol.toggle_mute();
+        vol.adjust(-100);
+        vol.toggle_mute();
+        assert_eq!(vol.level(), 0);
+        assert!(!vol.is_muted());
+    }
+
+    #[test]
+    fn muting_at_zero_still_round_trips() {
+        let mut vol = VolumeState::new(0);
+        vol.toggle_mute();
+        vol.adjust(10);
+        vol.toggle_mute();
+        assert_eq!(vol.level(), 10);
+    }
+
+    #[test]
+    fn adjust_clamps_to_range() {
+        let mut vol = VolumeState::new(98);
+        vol.adjust(5);
+        assert_eq!(vol.level(), 100);
+        vol.adjust(-200);
+        assert_eq!(vol.level(), 0);
+    }
+}
@@ -0,0 +1,6 @@
+/// 生成写入系统剪贴板的 OSC 52 序列（终端不支持时会被静默忽略）
+pub fn osc52_copy(text: &str) -> Vec<u8> {
+    use base64::Engine;
+    let encoded = base64::engine::general_purpose::STANDARD.encode(text);
+    format!("\x1b]52;c;{encoded}\x07").into_bytes()
+}
@@ -0,0 +1,28 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Paragraph, Wrap},
+};
+
+use super::theme::Theme;
+
+/// 渲染是/否确认浮层（退出等需要二次确认的操作共用）
+pub fn render(frame: &mut Frame, area: Rect, title: &str, message: &str) {
+    let (content_area, hint_area) = super::util::overlay_panel(
+        frame, area, title,
+        super::constants::CONFIRM_PANEL_WIDTH, 6,
+    );
+
+    let lines = vec![
+        Line::from(""),
+        Line::from(format!("  {message}")),
+    ];
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), content_area);
+
+    let hint = Paragraph::new(Span::styled(
+        format!("  {}", t!("confirm.hint")),
+        Theme::secondary(),
+    ));
+    frame.render_widget(hint, hint_area);
+}
@@ -2,10 +2,19 @@ pub const HELP_PANEL_WIDTH: u16 = 42;
 pub const LOG_PANEL_WIDTH: u16 = 70;
 pub const LOG_PANEL_HEIGHT: u16 = 20;
 pub const LOGIN_FORM_WIDTH: u16 = 44;
+pub const STATS_PANEL_WIDTH: u16 = 40;
+pub const CONFIRM_PANEL_WIDTH: u16 = 40;
+pub const INFO_PANEL_WIDTH: u16 = 70;
+pub const PINNED_PANEL_WIDTH: u16 = 40;
+pub const HIDDEN_PANEL_WIDTH: u16 = 50;
 
 pub const HEADER_HEIGHT: u16 = 1;
 pub const PLAYER_BAR_HEIGHT: u16 = 1;
 pub const SEARCH_BAR_HEIGHT: u16 = 1;
+/// 新版布局中独立的面包屑行
+pub const BREADCRUMB_HEIGHT: u16 = 1;
+/// 播放栏小封面占的列数（单行高，按字符 1:2 宽高比约为方形）
+pub const BAR_THUMBNAIL_WIDTH: u16 = 2;
 pub const MILLER_PARENT_PCT: u16 = 15;
 pub const MILLER_CURRENT_PCT: u16 = 45;
 pub const MILLER_PREVIEW_PCT: u16 = 40;
@@ -8,7 +8,7 @@ use ratatui::{
 
 // 每帧 draw 期间收集到的待放置封面，draw 结束后由主循环消费并写入 stdout
 thread_local! {
-    pub static PENDING_PLACEMENTS: RefCell<Vec<(u32, Rect)>> = RefCell::new(Vec::new());
+    pub static PENDING_PLACEMENTS: RefCell<Vec<(u32, Rect)>> = const { RefCell::new(Vec::new()) };
 }
 
 pub struct CoverWidget {
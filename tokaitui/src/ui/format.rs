@@ -5,6 +5,8 @@ use ratatui::{
     text::{Line, Span},
 };
 
+use super::theme::Theme;
+
 /// 按显示宽度截断文本，末尾加 ".."
 pub(crate) fn truncate_with_dots(text: &str, max_width: usize) -> String {
     let dots_width = 2; // ".." 占 2 列
@@ -23,6 +25,27 @@ pub(crate) fn truncate_with_dots(text: &str, max_width: usize) -> String {
     result
 }
 
+/// 按显示宽度从左侧截断文本，开头加 ".."，保留末尾（路径类文本最深处更重要）
+pub(crate) fn truncate_left_with_dots(text: &str, max_width: usize) -> String {
+    if text.width() <= max_width {
+        return text.to_string();
+    }
+    let content_width = max_width.saturating_sub(2);
+    let mut tail: Vec<char> = Vec::new();
+    let mut width = 0;
+    for c in text.chars().rev() {
+        let cw = c.width().unwrap_or(0);
+        if width + cw > content_width {
+            break;
+        }
+        tail.push(c);
+        width += cw;
+    }
+    let mut result = String::from("..");
+    result.extend(tail.into_iter().rev());
+    result
+}
+
 /// Marquee 文字滚动：在固定宽度内循环显示超长文本
 /// 开头和结尾各停顿 pause 个 tick，中间每 tick 滚动一个字符
 /// 使用 unicode 显示宽度，正确处理 CJK 双宽字符
@@ -72,16 +95,62 @@ pub(crate) fn marquee_text(text: &str, max_width: usize, tick: u16) -> String {
     result
 }
 
+/// 多位艺人的展示行（如 "Artists: A · B"），没有艺人时返回 None
+pub fn artists_line(song: &crate::model::song::PublicSongDetail) -> Option<Line<'static>> {
+    let artists = song.artists();
+    if artists.is_empty() {
+        return None;
+    }
+    let mut spans = vec![Span::styled(
+        format!("{}: ", t!("miller.artists")),
+        Style::default().add_modifier(Modifier::BOLD),
+    )];
+    for (i, member) in artists.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled(" \u{00b7} ", Style::default().fg(Color::DarkGray)));
+        }
+        let name = member.person_name.clone().unwrap_or_default();
+        // 有用户主页的艺人加下划线，提示可按 u 跳转
+        let style = if member.uid.is_some() {
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::UNDERLINED)
+        } else {
+            Style::default().fg(Color::Cyan)
+        };
+        spans.push(Span::styled(name, style));
+    }
+    Some(Line::from(spans))
+}
+
+/// 正在播放标记的动画帧（跳动的均衡器）
+const EQ_FRAMES: [&str; 6] = ["\u{2581}", "\u{2583}", "\u{2585}", "\u{2587}", "\u{2585}", "\u{2583}"];
+/// 暂停时的静止标记
+const EQ_PAUSED: &str = "\u{2016}";
+
+/// 正在播放歌曲的行首标记：播放中按 tick 轮换均衡器帧，暂停时静止
+pub fn now_playing_glyph(is_playing: bool, tick: u16) -> &'static str {
+    if is_playing {
+        EQ_FRAMES[tick as usize % EQ_FRAMES.len()]
+    } else {
+        EQ_PAUSED
+    }
+}
+
 /// 渲染歌曲列表行（标题左对齐 + Artist 右对齐 DarkGray）
-/// 选中项支持 marquee 滚动显示超长文字
+/// `marquee_tick` 为 Some 时超长标题按该帧滚动显示（选中项且开启滚动），否则截断；
+/// `marker` 为行首标记（队列中非播放行用空白占位）；`now_playing` 时标记与标题使用正在播放强调色，
+/// 与选中行的背景（由列表 highlight_style 叠加）互不覆盖
 pub fn song_list_line(
+    marker: Option<&'static str>,
+    now_playing: bool,
     title: &str,
     artist: &str,
     width: u16,
     is_selected: bool,
-    scroll_tick: u16,
+    marquee_tick: Option<u16>,
 ) -> Line<'static> {
-    let available = width as usize;
+    // 标记占 2 列（前导空格 + 字形）
+    let marker_width = if marker.is_some() { 2 } else { 0 };
+    let available = (width as usize).saturating_sub(marker_width);
 
     // Artist 保持完整显示，标题占剩余空间（使用显示宽度）
     let artist_display = format!(" {}", artist);
@@ -94,10 +163,9 @@ pub fn song_list_line(
 
     // 仅对歌曲名做截断和 marquee 滚动
     let title_display: String = if title_truncated {
-        if is_selected {
-            marquee_text(&title_full, title_max, scroll_tick)
-        } else {
-            truncate_with_dots(&title_full, title_max)
+        match marquee_tick {
+            Some(tick) => marquee_text(&title_full, title_max, tick),
+            None => truncate_with_dots(&title_full, title_max),
         }
     } else {
         title_full
@@ -108,12 +176,11 @@ pub fn song_list_line(
     let padding = available.saturating_sub(title_display_width + artist_display_width);
     let pad: String = " ".repeat(padding);
 
-    let title_style = if is_selected {
-        Style::default()
-            .fg(Color::Cyan)
-            .add_modifier(Modifier::BOLD)
-    } else {
-        Style::default()
+    let title_style = match (is_selected, now_playing) {
+        (true, true) => Theme::now_playing().add_modifier(Modifier::BOLD),
+        (false, true) => Theme::now_playing(),
+        (true, false) => Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        (false, false) => Style::default(),
     };
 
     let artist_style = if is_selected {
@@ -124,9 +191,12 @@ pub fn song_list_line(
         Style::default().fg(Color::DarkGray)
     };
 
-    Line::from(vec![
-        Span::styled(title_display, title_style),
-        Span::raw(pad),
-        Span::styled(artist_display, artist_style),
-    ])
+    let mut spans = Vec::with_capacity(4);
+    if let Some(m) = marker {
+        spans.push(Span::styled(format!(" {m}"), Theme::now_playing()));
+    }
+    spans.push(Span::styled(title_display, title_style));
+    spans.push(Span::raw(pad));
+    spans.push(Span::styled(artist_display, artist_style));
+    Line::from(spans)
 }
@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use ratatui::layout::Rect;
+use serde::{Deserialize, Serialize};
+
+use super::{kitty, sixel};
+
+/// 下载的封面统一缩放到的边长（像素）
+pub const COVER_PIXELS: u32 = 800;
+
+/// 配置中的图形协议选择
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GraphicsMode {
+    /// 按环境变量检测，Kitty 优先
+    #[default]
+    Auto,
+    Kitty,
+    Sixel,
+    Off,
+}
+
+/// 实际使用的封面图形协议
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphicsProtocol {
+    Kitty,
+    Sixel,
+}
+
+/// 按配置选择图形协议，返回协议与判定依据
+pub fn detect(mode: GraphicsMode) -> Option<(GraphicsProtocol, &'static str)> {
+    match mode {
+        GraphicsMode::Off => None,
+        GraphicsMode::Kitty => Some((GraphicsProtocol::Kitty, "config")),
+        GraphicsMode::Sixel => Some((GraphicsProtocol::Sixel, "config")),
+        GraphicsMode::Auto => kitty::detection_source()
+            .map(|src| (GraphicsProtocol::Kitty, src))
+            .or_else(|| sixel::detection_source().map(|src| (GraphicsProtocol::Sixel, src))),
+    }
+}
+
+impl GraphicsProtocol {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Kitty => "kitty",
+            Self::Sixel => "sixel",
+        }
+    }
+
+    /// 在后台线程把缩放好的封面（`COVER_PIXELS` 见方的 RGB）转换为缓存数据：
+    /// Kitty 为上传序列（可附带调暗的背景副本），Sixel 保留原始像素，放置时再按尺寸编码
+    pub fn prepare_cover(self, id: u32, rgb: Vec<u8>, backdrop: Option<Vec<u8>>) -> Vec<u8> {
+        match self {
+            Self::Kitty => {
+                let mut seq = kitty::upload_rgb(id, &rgb, COVER_PIXELS, COVER_PIXELS);
+                if let Some(dimmed) = backdrop {
+                    seq.extend(kitty::upload_rgb(kitty::backdrop_id(id), &dimmed, COVER_PIXELS, COVER_PIXELS));
+                }
+                seq
+            }
+            Self::Sixel => rgb,
+        }
+    }
+
+    /// 是否支持把背景封面放在文字下方
+    pub fn supports_backdrop(self) -> bool {
+        self == Self::Kitty
+    }
+
+    pub fn backend(self) -> Box<dyn CoverBackend> {
+        match self {
+            Self::Kitty => Box::new(KittyBackend::default()),
+            Self::Sixel => Box::new(SixelBackend::default()),
+        }
+    }
+}
+
+/// 封面输出的协议实现：preview 与 player_view 只通过 `CoverWidget` 登记位置，
+/// draw 结束后由主循环把本帧的放置交给这里写入终端
+pub trait CoverBackend {
+    /// 封面数据就绪，`data` 为 `prepare_cover` 的结果
+    fn on_ready(&mut self, out: &mut dyn Write, data: &[u8]) -> io::Result<()>;
+    /// 封面被淘汰，释放终端或本地缓存
+    fn evict(&mut self, out: &mut dyn Write, id: u32) -> io::Result<()>;
+    /// 终端尺寸变化（终端会清屏，Kitty 还会清除已上传的图片）
+    fn on_resize(&mut self);
+    /// 写出本帧的放置；`covers` 为全部已缓存封面的 (image ID, 数据)。返回 true 表示需要整屏重绘后再放置
+    fn place(&mut self, out: &mut dyn Write, placements: &[(u32, Rect)], covers: &[(u32, &[u8])]) -> io::Result<bool>;
+}
+
+/// Kitty：图片只上传一次，每帧删除上帧 placement 后按位置重新放置。
+/// 封面缩放只改变放置的格数（`c`/`r`），由终端缩放同一份图片，无需重新上传
+#[derive(Default)]
+struct KittyBackend {
+    active_ids: Vec<u32>,
+    needs_reupload: bool,
+}
+
+impl CoverBackend for KittyBackend {
+    fn on_ready(&mut self, out: &mut dyn Write, data: &[u8]) -> io::Result<()> {
+        out.write_all(data)?;
+        out.flush()
+    }
+
+    fn evict(&mut self, out: &mut dyn Write, id: u32) -> io::Result<()> {
+        out.write_all(&kitty::delete_image(id))?;
+        out.write_all(&kitty::delete_image(kitty::backdrop_id(id)))?;
+        out.flush()
+    }
+
+    fn on_resize(&mut self) {
+        self.needs_reupload = true;
+    }
+
+    fn place(&mut self, out: &mut dyn Write, placements: &[(u32, Rect)], covers: &[(u32, &[u8])]) -> io::Result<bool> {
+        if self.active_ids.is_empty() && placements.is_empty() && !self.needs_reupload {
+            return Ok(false);
+        }
+
+        // 终端缩放后 image data 被清除，需先重新上传再放置
+        if self.needs_reupload {
+            for (_, seq) in covers {
+                out.write_all(seq)?;
+            }
+            self.needs_reupload = false;
+        }
+
+        if self.active_ids.is_empty() && placements.is_empty() {
+            out.flush()?;
+            return Ok(false);
+        }
+
+        out.write_all(b"\x1b7")?;
+
+        // 删除所有上帧 placement（d=i 小写：保留 image data，避免 re-place 时数据不存在）
+        for &id in &self.active_ids {
+            out.write_all(&kitty::delete_placement(id))?;
+        }
+
+        // 放置本帧所有封面
+        for (id, rect) in placements {
+            write!(out, "\x1b[{};{}H", rect.y + 1, rect.x + 1)?;
+            if kitty::is_backdrop(*id) {
+                out.write_all(&kitty::place_behind_text(*id, rect.width, rect.height))?;
+            } else {
+                out.write_all(&kitty::place_at_cursor(*id, rect.width, rect.height))?;
+            }
+        }
+
+        out.write_all(b"\x1b8")?;
+        out.flush()?;
+
+        self.active_ids = placements.iter().map(|(id, _)| *id).collect();
+        Ok(false)
+    }
+}
+
+/// 终端报告不出格子像素尺寸时的假定值
+const FALLBACK_CELL_PX: (u16, u16) = (10, 20);
+
+/// Sixel：图片写进字符格，不能单独删除。放置不变时不重复输出；
+/// 变化时先整屏重绘清掉旧图，下一帧再输出。编码结果按 (ID, 列, 行) 缓存，
+/// 封面缩放改变尺寸后按新尺寸从缓存的原始像素重新编码
+#[derive(Default)]
+struct SixelBackend {
+    shown: Vec<(u32, Rect)>,
+    encoded: HashMap<(u32, u16, u16), Vec<u8>>,
+    cell_px: Option<(u16, u16)>,
+}
+
+impl SixelBackend {
+    fn cell_px(&mut self) -> (u16, u16) {
+        *self.cell_px.get_or_insert_with(|| {
+            crossterm::terminal::window_size()
+                .ok()
+                .filter(|s| s.width > 0 && s.height > 0 && s.columns > 0 && s.rows > 0)
+                .map(|s| (s.width / s.columns, s.height / s.rows))
+                .unwrap_or(FALLBACK_CELL_PX)
+        })
+    }
+}
+
+impl CoverBackend for SixelBackend {
+    fn on_ready(&mut self, _out: &mut dyn Write, _data: &[u8]) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn evict(&mut self, _out: &mut dyn Write, id: u32) -> io::Result<()> {
+        self.encoded.retain(|&(key, _, _), _| key != id);
+        Ok(())
+    }
+
+    fn on_resize(&mut self) {
+        self.shown.clear();
+        self.encoded.clear();
+        self.cell_px = None;
+    }
+
+    fn place(&mut self, out: &mut dyn Write, placements: &[(u32, Rect)], covers: &[(u32, &[u8])]) -> io::Result<bool> {
+        // 背景副本只有 Kitty 能放在文字下方
+        let placements: Vec<(u32, Rect)> = placements.iter()
+            .copied()
+            .filter(|(id, _)| !kitty::is_backdrop(*id))
+            .collect();
+        if placements == self.shown {
+            return Ok(false);
+        }
+        if !self.shown.is_empty() {
+            self.shown.clear();
+            return Ok(true);
+        }
+
+        let (cw, ch) = self.cell_px();
+        out.write_all(b"\x1b7")?;
+        for &(id, rect) in &placements {
+            let Some(&(_, rgb)) = covers.iter().find(|(cid, _)| *cid == id) else { continue };
+            let seq = self.encoded.entry((id, rect.width, rect.height)).or_insert_with(|| {
+                let (w, h) = (rect.width as u32 * cw as u32, rect.height as u32 * ch as u32);
+                if w == 0 || h == 0 {
+                    return Vec::new();
+                }
+                let Some(img) = image::RgbImage::from_raw(COVER_PIXELS, COVER_PIXELS, rgb.to_vec()) else {
+                    return Vec::new();
+                };
+                let img = image::imageops::resize(&img, w, h, image::imageops::FilterType::Triangle);
+                sixel::encode(img.as_raw(), w as usize, h as usize)
+            });
+            write!(out, "\x1b[{};{}H", rect.y + 1, rect.x + 1)?;
+            out.write_all(seq)?;
+        }
+        out.write_all(b"\x1b8")?;
+        out.flush()?;
+        self.shown = placements;
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn placed(out: &[u8]) -> String {
+        String::from_utf8_lossy(out).into_owned()
+    }
+
+    #[test]
+    fn kitty_replaces_placement_at_new_scale() {
+        let mut backend = KittyBackend::default();
+        let mut out = Vec::new();
+        backend.place(&mut out, &[(1, Rect::new(0, 0, 8, 4))], &[]).unwrap();
+        assert!(placed(&out).contains("a=p,i=1,c=8,r=4"));
+
+        // 缩放后同一张图删除旧 placement、按新格数放置，不重新上传
+        out.clear();
+        backend.place(&mut out, &[(1, Rect::new(0, 0, 12, 6))], &[]).unwrap();
+        let seq = placed(&out);
+        assert!(seq.contains("a=d,d=i,i=1"));
+        assert!(seq.contains("a=p,i=1,c=12,r=6"));
+        assert!(!seq.contains("a=t"));
+    }
+
+    #[test]
+    fn sixel_reencodes_cover_at_new_scale() {
+        let rgb = vec![0u8; (COVER_PIXELS * COVER_PIXELS * 3) as usize];
+        let covers = [(1, rgb.as_slice())];
+        let mut backend = SixelBackend { cell_px: Some((1, 1)), ..Default::default() };
+        let mut out = Vec::new();
+        assert!(!backend.place(&mut out, &[(1, Rect::new(0, 0, 4, 2))], &covers).unwrap());
+        assert!(placed(&out).contains("\"1;1;4;2"));
+
+        // 尺寸变化：先要求整屏重绘清掉旧图，下一帧按新尺寸重新编码
+        out.clear();
+        assert!(backend.place(&mut out, &[(1, Rect::new(0, 0, 8, 4))], &covers).unwrap());
+        assert!(out.is_empty());
+        assert!(!backend.place(&mut out, &[(1, Rect::new(0, 0, 8, 4))], &covers).unwrap());
+        assert!(placed(&out).contains("\"1;1;8;4"));
+    }
+}
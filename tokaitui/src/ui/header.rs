@@ -0,0 +1,69 @@
+use ratatui::text::Span;
+use unicode_width::UnicodeWidthStr;
+
+/// 顶栏右侧色块的舍弃优先级：空间不足时从 `Clock` 开始依次省略，`Pinned` 永不省略
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SegmentRank {
+    /// 未读日志数等必须可见的提示
+    Pinned,
+    /// 无音频、省流等状态标记
+    Badge,
+    Volume,
+    Mode,
+    Clock,
+}
+
+/// 按宽度上限挑选右侧色块：总宽度超出时先去掉优先级最低的一段，保持原有顺序
+pub fn fit_segments(mut segments: Vec<(Span<'_>, SegmentRank)>, max_width: usize) -> Vec<Span<'_>> {
+    let width = |segs: &[(Span, SegmentRank)]| segs.iter().map(|(s, _)| s.content.width()).sum::<usize>();
+    while width(&segments) > max_width {
+        let Some(drop) = segments
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, rank))| *rank != SegmentRank::Pinned)
+            .max_by_key(|(i, (_, rank))| (*rank, *i))
+            .map(|(i, _)| i)
+        else {
+            break;
+        };
+        segments.remove(drop);
+    }
+    segments.into_iter().map(|(span, _)| span).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SegmentRank, fit_segments};
+    use ratatui::text::Span;
+
+    fn header_segments() -> Vec<(Span<'static>, SegmentRank)> {
+        vec![
+            (Span::raw(" ! 3 "), SegmentRank::Pinned),
+            (Span::raw(" [>] "), SegmentRank::Mode),
+            (Span::raw(" vol 60% "), SegmentRank::Volume),
+            (Span::raw(" 12:34 "), SegmentRank::Clock),
+        ]
+    }
+
+    fn texts(spans: &[Span]) -> Vec<String> {
+        spans.iter().map(|s| s.content.to_string()).collect()
+    }
+
+    #[test]
+    fn wide_header_keeps_everything() {
+        assert_eq!(fit_segments(header_segments(), 80).len(), 4);
+    }
+
+    #[test]
+    fn drops_clock_then_mode() {
+        assert_eq!(texts(&fit_segments(header_segments(), 20)), [" ! 3 ", " [>] ", " vol 60% "]);
+        assert_eq!(texts(&fit_segments(header_segments(), 14)), [" ! 3 ", " vol 60% "]);
+    }
+
+    #[test]
+    fn twenty_column_header_keeps_title_and_unread() {
+        // 20 列终端减去 "  HACHIMI" 标题后只剩 11 列
+        let spans = fit_segments(header_segments(), 20 - "  HACHIMI".len());
+        assert_eq!(texts(&spans), [" ! 3 "]);
+    }
+}
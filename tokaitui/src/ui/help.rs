@@ -17,12 +17,23 @@ fn help_sections() -> Vec<(&'static str, Vec<(&'static str, &'static str)>)> {
                 ("Space", t!("help.play_pause")),
                 ("n / N", t!("help.next_prev")),
                 ("+/= / -", t!("help.volume")),
+                ("m", t!("help.mute")),
                 ("> / <", t!("help.seek")),
+                (". / ,", t!("help.seek_fine")),
+                ("0 / $", t!("help.seek_start_end")),
                 ("s", t!("help.play_mode")),
                 ("i", t!("help.player_view")),
+                ("Q", t!("help.goto_queue")),
+                ("M", t!("help.mini_player")),
+                ("y", t!("help.share_moment")),
+                ("Y", t!("help.copy_ids")),
                 // ("/", t!("help.search")),  // TODO: 搜索功能尚未实现
                 ("?", t!("help.help")),
                 ("!", t!("help.logs")),
+                ("S", t!("help.stats")),
+                ("*", t!("help.pin")),
+                ("P", t!("help.pinned")),
+                ("I", t!("help.song_info")),
                 ("L", t!("help.logout")),
             ],
         ),
@@ -32,10 +43,26 @@ fn help_sections() -> Vec<(&'static str, Vec<(&'static str, &'static str)>)> {
                 ("j / k", t!("help.down_up")),
                 ("l / Enter", t!("help.drill_in")),
                 ("h", t!("help.drill_out")),
+                ("Esc", t!("help.back")),
+                ("x", t!("help.clear_search")),
                 ("g / G", t!("help.top_bottom")),
+                ("r", t!("help.retry_load")),
+                ("J / K", t!("help.detail_scroll")),
+                ("f", t!("help.follow_playback")),
                 ("a", t!("help.add_queue")),
+                ("A", t!("help.add_list_queue")),
+                ("F", t!("help.play_from_here")),
                 ("d", t!("help.remove_queue")),
-                ("o", t!("help.open_link")),
+                ("T / B", t!("help.queue_top_bottom")),
+                ("z", t!("help.queue_order")),
+                ("O", t!("help.sort_queue")),
+                ("U", t!("help.undo_queue")),
+                ("X", t!("help.hide_song")),
+                ("H", t!("help.hidden")),
+                ("o [1-9]", t!("help.open_link")),
+                ("u", t!("help.goto_artist")),
+                ("t", t!("help.favorite_tag")),
+                ("Ctrl+t", t!("help.next_favorite_tag")),
                 // ("p", t!("help.add_playlist")),  // TODO: 歌单功能尚未实现
             ],
         ),
@@ -57,6 +84,19 @@ fn help_sections() -> Vec<(&'static str, Vec<(&'static str, &'static str)>)> {
     ]
 }
 
+/// 以当前语言生成 Markdown 格式的快捷键速查表，内容与帮助面板一致
+pub fn cheatsheet_markdown() -> String {
+    let mut out = format!("# {}\n", t!("help.title"));
+    for (section_name, bindings) in help_sections() {
+        out.push_str(&format!("\n## {section_name}\n\n| Key | |\n|-----|-|\n"));
+        for (key, desc) in bindings {
+            // 表格中的 | 需要转义
+            out.push_str(&format!("| `{}` | {desc} |\n", key.replace('|', "\\|")));
+        }
+    }
+    out
+}
+
 /// 渲染悬浮帮助面板（居中覆盖）
 pub fn render(frame: &mut Frame, area: Rect, scroll: u16) {
     let sections = help_sections();
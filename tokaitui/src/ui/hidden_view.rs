@@ -0,0 +1,53 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use crate::config::hidden_songs::HiddenSong;
+
+use super::theme::Theme;
+
+/// 渲染已隐藏歌曲浮层：j/k 选择，d 恢复选中项，c 清空
+pub fn render(frame: &mut Frame, area: Rect, songs: &[HiddenSong], selected: usize) {
+    // 面板外高度 = 2 (borders) + 空行 + 条目（至少一行空提示） + 1 (hint)
+    let panel_h = songs.len().max(1) as u16 + 4;
+    let (content_area, hint_area) = super::util::overlay_panel(
+        frame, area, t!("hidden.title"),
+        super::constants::HIDDEN_PANEL_WIDTH, panel_h,
+    );
+
+    let mut lines = vec![Line::from("")];
+    if songs.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!("  {}", t!("hidden.empty")),
+            Theme::secondary(),
+        )));
+    }
+    let width = content_area.width.saturating_sub(2) as usize;
+    for (i, song) in songs.iter().enumerate() {
+        let is_selected = i == selected;
+        let marker = if is_selected { "▸ " } else { "  " };
+        let label = if song.title.is_empty() {
+            format!("#{}", song.id)
+        } else {
+            format!("{} - {}", song.title, song.artist)
+        };
+        lines.push(Line::from(Span::styled(
+            format!("{marker}{}", super::format::truncate_with_dots(&label, width)),
+            Theme::list_item_style(is_selected, true),
+        )));
+    }
+
+    // 选中项超出面板时整体上移
+    let visible = content_area.height.saturating_sub(1) as usize;
+    let scroll = (selected + 1).saturating_sub(visible) as u16;
+    frame.render_widget(Paragraph::new(lines).scroll((scroll, 0)), content_area);
+
+    let hint = Paragraph::new(Span::styled(
+        format!("  {}", t!("hidden.hint")),
+        Theme::secondary(),
+    ));
+    frame.render_widget(hint, hint_area);
+}
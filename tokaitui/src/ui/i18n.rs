@@ -19,10 +19,10 @@ impl Lang {
     /// 从系统环境变量检测语言，首次启动时使用
     fn detect_system() -> Self {
         for var in ["LC_ALL", "LC_MESSAGES", "LANG"] {
-            if let Ok(val) = std::env::var(var) {
-                if val.starts_with("zh") {
-                    return Lang::Zh;
-                }
+            if let Ok(val) = std::env::var(var)
+                && val.starts_with("zh")
+            {
+                return Lang::Zh;
             }
         }
         Lang::En
@@ -72,12 +72,14 @@ fn tr_en(key: &str) -> &'static str {
         // app
         "app.logged_in" => "logged in",
         "app.anonymous" => "anonymous",
+        "app.data_saver" => "SAVER",
+        "app.no_audio" => "NO AUDIO",
         "app.email_password_required" => "Email and password required",
         "app.no_captcha_key" => "No captcha key",
 
         // help
         "help.title" => "Key Bindings",
-        "help.close" => "j/k scroll  \u{00b7}  q / ? / Esc to close",
+        "help.close" => "j/k scroll  \u{00b7}  e export  \u{00b7}  q / ? / Esc to close",
         "help.section.global" => "Global",
         "help.section.navigation" => "Navigation",
         "help.section.search" => "Search",
@@ -85,19 +87,77 @@ fn tr_en(key: &str) -> &'static str {
         "help.play_pause" => "Play / Pause",
         "help.next_prev" => "Next / Prev track",
         "help.volume" => "Volume up / down",
+        "help.mute" => "Mute / unmute",
         "help.seek" => "Seek \u{00b1}5s",
+        "help.seek_fine" => "Seek \u{00b1}1s",
+        "help.seek_start_end" => "Seek to start / 5s before end",
+        "help.goto_queue" => "Jump to queue",
+        "help.mini_player" => "Toggle mini player",
+        "help.back" => "Close overlay / go back",
+        "help.clear_search" => "Clear search and go back",
         "help.play_mode" => "Cycle play mode",
         "help.player_view" => "Toggle player view",
+        "help.follow_playback" => "Player view: follow playing / browsed song",
+        "help.queue_order" => "Shuffle: queue in play / queue order",
+        "help.sort_queue" => "Sort queue: title / artist / length",
+        "help.undo_queue" => "Undo last queue change",
+        "help.hide_song" => "Hide song from feeds",
+        "help.hidden" => "Hidden songs",
+        "help.share_moment" => "Copy now-playing moment",
+        "help.copy_ids" => "Copy song id / display_id",
         "help.search" => "Search",
         "help.help" => "This help",
         "help.logs" => "Show logs",
+        "help.stats" => "Listening stats",
+        "help.pin" => "Pin / unpin tag, playlist, user",
+        "help.favorite_tag" => "Favorite / unfavorite tag",
+        "help.next_favorite_tag" => "Jump to next favorite tag",
+        "help.pinned" => "Pinned list",
+        "help.song_info" => "Full song info",
+        // stats
+        "stats.title" => "Listening Stats",
+        "stats.session" => "Session",
+        "stats.all_time" => "All time",
+        "stats.plays" => "Plays",
+        "stats.completed" => "Completed",
+        "stats.listened" => "Listened",
+        "stats.close" => "q / S / Esc to close",
+        "confirm.hint" => "y / Enter confirm · n / Esc cancel",
+        "info.title" => "Song Info",
+        "info.close" => "j/k scroll  \u{00b7}  1-9 open link  \u{00b7}  q / I / Esc to close",
+        "pinned.title" => "Pinned",
+        "pinned.empty" => "Nothing pinned yet (* to pin)",
+        "pinned.hint" => "Enter jump  \u{00b7}  d unpin  \u{00b7}  q / P / Esc",
+        "hidden.title" => "Hidden Songs",
+        "hidden.empty" => "No hidden songs (X to hide)",
+        "hidden.hint" => "d unhide  \u{00b7}  c clear all  \u{00b7}  q / H / Esc",
+        "info.section.stats" => "Stats",
+        "info.section.ids" => "IDs",
+        "info.section.tags" => "Tags",
+        "info.section.description" => "Description",
+        "info.duration" => "Duration",
+        "info.plays" => "Plays",
+        "info.likes" => "Likes",
+        "info.created" => "Uploaded",
+        "info.explicit" => "Explicit",
+        "info.yes" => "yes",
+        "info.gain" => "Gain",
+        "info.uploader" => "Uploader",
+        "confirm.quit.title" => "Quit",
+        "confirm.quit.message" => "Quit tokaitui?",
         "help.logout" => "Logout",
         "help.down_up" => "Down / Up",
         "help.drill_in" => "Drill in",
         "help.drill_out" => "Drill out",
         "help.top_bottom" => "Top / Bottom",
         "help.add_queue" => "Add to queue",
+        "help.add_list_queue" => "Add whole list to queue",
+        "help.play_from_here" => "Play list from selection to end",
+        "help.retry_load" => "Retry failed list",
         "help.remove_queue" => "Remove from queue",
+        "help.queue_top_bottom" => "Move to queue top / bottom",
+        "help.detail_scroll" => "Scroll details (player view)",
+        "help.goto_artist" => "Go to artist page (repeat to cycle)",
         "help.open_link" => "Open external link",
         "help.add_playlist" => "Add to playlist",
         "help.switch_type" => "Switch type",
@@ -109,10 +169,14 @@ fn tr_en(key: &str) -> &'static str {
         // logs
         "logs.title" => "Logs",
         "logs.empty" => "No logs yet",
-        "logs.hint" => "j/k scroll  \u{00b7}  h/l pan  \u{00b7}  Esc/! close",
+        "logs.hint" => "j/k scroll  \u{00b7}  h/l pan  \u{00b7}  r debug report  \u{00b7}  Esc/! close",
 
         // player
         "player.no_song" => "No song playing",
+        "player.starting" => "Decoding…",
+        "player.next" => "Next:",
+        "player.following" => "Following playback",
+        "player.browsing" => "Browsing",
         "player.no_lyrics" => "No lyrics",
 
         // login
@@ -121,24 +185,79 @@ fn tr_en(key: &str) -> &'static str {
         "login.password" => "Password",
         "login.hint_login" => "Login",
         "login.hint_quit" => "Quit",
+        "login.hint_back" => "Back",
+        "login.hint_anonymous" => "Browse without login",
         "login.generating_captcha" => "Generating captcha...",
         "login.captcha_opened" => "Captcha opened in browser",
+        "login.browser_failed" => "Couldn't open a browser; URL copied, open it below",
+        "login.hint_retry" => "Retry",
+        "login.auto_retry" => "Network error, retrying automatically in",
         "login.continue_captcha" => "Continue after completing captcha",
         "login.logging_in" => "Logging in...",
 
         // queue
         "queue.empty" => "Queue is empty",
-        "queue.hint" => "d remove \u{00b7} Enter play",
+        "queue.hint" => "d remove \u{00b7} T/B top/bottom \u{00b7} Enter play",
+
+        // toast
+        "toast.queue_added" => "Added to queue",
+        "toast.queue_order_shuffle" => "Queue shown in shuffle play order",
+        "toast.queue_order_sequential" => "Queue shown in queue order",
+        "toast.queue_order_not_shuffle" => "Queue order view only applies in shuffle mode",
+        "toast.queue_move_shuffle_view" => "Switch to queue order (z) to move items",
+        "toast.queue_already_added" => "Just added, skipped",
+        "toast.queue_list_added" => "Added list to queue",
+        "toast.pick_link" => "Press a number to pick a link",
+        "toast.queue_list_skipped" => "already queued:",
+        "toast.artist" => "Artist",
+        "toast.not_logged_in" => "Not logged in",
+        "toast.testing_connection" => "Testing connection...",
+        "toast.connection_ok" => "Connection OK",
+        "toast.connection_failed" => "Connection failed, see logs",
+        "toast.share_copied" => "Share text copied (also in logs)",
+        "toast.ids_copied" => "Song IDs copied (also in logs)",
+        "toast.queue_sorted" => "Queue sorted by",
+        "toast.queue_undone" => "Queue change undone",
+        "toast.anonymous_mode" => "Browsing anonymously: playlists and history need login (L)",
+        "toast.login_required" => "Log in to see this (L)",
+        "toast.queue_undo_empty" => "Nothing to undo",
+        "queue.sort.title" => "title",
+        "queue.sort.artist" => "artist",
+        "queue.sort.duration" => "duration",
+        "toast.pinned" => "Pinned:",
+        "toast.unpinned" => "Unpinned:",
+        "toast.song_hidden" => "Hidden:",
+        "toast.hidden_cleared" => "Hidden list cleared",
+        "toast.cheatsheet_saved" => "Key bindings exported (path in logs)",
+        "toast.duration_unknown" => "Track length unknown, cannot seek to the end",
+        "toast.pin_unsupported" => "Only tags, playlists and users can be pinned",
+        "toast.tag_favorited" => "Favorite tag:",
+        "toast.tag_unfavorited" => "Removed favorite tag:",
+        "toast.favorite_tag_unsupported" => "Select a tag in Categories to favorite it",
+        "toast.no_favorite_tags" => "No favorite tags yet — press t on a tag",
+        "toast.engine_dead" => "Audio engine stopped, restart it in Settings",
+        "toast.region_locked" => "This track isn't available in your region",
+        "toast.access_denied" => "Access to this track was denied",
+        "toast.no_audio_device" => "No audio output device, browsing only (restart audio engine in Settings to retry)",
+        "toast.engine_restarted" => "Audio engine restarted, Space to resume",
+        "toast.engine_restart_failed" => "Failed to restart audio engine, see logs",
 
         // miller
         "miller.no_songs" => "No songs",
         "miller.no_playlists" => "No playlists",
         "miller.loading" => "Loading...",
+        "miller.load_failed" => "Failed to load — press r to retry",
+        "miller.login_required" => "Log in to see this — press L",
+        "error.rate_limited" => "Too many requests — wait a moment, then try again",
+        "error.unauthorized" => "Session expired — sign in again (Settings → Re-login)",
+        "error.not_found" => "Not found — it may have been deleted or made private",
+        "error.region_blocked" => "Not available in your region",
         "miller.origin" => "Original",
         "miller.release_date" => "Released",
         "miller.crew" => "Credits",
+        "miller.artists" => "Artists",
         "miller.links" => "Links",
-        "miller.links_hint" => "o to open",
+        "miller.links_hint" => "o + number to open",
 
         // navigation
         "nav.root" => "Root",
@@ -152,6 +271,7 @@ fn tr_en(key: &str) -> &'static str {
         "nav.categories" => "Categories",
         "nav.playlists" => "Playlists",
         "nav.favorites" => "Favorites",
+        "nav.my_uploads" => "My Uploads",
         "nav.history" => "History",
         "nav.detail" => "Detail",
         "nav.tags" => "Tags",
@@ -165,6 +285,8 @@ fn tr_en(key: &str) -> &'static str {
         "search.user" => "user",
         "search.playlist" => "playlist",
         "search.no_results" => "No results",
+        "search.recent" => "Recent searches  (↑/↓ select, Enter search)",
+        "search.placeholder" => "press / to search",
         "search.songs_count" => "Songs",
         "sort.relevance" => "relevance",
         "sort.newest" => "newest",
@@ -179,14 +301,75 @@ fn tr_en(key: &str) -> &'static str {
         "settings.sequential" => "Sequential",
         "settings.shuffle" => "Shuffle",
         "settings.repeat_one" => "Repeat One",
+        "settings.repeat_all" => "Repeat All",
         "settings.hint" => "Enter/l to change \u{00b7} h/\u{2190} go back",
         "settings.desc.language" => "Interface display language",
-        "settings.desc.play_mode" => "Playback order when a track finishes: sequential, shuffle, or repeat one",
+        "settings.desc.play_mode" => "Playback order when a track finishes: sequential, repeat all, shuffle, or repeat one",
         "settings.desc.replay_gain" => "Normalize volume across tracks to reduce loudness differences",
         "settings.lang.en.desc" => "Full English interface",
         "settings.lang.zh.desc" => "Simplified Chinese interface",
-        "settings.cover_scale" => "Cover Scale",
-        "settings.desc.cover_scale" => "Cover image scale in the browser preview (20%-200%)",
+        "settings.preview_cover_scale" => "Preview Cover Scale",
+        "settings.desc.preview_cover_scale" => "Cover image scale in the browser preview (20%-200%)",
+        "settings.player_cover_scale" => "Player Cover Scale",
+        "settings.desc.player_cover_scale" => "Cover image scale in the expanded player view (20%-200%, capped by the available space)",
+        "settings.enter_action" => "Enter on Song",
+        "settings.desc.enter_action" => "What Enter does on a song in a list",
+        "settings.enter.play_list" => "Play list",
+        "settings.enter.play_one" => "Play one",
+        "settings.enter.add_and_play" => "Add & play",
+        "settings.enter.play_list.desc" => "Replace the queue with the whole list and play",
+        "settings.enter.play_one.desc" => "Play only this song, keep the queue untouched",
+        "settings.enter.add_and_play.desc" => "Append to the queue and jump to it",
+        "settings.credits_roll" => "Credits Roll",
+        "settings.test_connection" => "Test Connection",
+        "settings.desc.test_connection" => "Make an authenticated request to check the network and login status",
+        "settings.relogin" => "Re-login",
+        "settings.desc.relogin" => "Log in again without clearing the queue or caches (Esc to go back)",
+        "settings.restart_engine" => "Restart Audio Engine",
+        "settings.desc.restart_engine" => "Recreate the audio engine after the output device was lost or playback stopped responding",
+        "settings.desc.credits_roll" => "Slowly auto-scroll song details in the player view; J/K pauses it",
+        "settings.prefetch_queue" => "Prefetch Queue",
+        "settings.desc.prefetch_queue" => "After startup, fetch details of the tracks around the current queue item in the background",
+        "settings.autoplay_on_start" => "Autoplay on Start",
+        "settings.desc.autoplay_on_start" => "What to do with the restored queue after startup or login",
+        "settings.autoplay.off" => "Off",
+        "settings.autoplay.resume" => "Resume",
+        "settings.autoplay.off.desc" => "Restore the player bar and position, stay paused",
+        "settings.autoplay.resume.desc" => "Continue playing from the saved position",
+        "settings.data_saver" => "Data Saver",
+        "settings.desc.data_saver" => "For metered connections: skip cover downloads, queue prefetching and play-history reporting",
+        "settings.rich_preview" => "Rich List Preview",
+        "settings.desc.rich_preview" => "When hovering a list (category, tag, playlist), preview its cover, song count and total length instead of only titles",
+        "settings.confirm_quit" => "Confirm Quit",
+        "settings.desc.confirm_quit" => "Ask before quitting with q (Ctrl+C always quits immediately)",
+        "settings.replace_queue_on_play" => "Replace Queue on Play",
+        "settings.desc.replace_queue_on_play" => "On: \"Play list\" replaces the queue. Off: the list is inserted after the current song and the queue is kept",
+        "settings.repeat_all_from" => "Repeat All From",
+        "settings.desc.repeat_all_from" => "Where Repeat All starts over after the last track",
+        "settings.repeat_from.queue_start" => "Queue start",
+        "settings.repeat_from.queue_start.desc" => "Loop the whole queue",
+        "settings.repeat_from.play_start" => "Play start",
+        "settings.repeat_from.play_start.desc" => "Loop from the song you last started playing, skipping the songs before it",
+        "settings.marquee" => "Scroll Long Titles",
+        "settings.desc.marquee" => "Scroll the selected row's title when it does not fit. Off: truncate it like every other row",
+        "settings.bar_thumbnail" => "Player Bar Cover",
+        "settings.desc.bar_thumbnail" => "Show a small cover of the playing song at the left of the player bar (Kitty graphics only)",
+        "settings.layout" => "Layout",
+        "settings.desc.layout" => "How the browser is laid out; switches immediately",
+        "settings.layout.classic" => "Classic",
+        "settings.layout.classic.desc" => "Path in the header, search bar only while searching",
+        "settings.layout.modern" => "Modern",
+        "settings.layout.modern.desc" => "Path on its own row, search bar always shown above the columns",
+        "settings.cover_policy" => "Cover Downloads",
+        "settings.desc.cover_policy" => "Which covers are downloaded (Data Saver always downloads none)",
+        "settings.cover_policy.off" => "Off",
+        "settings.cover_policy.off.desc" => "No cover traffic at all; placeholders everywhere",
+        "settings.cover_policy.playing_only" => "Playing only",
+        "settings.cover_policy.playing_only.desc" => "One cover per track played, shown in the player view; browsing shows placeholders",
+        "settings.cover_policy.browse" => "Browse + playing",
+        "settings.cover_policy.browse.desc" => "Covers of the selected item while browsing and of the playing track",
+        "settings.cover_policy.prefetch" => "Prefetch",
+        "settings.cover_policy.prefetch.desc" => "Also downloads the playing and next track's covers up front; most traffic, least waiting",
 
         _ => "???",
     }
@@ -197,12 +380,14 @@ fn tr_zh(key: &str) -> &'static str {
         // app
         "app.logged_in" => "已登录",
         "app.anonymous" => "匿名",
+        "app.data_saver" => "省流",
+        "app.no_audio" => "无音频",
         "app.email_password_required" => "请输入邮箱和密码",
         "app.no_captcha_key" => "验证码密钥缺失",
 
         // help
         "help.title" => "快捷键",
-        "help.close" => "j/k 滚动  \u{00b7}  q / ? / Esc 关闭",
+        "help.close" => "j/k 滚动  \u{00b7}  e 导出  \u{00b7}  q / ? / Esc 关闭",
         "help.section.global" => "全局",
         "help.section.navigation" => "导航",
         "help.section.search" => "搜索",
@@ -210,19 +395,77 @@ fn tr_zh(key: &str) -> &'static str {
         "help.play_pause" => "播放 / 暂停",
         "help.next_prev" => "下一首 / 上一首",
         "help.volume" => "音量 +/-",
+        "help.mute" => "静音/取消静音",
         "help.seek" => "快进/快退 \u{00b1}5s",
+        "help.seek_fine" => "快进/快退 \u{00b1}1s",
+        "help.seek_start_end" => "跳到开头 / 结尾前 5 秒",
+        "help.goto_queue" => "跳转到播放队列",
+        "help.mini_player" => "切换迷你播放器",
+        "help.back" => "关闭浮层/返回上一级",
+        "help.clear_search" => "清除搜索并返回",
         "help.play_mode" => "切换播放模式",
         "help.player_view" => "切换播放器视图",
+        "help.follow_playback" => "播放器视图：跟随播放中/浏览中歌曲",
+        "help.queue_order" => "随机模式：队列按播放/原始顺序显示",
+        "help.sort_queue" => "队列排序：标题/艺人/时长",
+        "help.undo_queue" => "撤销上一次队列改动",
+        "help.hide_song" => "在推荐中隐藏歌曲",
+        "help.hidden" => "已隐藏的歌曲",
+        "help.share_moment" => "复制当前播放时刻",
+        "help.copy_ids" => "复制歌曲 id / display_id",
         "help.search" => "搜索",
         "help.help" => "帮助",
         "help.logs" => "显示日志",
+        "help.stats" => "收听统计",
+        "help.pin" => "固定/取消固定标签、歌单、用户",
+        "help.favorite_tag" => "收藏/取消收藏标签",
+        "help.next_favorite_tag" => "跳到下一个收藏标签",
+        "help.pinned" => "固定列表",
+        "help.song_info" => "歌曲完整信息",
+        // stats
+        "stats.title" => "收听统计",
+        "stats.session" => "本次",
+        "stats.all_time" => "累计",
+        "stats.plays" => "播放次数",
+        "stats.completed" => "完整播放",
+        "stats.listened" => "收听时长",
+        "stats.close" => "q / S / Esc 关闭",
+        "confirm.hint" => "y / Enter 确认 · n / Esc 取消",
+        "info.title" => "歌曲信息",
+        "info.close" => "j/k 滚动  \u{00b7}  1-9 打开链接  \u{00b7}  q / I / Esc 关闭",
+        "pinned.title" => "固定列表",
+        "pinned.empty" => "尚未固定任何节点（按 * 固定）",
+        "pinned.hint" => "Enter 跳转  \u{00b7}  d 取消固定  \u{00b7}  q / P / Esc",
+        "hidden.title" => "已隐藏的歌曲",
+        "hidden.empty" => "没有隐藏的歌曲（按 X 隐藏）",
+        "hidden.hint" => "d 恢复  \u{00b7}  c 全部清空  \u{00b7}  q / H / Esc",
+        "info.section.stats" => "统计",
+        "info.section.ids" => "ID",
+        "info.section.tags" => "标签",
+        "info.section.description" => "简介",
+        "info.duration" => "时长",
+        "info.plays" => "播放",
+        "info.likes" => "喜欢",
+        "info.created" => "上传时间",
+        "info.explicit" => "含露骨内容",
+        "info.yes" => "是",
+        "info.gain" => "响度增益",
+        "info.uploader" => "上传者",
+        "confirm.quit.title" => "退出",
+        "confirm.quit.message" => "确定退出 tokaitui？",
         "help.logout" => "退出登录",
         "help.down_up" => "下 / 上",
         "help.drill_in" => "进入",
         "help.drill_out" => "返回",
         "help.top_bottom" => "顶部 / 底部",
         "help.add_queue" => "加入队列",
+        "help.add_list_queue" => "整个列表加入队列",
+        "help.play_from_here" => "从选中项播放到列表末尾",
+        "help.retry_load" => "重新加载失败的列表",
         "help.remove_queue" => "从队列移除",
+        "help.queue_top_bottom" => "移到队首 / 队尾",
+        "help.detail_scroll" => "滚动详情（播放器视图）",
+        "help.goto_artist" => "前往艺人主页（再按切换下一位）",
         "help.open_link" => "打开外部链接",
         "help.add_playlist" => "加入歌单",
         "help.switch_type" => "切换类型",
@@ -234,10 +477,14 @@ fn tr_zh(key: &str) -> &'static str {
         // logs
         "logs.title" => "日志",
         "logs.empty" => "暂无日志",
-        "logs.hint" => "j/k 滚动  \u{00b7}  h/l 左右滚动  \u{00b7}  Esc/! 关闭",
+        "logs.hint" => "j/k 滚动  \u{00b7}  h/l 左右滚动  \u{00b7}  r 调试报告  \u{00b7}  Esc/! 关闭",
 
         // player
         "player.no_song" => "未在播放",
+        "player.starting" => "解码中…",
+        "player.next" => "下一首",
+        "player.following" => "跟随播放",
+        "player.browsing" => "浏览中",
         "player.no_lyrics" => "无歌词",
 
         // login
@@ -246,24 +493,79 @@ fn tr_zh(key: &str) -> &'static str {
         "login.password" => "密码",
         "login.hint_login" => "登录",
         "login.hint_quit" => "退出",
+        "login.hint_back" => "返回",
+        "login.hint_anonymous" => "不登录先逛逛",
         "login.generating_captcha" => "正在生成验证码...",
         "login.captcha_opened" => "验证码已在浏览器中打开",
+        "login.browser_failed" => "无法打开浏览器，已复制下方地址，请手动打开",
+        "login.hint_retry" => "重试",
+        "login.auto_retry" => "网络错误，自动重试倒计时",
         "login.continue_captcha" => "完成验证码后按 Enter 继续",
         "login.logging_in" => "正在登录...",
 
         // queue
         "queue.empty" => "队列为空",
-        "queue.hint" => "d 删除 \u{00b7} Enter 播放",
+        "queue.hint" => "d 删除 \u{00b7} T/B 队首/队尾 \u{00b7} Enter 播放",
+
+        // toast
+        "toast.queue_added" => "已加入队列",
+        "toast.queue_order_shuffle" => "队列按随机播放顺序显示",
+        "toast.queue_order_sequential" => "队列按原始顺序显示",
+        "toast.queue_order_not_shuffle" => "播放顺序视图仅在随机模式下生效",
+        "toast.queue_move_shuffle_view" => "请先按 z 切换到原始顺序再移动",
+        "toast.queue_already_added" => "刚刚已添加，已跳过",
+        "toast.queue_list_added" => "已将列表加入队列",
+        "toast.pick_link" => "按数字键选择链接",
+        "toast.queue_list_skipped" => "已在队列中跳过",
+        "toast.artist" => "艺人",
+        "toast.not_logged_in" => "尚未登录",
+        "toast.testing_connection" => "正在测试连接...",
+        "toast.connection_ok" => "连接正常",
+        "toast.connection_failed" => "连接失败，详见日志",
+        "toast.share_copied" => "已复制分享文本（日志中也有）",
+        "toast.ids_copied" => "已复制歌曲 ID（日志中也有）",
+        "toast.queue_sorted" => "队列已排序：",
+        "toast.queue_undone" => "已撤销队列改动",
+        "toast.anonymous_mode" => "匿名浏览中：歌单与播放历史需要登录（按 L）",
+        "toast.login_required" => "登录后才能查看（按 L）",
+        "toast.queue_undo_empty" => "没有可撤销的队列改动",
+        "queue.sort.title" => "按标题",
+        "queue.sort.artist" => "按艺人",
+        "queue.sort.duration" => "按时长",
+        "toast.pinned" => "已固定：",
+        "toast.unpinned" => "已取消固定：",
+        "toast.song_hidden" => "已隐藏：",
+        "toast.hidden_cleared" => "已清空隐藏列表",
+        "toast.cheatsheet_saved" => "快捷键已导出（路径见日志）",
+        "toast.duration_unknown" => "歌曲时长未知，无法跳到结尾",
+        "toast.pin_unsupported" => "只能固定标签、歌单和用户",
+        "toast.tag_favorited" => "已收藏标签：",
+        "toast.tag_unfavorited" => "已取消收藏标签：",
+        "toast.favorite_tag_unsupported" => "请在分类中选中标签后收藏",
+        "toast.no_favorite_tags" => "还没有收藏的标签，在标签上按 t 收藏",
+        "toast.engine_dead" => "音频引擎已停止，请在设置中重启",
+        "toast.region_locked" => "该曲目在你所在的地区不可用",
+        "toast.access_denied" => "无权访问该曲目",
+        "toast.no_audio_device" => "没有音频输出设备，仅可浏览（可在设置中重启音频引擎重试）",
+        "toast.engine_restarted" => "音频引擎已重启，按空格继续播放",
+        "toast.engine_restart_failed" => "重启音频引擎失败，详见日志",
 
         // miller
         "miller.no_songs" => "暂无歌曲",
         "miller.no_playlists" => "暂无歌单",
         "miller.loading" => "加载中...",
+        "miller.load_failed" => "加载失败，按 r 重试",
+        "miller.login_required" => "登录后才能查看，按 L 登录",
+        "error.rate_limited" => "请求过于频繁，请稍候再试",
+        "error.unauthorized" => "登录已失效，请重新登录（设置 → 重新登录）",
+        "error.not_found" => "内容不存在，可能已被删除或设为私密",
+        "error.region_blocked" => "所在地区不可用",
         "miller.origin" => "原作",
         "miller.release_date" => "发行日期",
         "miller.crew" => "创作团队",
+        "miller.artists" => "艺人",
         "miller.links" => "外部链接",
-        "miller.links_hint" => "按 o 打开",
+        "miller.links_hint" => "按 o 加编号打开",
 
         // navigation
         "nav.root" => "根",
@@ -277,6 +579,7 @@ fn tr_zh(key: &str) -> &'static str {
         "nav.categories" => "分类",
         "nav.playlists" => "歌单",
         "nav.favorites" => "收藏",
+        "nav.my_uploads" => "我的投稿",
         "nav.history" => "历史",
         "nav.detail" => "详情",
         "nav.tags" => "标签",
@@ -290,6 +593,8 @@ fn tr_zh(key: &str) -> &'static str {
         "search.user" => "用户",
         "search.playlist" => "歌单",
         "search.no_results" => "无结果",
+        "search.recent" => "最近搜索（↑/↓ 选择，Enter 搜索）",
+        "search.placeholder" => "按 / 搜索",
         "search.songs_count" => "歌曲数",
         "sort.relevance" => "相关度",
         "sort.newest" => "最新",
@@ -304,14 +609,75 @@ fn tr_zh(key: &str) -> &'static str {
         "settings.sequential" => "顺序播放",
         "settings.shuffle" => "随机播放",
         "settings.repeat_one" => "单曲循环",
+        "settings.repeat_all" => "列表循环",
         "settings.hint" => "Enter/l 切换 \u{00b7} h/\u{2190} 返回",
         "settings.desc.language" => "界面显示语言",
-        "settings.desc.play_mode" => "曲目结束后的播放顺序：顺序、随机或单曲循环",
+        "settings.desc.play_mode" => "曲目结束后的播放顺序：顺序、列表循环、随机或单曲循环",
         "settings.desc.replay_gain" => "均衡各曲目音量，减少响度差异",
         "settings.lang.en.desc" => "英文界面",
         "settings.lang.zh.desc" => "简体中文界面",
-        "settings.cover_scale" => "封面缩放",
-        "settings.desc.cover_scale" => "浏览视图中预览封面图的缩放比例 (20%-200%)",
+        "settings.preview_cover_scale" => "预览封面缩放",
+        "settings.desc.preview_cover_scale" => "浏览视图中预览封面图的缩放比例 (20%-200%)",
+        "settings.player_cover_scale" => "播放器封面缩放",
+        "settings.desc.player_cover_scale" => "展开播放器视图中封面图的缩放比例 (20%-200%，不超过可用空间)",
+        "settings.enter_action" => "回车播放",
+        "settings.desc.enter_action" => "在歌曲列表中按 Enter 的行为",
+        "settings.enter.play_list" => "播放整个列表",
+        "settings.enter.play_one" => "仅播放此曲",
+        "settings.enter.add_and_play" => "加入并播放",
+        "settings.enter.play_list.desc" => "用整个列表替换队列并播放",
+        "settings.enter.play_one.desc" => "只播放这首歌，不改动队列",
+        "settings.enter.add_and_play.desc" => "追加到队列末尾并跳转播放",
+        "settings.credits_roll" => "自动滚动详情",
+        "settings.test_connection" => "测试连接",
+        "settings.desc.test_connection" => "发起一次需要认证的请求，检查网络与登录状态",
+        "settings.relogin" => "重新登录",
+        "settings.desc.relogin" => "重新登录，不清空队列与缓存（按 Esc 返回）",
+        "settings.restart_engine" => "重启音频引擎",
+        "settings.desc.restart_engine" => "音频设备丢失或播放无响应时，重新创建音频引擎",
+        "settings.desc.credits_roll" => "在播放器视图中缓慢自动滚动歌曲详情，按 J/K 手动滚动时暂停",
+        "settings.prefetch_queue" => "预取队列详情",
+        "settings.desc.prefetch_queue" => "启动后在后台获取当前队列项前后几首歌曲的详情，减少切歌等待",
+        "settings.autoplay_on_start" => "启动时自动播放",
+        "settings.desc.autoplay_on_start" => "启动或登录后如何处理恢复的播放队列",
+        "settings.autoplay.off" => "关",
+        "settings.autoplay.resume" => "继续播放",
+        "settings.autoplay.off.desc" => "恢复播放栏与进度，保持暂停",
+        "settings.autoplay.resume.desc" => "从上次保存的位置继续播放",
+        "settings.data_saver" => "省流模式",
+        "settings.desc.data_saver" => "适用于按流量计费的网络：不下载封面、不预取队列详情、不上报播放历史",
+        "settings.rich_preview" => "列表详细预览",
+        "settings.desc.rich_preview" => "选中列表（分类、标签、歌单）时，预览栏显示封面、歌曲数与总时长，而不只是标题",
+        "settings.confirm_quit" => "退出确认",
+        "settings.desc.confirm_quit" => "按 q 退出前先确认（Ctrl+C 始终直接退出）",
+        "settings.replace_queue_on_play" => "播放时替换队列",
+        "settings.desc.replace_queue_on_play" => "开启：「播放整个列表」会替换队列；关闭：列表插入到当前歌曲之后，保留原队列",
+        "settings.repeat_all_from" => "列表循环起点",
+        "settings.desc.repeat_all_from" => "列表循环播完最后一首后从哪里重新开始",
+        "settings.repeat_from.queue_start" => "队列开头",
+        "settings.repeat_from.queue_start.desc" => "循环整个队列",
+        "settings.repeat_from.play_start" => "开始播放处",
+        "settings.repeat_from.play_start.desc" => "从最近一次开始播放的歌曲处循环，跳过它之前的歌曲",
+        "settings.marquee" => "滚动显示长标题",
+        "settings.desc.marquee" => "选中行标题过长时滚动显示；关闭后与其他行一样截断",
+        "settings.bar_thumbnail" => "播放栏小封面",
+        "settings.desc.bar_thumbnail" => "在播放栏左侧显示正在播放歌曲的小封面（仅 Kitty 图形协议）",
+        "settings.layout" => "布局",
+        "settings.desc.layout" => "浏览区的布局，切换后立即生效",
+        "settings.layout.classic" => "经典",
+        "settings.layout.classic.desc" => "路径显示在顶栏，搜索栏只在搜索时出现",
+        "settings.layout.modern" => "新版",
+        "settings.layout.modern.desc" => "路径独占一行，搜索栏常驻在分栏上方",
+        "settings.cover_policy" => "封面下载",
+        "settings.desc.cover_policy" => "下载哪些封面（省流模式下一律不下载）",
+        "settings.cover_policy.off" => "关闭",
+        "settings.cover_policy.off.desc" => "完全不下载封面，全部显示占位",
+        "settings.cover_policy.playing_only" => "仅播放中",
+        "settings.cover_policy.playing_only.desc" => "每播放一首只下载一张封面，在播放器视图中显示；浏览时显示占位",
+        "settings.cover_policy.browse" => "浏览 + 播放中",
+        "settings.cover_policy.browse.desc" => "浏览时下载选中项的封面，并显示播放中歌曲的封面",
+        "settings.cover_policy.prefetch" => "预取",
+        "settings.cover_policy.prefetch.desc" => "另外提前下载当前与下一首的封面；流量最多、等待最少",
 
         _ => tr_en(key),
     }
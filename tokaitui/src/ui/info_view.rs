@@ -0,0 +1,156 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Paragraph, Wrap},
+};
+
+use crate::model::song::PublicSongDetail;
+use super::theme::Theme;
+
+/// 渲染歌曲信息浮层：完整列出简介、创作团队、原作、外部链接（带编号）、标签、统计与各类 ID
+pub fn render(frame: &mut Frame, area: Rect, song: &PublicSongDetail, scroll: u16) {
+    let lines = info_lines(song);
+    // 面板外高度 = 2 (borders) + content_lines + 1 (hint)，超出时由 overlay_panel 裁剪并滚动
+    let panel_h = lines.len() as u16 + 3;
+
+    let (content_area, hint_area) = super::util::overlay_panel(
+        frame, area, t!("info.title"),
+        super::constants::INFO_PANEL_WIDTH, panel_h,
+    );
+
+    let para = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .scroll((scroll, 0));
+    frame.render_widget(para, content_area);
+
+    let hint = Paragraph::new(Span::styled(
+        format!("  {}", t!("info.close")),
+        Theme::secondary(),
+    ));
+    frame.render_widget(hint, hint_area);
+}
+
+fn section(title: &str) -> Line<'static> {
+    Line::from(Span::styled(
+        format!("  {title}"),
+        Style::default().add_modifier(Modifier::BOLD),
+    ))
+}
+
+fn field(label: &str, value: String) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(format!("    {label:<12}"), Theme::secondary()),
+        Span::raw(value),
+    ])
+}
+
+fn info_lines(song: &PublicSongDetail) -> Vec<Line<'static>> {
+    let mut lines: Vec<Line<'static>> = vec![Line::from("")];
+
+    lines.push(Line::from(Span::styled(
+        format!("  {}", song.title),
+        Style::default().add_modifier(Modifier::BOLD),
+    )));
+    if !song.subtitle.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!("  {}", song.subtitle),
+            Theme::secondary(),
+        )));
+    }
+    lines.push(Line::from(""));
+
+    // 统计
+    lines.push(section(t!("info.section.stats")));
+    lines.push(field(t!("info.duration"), song.format_duration()));
+    lines.push(field(t!("info.plays"), song.play_count.to_string()));
+    lines.push(field(t!("info.likes"), song.like_count.to_string()));
+    lines.push(field(
+        t!("miller.release_date"),
+        song.release_time.format("%Y-%m-%d").to_string(),
+    ));
+    lines.push(field(
+        t!("info.created"),
+        song.create_time.format("%Y-%m-%d %H:%M").to_string(),
+    ));
+    if song.explicit == Some(true) {
+        lines.push(field(t!("info.explicit"), t!("info.yes").to_string()));
+    }
+    if let Some(gain) = song.gain {
+        lines.push(field(t!("info.gain"), format!("{gain:+.2} dB")));
+    }
+    lines.push(Line::from(""));
+
+    // ID
+    lines.push(section(t!("info.section.ids")));
+    lines.push(field("id", song.id.to_string()));
+    lines.push(field("display_id", song.display_id.clone()));
+    lines.push(field(
+        t!("info.uploader"),
+        format!("{} (uid {})", song.uploader_name, song.uploader_uid),
+    ));
+    lines.push(Line::from(""));
+
+    // 标签
+    if !song.tags.is_empty() {
+        lines.push(section(t!("info.section.tags")));
+        let names: Vec<&str> = song.tags.iter().map(|t| t.name.as_str()).collect();
+        lines.push(Line::from(Span::raw(format!("    {}", names.join(" · ")))));
+        lines.push(Line::from(""));
+    }
+
+    // 创作团队
+    if !song.production_crew.is_empty() {
+        lines.push(section(t!("miller.crew")));
+        for member in &song.production_crew {
+            let name = member.person_name.as_deref().unwrap_or("?");
+            lines.push(field(&member.role, name.to_string()));
+        }
+        lines.push(Line::from(""));
+    }
+
+    // 原作信息
+    if !song.origin_infos.is_empty() {
+        lines.push(section(t!("miller.origin")));
+        for info in &song.origin_infos {
+            let title = info.title.as_deref().unwrap_or("?");
+            let mut text = match info.artist.as_deref() {
+                Some(artist) if !artist.is_empty() => format!("    {title} - {artist}"),
+                _ => format!("    {title}"),
+            };
+            if let Some(id) = &info.song_display_id {
+                text.push_str(&format!("  [{id}]"));
+            }
+            lines.push(Line::from(Span::raw(text)));
+            if let Some(url) = &info.url {
+                lines.push(Line::from(Span::styled(format!("      {url}"), Theme::secondary())));
+            }
+        }
+        lines.push(Line::from(""));
+    }
+
+    // 外部链接（编号从 1 开始）
+    if !song.external_links.is_empty() {
+        lines.push(section(t!("miller.links")));
+        for (i, link) in song.external_links.iter().enumerate() {
+            lines.push(Line::from(vec![
+                Span::styled(format!("    {}. ", i + 1), Theme::active()),
+                Span::styled(format!(" {} ", link.platform), Theme::link_badge()),
+                Span::styled(format!(" {}", link.url), Theme::secondary()),
+            ]));
+        }
+        lines.push(Line::from(""));
+    }
+
+    // 简介
+    if !song.description.is_empty() {
+        lines.push(section(t!("info.section.description")));
+        for line in song.description.lines() {
+            lines.push(Line::from(Span::raw(format!("    {line}"))));
+        }
+        lines.push(Line::from(""));
+    }
+
+    lines
+}
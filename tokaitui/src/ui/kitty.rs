@@ -1,14 +1,50 @@
-/// 检测终端是否支持 Kitty 图形协议
-pub fn is_supported() -> bool {
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// 是否需要用 tmux DCS 透传包装图形序列
+static TMUX_PASSTHROUGH: AtomicBool = AtomicBool::new(false);
+
+/// 返回判定支持 Kitty 图形协议所依据的环境变量（仅读环境变量，不查询终端，启动不会阻塞）
+pub fn detection_source() -> Option<&'static str> {
     if std::env::var("KITTY_WINDOW_ID").is_ok() {
-        return true;
+        return Some("KITTY_WINDOW_ID");
     }
-    if let Ok(val) = std::env::var("TERM_PROGRAM") {
-        if val == "ghostty" || val == "kitty" {
-            return true;
-        }
+    if let Ok(val) = std::env::var("TERM_PROGRAM")
+        && (val == "ghostty" || val == "kitty")
+    {
+        return Some("TERM_PROGRAM");
+    }
+    None
+}
+
+/// 判定是否启用 tmux 透传：`override_` 为配置中的强制开关，未设置时按 `TMUX` 环境变量检测
+pub fn detect_tmux_passthrough(override_: Option<bool>) -> bool {
+    override_.unwrap_or_else(|| std::env::var_os("TMUX").is_some())
+}
+
+pub fn set_tmux_passthrough(enabled: bool) {
+    TMUX_PASSTHROUGH.store(enabled, Ordering::Relaxed);
+}
+
+/// 查询 tmux 的 allow-passthrough 选项（tmux 3.3+ 需开启才会转发图形序列），查询失败返回 None
+pub fn tmux_allows_passthrough() -> Option<bool> {
+    let out = std::process::Command::new("tmux")
+        .args(["show", "-gv", "allow-passthrough"])
+        .output()
+        .ok()?;
+    if !out.status.success() {
+        return None;
+    }
+    let val = String::from_utf8_lossy(&out.stdout);
+    Some(matches!(val.trim(), "on" | "all"))
+}
+
+/// 按需包装一条 APC 序列：tmux 透传格式为 `ESC P tmux; <ESC 加倍的原序列> ESC \`
+fn wrap(seq: String) -> Vec<u8> {
+    if TMUX_PASSTHROUGH.load(Ordering::Relaxed) {
+        format!("\x1bPtmux;{}\x1b\\", seq.replace('\x1b', "\x1b\x1b")).into_bytes()
+    } else {
+        seq.into_bytes()
     }
-    false
 }
 
 /// 生成上传 raw RGB 图片的 APC 序列（分块 base64，f=24，a=T 纯上传，q=2 静默）
@@ -29,10 +65,10 @@ pub fn upload_rgb(id: u32, rgb: &[u8], w: u32, h: u32) -> Vec<u8> {
             let header = format!(
                 "\x1b_Ga=t,f=24,s={w},v={h},i={id},q=2,m={m};{chunk_str}\x1b\\"
             );
-            out.extend_from_slice(header.as_bytes());
+            out.extend_from_slice(&wrap(header));
         } else {
             let cont = format!("\x1b_Gm={m};{chunk_str}\x1b\\");
-            out.extend_from_slice(cont.as_bytes());
+            out.extend_from_slice(&wrap(cont));
         }
     }
     out
@@ -41,17 +77,34 @@ pub fn upload_rgb(id: u32, rgb: &[u8], w: u32, h: u32) -> Vec<u8> {
 /// 生成在当前光标位置放置图片的序列（需调用方先移动光标到目标位置）
 /// c = 列数, r = 行数（字符单元格数）
 pub fn place_at_cursor(id: u32, cols: u16, rows: u16) -> Vec<u8> {
-    format!("\x1b_Ga=p,i={id},c={cols},r={rows},q=2;\x1b\\").into_bytes()
+    wrap(format!("\x1b_Ga=p,i={id},c={cols},r={rows},q=2;\x1b\\"))
+}
+
+/// 背景封面的 image ID 标记位：调暗副本与原封面 ID 一一对应
+const BACKDROP_ID_BIT: u32 = 1 << 24;
+
+/// 封面对应的调暗背景副本 ID
+pub fn backdrop_id(id: u32) -> u32 {
+    id | BACKDROP_ID_BIT
+}
+
+pub fn is_backdrop(id: u32) -> bool {
+    id & BACKDROP_ID_BIT != 0
+}
+
+/// 与 `place_at_cursor` 相同，但 z=-1 放在文字下方，用作背景
+pub fn place_behind_text(id: u32, cols: u16, rows: u16) -> Vec<u8> {
+    wrap(format!("\x1b_Ga=p,i={id},c={cols},r={rows},z=-1,q=2;\x1b\\"))
 }
 
 /// 删除图片的所有 placement（d=i 小写：保留 image data，可再次 place）
 /// 用于帧间清理，避免 image data 被意外释放
 pub fn delete_placement(id: u32) -> Vec<u8> {
-    format!("\x1b_Ga=d,d=i,i={id},q=2;\x1b\\").into_bytes()
+    wrap(format!("\x1b_Ga=d,d=i,i={id},q=2;\x1b\\"))
 }
 
 /// 完全删除图片（d=I 大写：同时释放 image data）
 /// 用于内存淘汰（超过 10 张时）
 pub fn delete_image(id: u32) -> Vec<u8> {
-    format!("\x1b_Ga=d,d=I,i={id},q=2;\x1b\\").into_bytes()
+    wrap(format!("\x1b_Ga=d,d=I,i={id},q=2;\x1b\\"))
 }
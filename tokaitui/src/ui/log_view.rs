@@ -38,8 +38,8 @@ pub struct LogStore {
 
 impl LogStore {
     pub fn new() -> Self {
-        let file = dirs::cache_dir()
-            .map(|d| d.join("hachimi.log"))
+        let file = crate::config::paths::log_file()
+            .ok()
             .and_then(|path| {
                 OpenOptions::new()
                     .create(true)
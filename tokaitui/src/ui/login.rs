@@ -6,6 +6,8 @@ use ratatui::{
     widgets::Paragraph,
 };
 
+use std::time::Instant;
+
 use super::theme::Theme;
 
 const LOGO: &[&str] = &[
@@ -41,6 +43,16 @@ pub struct LoginState {
     pub error: Option<String>,
     pub step: LoginStep,
     pub captcha_key: Option<String>,
+    /// 上次生成验证码失败，Enter 提示改为「重试」
+    pub captcha_failed: bool,
+    /// 本轮已自动重试的次数
+    pub captcha_attempts: u32,
+    /// 网络错误后自动重试的时间
+    pub captcha_retry_at: Option<Instant>,
+    /// 浏览器打不开时展示给用户手动打开的验证码地址
+    pub captcha_url: Option<String>,
+    /// 从设置页重新登录时可按 Esc 返回
+    pub cancelable: bool,
 }
 
 impl LoginState {
@@ -54,6 +66,11 @@ impl LoginState {
             error: None,
             step: LoginStep::Input,
             captcha_key: None,
+            captcha_failed: false,
+            captcha_attempts: 0,
+            captcha_retry_at: None,
+            captcha_url: None,
+            cancelable: false,
         }
     }
 
@@ -64,6 +81,37 @@ impl LoginState {
         }
     }
 
+    /// 在光标处插入字符（光标为字符下标，不是字节下标）
+    pub fn insert_char(&mut self, c: char) {
+        let (text, cursor) = self.current_input();
+        let at = byte_offset(text, *cursor);
+        text.insert(at, c);
+        *cursor += 1;
+    }
+
+    /// 删除光标前一个字符
+    pub fn delete_before_cursor(&mut self) {
+        let (text, cursor) = self.current_input();
+        if *cursor == 0 {
+            return;
+        }
+        *cursor -= 1;
+        let at = byte_offset(text, *cursor);
+        text.remove(at);
+    }
+
+    pub fn move_cursor_left(&mut self) {
+        let (_, cursor) = self.current_input();
+        *cursor = cursor.saturating_sub(1);
+    }
+
+    pub fn move_cursor_right(&mut self) {
+        let (text, cursor) = self.current_input();
+        if *cursor < text.chars().count() {
+            *cursor += 1;
+        }
+    }
+
     pub fn toggle_field(&mut self) {
         self.focused_field = match self.focused_field {
             LoginField::Email => LoginField::Password,
@@ -79,6 +127,11 @@ impl LoginState {
     }
 }
 
+/// 字符下标对应的字节偏移，超出时返回末尾
+fn byte_offset(text: &str, char_idx: usize) -> usize {
+    text.char_indices().nth(char_idx).map_or(text.len(), |(i, _)| i)
+}
+
 /// 渲染登录表单（居中显示在主内容区）
 pub fn render(frame: &mut Frame, area: Rect, state: &LoginState) {
     let logo_height = LOGO.len() as u16;
@@ -184,13 +237,26 @@ pub fn render(frame: &mut Frame, area: Rect, state: &LoginState) {
     // 提示行（根据 step 不同显示不同内容）
     match state.step {
         LoginStep::Input => {
-            let hint = Line::from(vec![
+            let enter_hint = if state.captcha_failed { t!("login.hint_retry") } else { t!("login.hint_login") };
+            let mut spans = vec![
                 Span::styled("[Enter]", Theme::highlight()),
-                Span::raw(format!(" {}  ", t!("login.hint_login"))),
-                Span::styled("[q]", Theme::secondary()),
-                Span::raw(format!(" {}", t!("login.hint_quit"))),
-            ]);
+                Span::raw(format!(" {enter_hint}  ")),
+            ];
+            spans.push(Span::styled("[Esc]", Theme::secondary()));
+            let esc_hint = if state.cancelable { t!("login.hint_back") } else { t!("login.hint_anonymous") };
+            spans.push(Span::raw(format!(" {esc_hint}  ")));
+            spans.push(Span::styled("[q]", Theme::secondary()));
+            spans.push(Span::raw(format!(" {}", t!("login.hint_quit"))));
+            let hint = Line::from(spans);
             frame.render_widget(Paragraph::new(hint), rows[7]);
+            if let Some(at) = state.captcha_retry_at {
+                let secs = at.saturating_duration_since(Instant::now()).as_secs_f32().ceil() as u32;
+                let retry = Line::from(Span::styled(
+                    format!("{} {secs}s", t!("login.auto_retry")),
+                    Theme::secondary(),
+                ));
+                frame.render_widget(Paragraph::new(retry), rows[8]);
+            }
         }
         LoginStep::GeneratingCaptcha => {
             let hint = Line::from(Span::styled(
@@ -200,16 +266,23 @@ pub fn render(frame: &mut Frame, area: Rect, state: &LoginState) {
             frame.render_widget(Paragraph::new(hint), rows[7]);
         }
         LoginStep::WaitingCaptcha => {
-            let hint1 = Line::from(Span::styled(
-                t!("login.captcha_opened"),
-                Theme::active(),
-            ));
+            let opened = if state.captcha_url.is_some() { t!("login.browser_failed") } else { t!("login.captcha_opened") };
+            let hint1 = Line::from(Span::styled(opened, Theme::active()));
             let hint2 = Line::from(vec![
                 Span::styled("[Enter]", Theme::highlight()),
                 Span::raw(format!(" {}", t!("login.continue_captcha"))),
             ]);
             frame.render_widget(Paragraph::new(hint1), rows[7]);
             frame.render_widget(Paragraph::new(hint2), rows[8]);
+
+            // 浏览器打不开：在表单下方完整显示地址，供手动复制
+            if let Some(url) = &state.captcha_url {
+                let below = v_layout[4];
+                let url_area = Rect { x: form_area.x, width: form_area.width, ..below };
+                let para = Paragraph::new(Span::styled(url.as_str(), Theme::highlight()))
+                    .wrap(ratatui::widgets::Wrap { trim: false });
+                frame.render_widget(para, url_area);
+            }
         }
         LoginStep::Submitting => {
             let hint = Line::from(Span::styled(t!("login.logging_in"), Theme::active()));
@@ -262,3 +335,62 @@ fn build_input_line(
         ])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{LoginField, LoginState};
+
+    fn typed(s: &str) -> LoginState {
+        let mut state = LoginState::new();
+        for c in s.chars() {
+            state.insert_char(c);
+        }
+        state
+    }
+
+    #[test]
+    fn inserts_multibyte_chars_in_the_middle() {
+        let mut state = typed("ab");
+        state.move_cursor_left();
+        state.insert_char('哈');
+        state.insert_char('🎵');
+        assert_eq!(state.email, "a哈🎵b");
+        assert_eq!(state.email_cursor, 3);
+    }
+
+    #[test]
+    fn deletes_multibyte_chars_before_cursor() {
+        let mut state = typed("基米🎵x");
+        state.move_cursor_left();
+        state.delete_before_cursor();
+        assert_eq!(state.email, "基米x");
+        state.delete_before_cursor();
+        state.delete_before_cursor();
+        state.delete_before_cursor();
+        assert_eq!(state.email, "x");
+        assert_eq!(state.email_cursor, 0);
+    }
+
+    #[test]
+    fn cursor_stays_within_char_bounds() {
+        let mut state = typed("é");
+        state.move_cursor_right();
+        assert_eq!(state.email_cursor, 1);
+        state.move_cursor_left();
+        state.move_cursor_left();
+        assert_eq!(state.email_cursor, 0);
+    }
+
+    #[test]
+    fn fields_keep_separate_cursors() {
+        let mut state = typed("邮箱");
+        state.focused_field = LoginField::Password;
+        state.insert_char('密');
+        state.insert_char('码');
+        state.move_cursor_left();
+        state.delete_before_cursor();
+        assert_eq!(state.password, "码");
+        assert_eq!(state.email, "邮箱");
+        assert_eq!(state.email_cursor, 2);
+    }
+}
@@ -1,4 +1,4 @@
-/// LRC 歌词解析与时间同步
+//! LRC 歌词解析与时间同步
 
 pub struct LrcLine {
     pub time_secs: u32,
@@ -79,7 +79,6 @@ fn parse_timestamp(tag: &str) -> Option<u32> {
 
 impl ParsedLyrics {
     /// 二分查找 `time_secs <= current_secs` 的最后一行索引
-    #[allow(dead_code)] // TODO: 歌词高亮定位
     pub fn current_index(&self, current_secs: u32) -> Option<usize> {
         let ParsedLyrics::Synced(lines) = self else { return None };
         if lines.is_empty() {
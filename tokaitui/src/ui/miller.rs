@@ -1,9 +1,9 @@
 use std::collections::{HashMap, HashSet};
+use std::ops::Range;
 
 use ratatui::{
     Frame,
     layout::{Constraint, Layout, Rect},
-    style::{Color, Modifier, Style},
     text::Span,
     widgets::{List, ListItem, ListState, Paragraph},
 };
@@ -13,6 +13,7 @@ use super::format::song_list_line;
 use super::navigation::{NavNode, NavStack, SearchType};
 use super::preview::render_preview_column;
 use super::theme::Theme;
+use crate::config::favorite_tags::FavoriteTags;
 use crate::config::settings::Settings;
 use crate::model::playlist::PlaylistItem;
 use crate::model::queue::QueueState;
@@ -24,30 +25,102 @@ use crate::model::playlist::PlaylistMetadata;
 pub struct ColumnData<'a> {
     pub song_cache: &'a HashMap<NavNode, Vec<PublicSongDetail>>,
     pub tag_cache: &'a [String],
+    /// 收藏的标签名，在分类列表中以 ★ 标记
+    pub favorite_tags: &'a FavoriteTags,
     pub playlist_cache: &'a [PlaylistItem],
     pub queue: &'a QueueState,
     pub queue_detail: &'a HashMap<i64, PublicSongDetail>,
+    /// 队列的显示顺序（行 → 队列下标），随机模式下可按实际播放顺序排列
+    pub queue_order: &'a [usize],
     pub loading: &'a HashSet<NavNode>,
+    /// 加载失败的节点，显示重试提示
+    pub failed: &'a HashSet<NavNode>,
+    /// 是否已登录；匿名时需要登录的节点显示登录提示而非重试提示
+    pub authenticated: bool,
     pub settings: &'a Settings,
     pub search_type: SearchType,
     pub search_users: &'a [PublicUserProfile],
     pub search_playlists: &'a [PlaylistMetadata],
     /// URL → Kitty image ID（已上传到终端的封面）
     pub covers: &'a HashMap<String, u32>,
+    /// 正在下载的封面 URL
+    pub covers_loading: &'a HashSet<String>,
+    /// 已防抖排队、尚未开始下载的封面 URL
+    pub pending_cover: Option<&'a str>,
+    /// 封面未就绪时是否以色块占位（封面整体不可用时关闭）
+    pub cover_placeholders: bool,
+    /// 正在播放（或暂停中）的歌曲 ID
+    pub playing_song_id: Option<i64>,
+    pub is_playing: bool,
+    /// 正在播放标记的动画帧计数
+    pub anim_tick: u16,
 }
 
-/// 渲染 Miller Columns 三栏布局
+impl ColumnData<'_> {
+    /// 分类列表中的标签行，收藏的标签后附 ★
+    pub fn tag_label(&self, tag: &str) -> String {
+        if self.favorite_tags.contains(tag) {
+            format!(" {tag} ★")
+        } else {
+            format!(" {tag}")
+        }
+    }
+
+    /// 加载失败节点的提示：匿名浏览时需要登录的节点提示登录，其余提示按 r 重试
+    pub fn render_failed(&self, frame: &mut Frame, area: Rect, node: &NavNode) {
+        if node.requires_login() && !self.authenticated {
+            super::util::render_login_required(frame, area);
+        } else {
+            super::util::render_load_failed(frame, area);
+        }
+    }
+
+    /// 歌曲为当前播放歌曲时返回行首标记
+    pub fn playing_marker(&self, song_id: i64) -> Option<&'static str> {
+        (self.playing_song_id == Some(song_id)).then(|| self.now_playing_glyph())
+    }
+
+    /// 选中行且开启标题滚动时返回滚动帧，其余行截断显示
+    fn marquee_tick(&self, is_sel: bool, scroll_tick: u16) -> Option<u16> {
+        (is_sel && self.settings.display.marquee_enabled).then_some(scroll_tick)
+    }
+
+    pub fn now_playing_glyph(&self) -> &'static str {
+        super::format::now_playing_glyph(self.is_playing, self.anim_tick)
+    }
+
+    /// 封面是否即将到达（下载中或已排队），此时应提前预留位置
+    pub fn cover_loading(&self, url: &str) -> bool {
+        !url.is_empty() && (self.covers_loading.contains(url) || self.pending_cover == Some(url))
+    }
+}
+
+/// 渲染 Miller Columns 三栏布局；`compact` 时只渲染当前列（窄终端）
 pub fn render(
     frame: &mut Frame,
     area: Rect,
     nav: &NavStack,
     data: &ColumnData,
     scroll_tick: u16,
+    compact: bool,
 ) {
     let depth = nav.depth();
     let current = nav.current();
 
-    if depth <= 1 {
+    if compact {
+        // 单栏：顶部一行显示面包屑，其下为占满宽度的当前列表
+        let rows = Layout::vertical([Constraint::Length(1), Constraint::Min(1)]).split(area);
+        let crumb = match nav.breadcrumb() {
+            c if c.is_empty() => current.node.display_name().to_string(),
+            c => c,
+        };
+        let crumb = super::format::truncate_left_with_dots(&crumb, area.width.saturating_sub(1) as usize);
+        frame.render_widget(
+            Paragraph::new(Span::styled(format!(" {crumb}"), Theme::title())),
+            rows[0],
+        );
+        render_column(frame, rows[1], &current.node, current.selected, true, data, scroll_tick);
+    } else if depth <= 1 {
         let cols = Layout::horizontal([
                 Constraint::Percentage(MILLER_TWO_COL_PCT),
                 Constraint::Percentage(MILLER_TWO_COL_PCT),
@@ -98,11 +171,7 @@ fn render_column(
             })
             .collect();
 
-        let list = List::new(items).highlight_style(
-            Style::default()
-                .bg(Color::DarkGray)
-                .add_modifier(Modifier::BOLD),
-        );
+        let list = List::new(items).highlight_style(Theme::selected_row());
 
         let mut state = ListState::default();
         if is_active {
@@ -115,6 +184,8 @@ fn render_column(
         if data.tag_cache.is_empty() {
             if data.loading.contains(parent_node) {
                 super::util::render_placeholder(frame, area, true, "");
+            } else if data.failed.contains(parent_node) {
+                super::util::render_load_failed(frame, area);
             }
             return;
         }
@@ -123,16 +194,12 @@ fn render_column(
             .iter()
             .enumerate()
             .map(|(i, tag)| {
-                ListItem::new(format!(" {}", tag))
+                ListItem::new(data.tag_label(tag))
                     .style(Theme::list_item_style(i == selected, is_active))
             })
             .collect();
 
-        let list = List::new(items).highlight_style(
-            Style::default()
-                .bg(Color::DarkGray)
-                .add_modifier(Modifier::BOLD),
-        );
+        let list = List::new(items).highlight_style(Theme::selected_row());
 
         let mut state = ListState::default();
         if is_active {
@@ -143,7 +210,11 @@ fn render_column(
     } else if *parent_node == NavNode::MyPlaylists {
         // 渲染歌单列表
         if data.playlist_cache.is_empty() {
-            super::util::render_placeholder(frame, area, data.loading.contains(parent_node), t!("miller.no_playlists"));
+            if data.failed.contains(parent_node) {
+                data.render_failed(frame, area, parent_node);
+            } else {
+                super::util::render_placeholder(frame, area, data.loading.contains(parent_node), t!("miller.no_playlists"));
+            }
             return;
         }
 
@@ -156,11 +227,7 @@ fn render_column(
             })
             .collect();
 
-        let list = List::new(items).highlight_style(
-            Style::default()
-                .bg(Color::DarkGray)
-                .add_modifier(Modifier::BOLD),
-        );
+        let list = List::new(items).highlight_style(Theme::selected_row());
 
         let mut state = ListState::default();
         if is_active {
@@ -177,29 +244,28 @@ fn render_column(
         }
 
         let now_playing = data.queue.current_index;
-        let items: Vec<ListItem> = data.queue
-            .songs
+        let window = visible_window(data.queue_order.len(), is_active.then_some(selected), area.height);
+        let start = window.start;
+        let items: Vec<ListItem> = data.queue_order[window]
             .iter()
             .enumerate()
-            .map(|(i, item)| {
-                let is_sel = i == selected && is_active;
-                let tick = if is_sel { scroll_tick } else { 0 };
-                let prefix = if Some(i) == now_playing { "\u{25b6} " } else { "  " };
-                let title = format!("{}{}", prefix, item.name);
-                let line = song_list_line(&title, &item.artist, area.width, is_sel, tick);
+            .filter_map(|(row, &i)| Some((start + row, i, data.queue.songs.get(i)?)))
+            .map(|(row, i, item)| {
+                let is_sel = row == selected && is_active;
+                let tick = data.marquee_tick(is_sel, scroll_tick);
+                // 非播放行用空白占位，保持队列标题对齐
+                let playing = Some(i) == now_playing;
+                let marker = if playing { data.now_playing_glyph() } else { " " };
+                let line = song_list_line(Some(marker), playing, &item.name, &item.artist, area.width, is_sel, tick);
                 ListItem::new(line)
             })
             .collect();
 
-        let list = List::new(items).highlight_style(
-            Style::default()
-                .bg(Color::DarkGray)
-                .add_modifier(Modifier::BOLD),
-        );
+        let list = List::new(items).highlight_style(Theme::selected_row());
 
         let mut state = ListState::default();
         if is_active {
-            state.select(Some(selected));
+            state.select(Some(selected - start));
         }
 
         frame.render_stateful_widget(list, area, &mut state);
@@ -212,14 +278,17 @@ fn render_column(
                         super::util::render_placeholder(frame, area, false, t!("search.no_results"));
                         return;
                     }
-                    let items: Vec<ListItem> = songs.iter().enumerate().map(|(i, song)| {
-                        let is_sel = i == selected && is_active;
-                        let tick = if is_sel { scroll_tick } else { 0 };
-                        ListItem::new(song_list_line(&song.title, &song.uploader_name, area.width, is_sel, tick))
+                    let window = visible_window(songs.len(), is_active.then_some(selected), area.height);
+                    let start = window.start;
+                    let items: Vec<ListItem> = songs[window].iter().enumerate().map(|(row, song)| {
+                        let is_sel = start + row == selected && is_active;
+                        let tick = data.marquee_tick(is_sel, scroll_tick);
+                        let marker = data.playing_marker(song.id);
+                        ListItem::new(song_list_line(marker, marker.is_some(), &song.title, &song.uploader_name, area.width, is_sel, tick))
                     }).collect();
-                    let list = List::new(items).highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD));
+                    let list = List::new(items).highlight_style(Theme::selected_row());
                     let mut state = ListState::default();
-                    if is_active { state.select(Some(selected)); }
+                    if is_active { state.select(Some(selected - start)); }
                     frame.render_stateful_widget(list, area, &mut state);
                 } else if data.loading.contains(&NavNode::SearchResults) {
                     super::util::render_placeholder(frame, area, true, "");
@@ -234,7 +303,7 @@ fn render_column(
                     ListItem::new(format!(" {}", user.username))
                         .style(Theme::list_item_style(i == selected, is_active))
                 }).collect();
-                let list = List::new(items).highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD));
+                let list = List::new(items).highlight_style(Theme::selected_row());
                 let mut state = ListState::default();
                 if is_active { state.select(Some(selected)); }
                 frame.render_stateful_widget(list, area, &mut state);
@@ -248,7 +317,7 @@ fn render_column(
                     ListItem::new(format!(" {}", pl.name))
                         .style(Theme::list_item_style(i == selected, is_active))
                 }).collect();
-                let list = List::new(items).highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD));
+                let list = List::new(items).highlight_style(Theme::selected_row());
                 let mut state = ListState::default();
                 if is_active { state.select(Some(selected)); }
                 frame.render_stateful_widget(list, area, &mut state);
@@ -260,13 +329,18 @@ fn render_column(
             return;
         }
 
-        let items: Vec<ListItem> = songs
+        let window = visible_window(songs.len(), is_active.then_some(selected), area.height);
+        let start = window.start;
+        let items: Vec<ListItem> = songs[window]
             .iter()
             .enumerate()
-            .map(|(i, song)| {
-                let is_sel = i == selected && is_active;
-                let tick = if is_sel { scroll_tick } else { 0 };
+            .map(|(row, song)| {
+                let is_sel = start + row == selected && is_active;
+                let tick = data.marquee_tick(is_sel, scroll_tick);
+                let marker = data.playing_marker(song.id);
                 ListItem::new(song_list_line(
+                    marker,
+                    marker.is_some(),
                     &song.title,
                     &song.uploader_name,
                     area.width,
@@ -276,19 +350,51 @@ fn render_column(
             })
             .collect();
 
-        let list = List::new(items).highlight_style(
-            Style::default()
-                .bg(Color::DarkGray)
-                .add_modifier(Modifier::BOLD),
-        );
+        let list = List::new(items).highlight_style(Theme::selected_row());
 
         let mut state = ListState::default();
         if is_active {
-            state.select(Some(selected));
+            state.select(Some(selected - start));
         }
 
         frame.render_stateful_widget(list, area, &mut state);
     } else if data.loading.contains(parent_node) {
         super::util::render_placeholder(frame, area, true, "");
+    } else if data.failed.contains(parent_node) {
+        data.render_failed(frame, area, parent_node);
+    }
+}
+
+/// 计算列表实际可见的行区间，只为这些行构建 `ListItem`
+///
+/// 每帧都用全新的 `ListState`（偏移为 0）渲染，ratatui 会把选中行滚到视口底部，
+/// 这里按同样规则算出窗口起点。5000 首歌的歌单每帧从构建 5000 行（各自截断、滚动标题）
+/// 降为只构建视口内的几十行，单帧开销不再随列表长度增长。
+fn visible_window(len: usize, selected: Option<usize>, height: u16) -> Range<usize> {
+    let height = (height as usize).max(1);
+    let start = match selected {
+        Some(sel) if len > 0 => (sel.min(len - 1) + 1).saturating_sub(height),
+        _ => 0,
+    };
+    start..(start + height).min(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::visible_window;
+
+    #[test]
+    fn window_follows_selection_to_viewport_bottom() {
+        assert_eq!(visible_window(5000, Some(3), 20), 0..20);
+        assert_eq!(visible_window(5000, Some(19), 20), 0..20);
+        assert_eq!(visible_window(5000, Some(4321), 20), 4302..4322);
+        assert_eq!(visible_window(5000, None, 20), 0..20);
+    }
+
+    #[test]
+    fn window_stays_in_bounds() {
+        assert_eq!(visible_window(5, Some(2), 20), 0..5);
+        assert_eq!(visible_window(5, Some(99), 3), 2..5);
+        assert_eq!(visible_window(0, Some(0), 3), 0..0);
     }
 }
@@ -0,0 +1,42 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use super::lyrics::ParsedLyrics;
+use super::player_bar::PlayerBarState;
+use super::theme::Theme;
+
+/// 迷你播放器：隐藏浏览区，只在播放栏上方显示正在播放的歌曲与当前歌词行（垂直居中）
+pub fn render(frame: &mut Frame, area: Rect, state: &PlayerBarState, lyrics: &ParsedLyrics) {
+    let width = area.width as usize;
+    let mut lines = Vec::with_capacity(2);
+    if state.has_song() {
+        let now = format!("{} - {}", state.title, state.artist);
+        lines.push(Line::from(Span::styled(
+            super::format::truncate_with_dots(&now, width),
+            Theme::title(),
+        )));
+        if let ParsedLyrics::Synced(lrc) = lyrics
+            && let Some(i) = lyrics.current_index(state.current_secs)
+            && area.height >= 2
+        {
+            lines.push(Line::from(Span::styled(
+                super::format::truncate_with_dots(&lrc[i].text, width),
+                Theme::highlight(),
+            )));
+        }
+    } else {
+        lines.push(Line::from(Span::styled(t!("player.no_song"), Theme::secondary())));
+    }
+
+    let top = area.height.saturating_sub(lines.len() as u16) / 2;
+    let rect = Rect {
+        y: area.y + top,
+        height: area.height - top,
+        ..area
+    };
+    frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), rect);
+}
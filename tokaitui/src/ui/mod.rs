@@ -1,19 +1,31 @@
 #[macro_use]
 pub mod i18n;
 
+pub mod clipboard;
+pub mod confirm;
 pub mod constants;
 pub mod cover_widget;
 pub mod format;
+pub mod graphics;
 pub mod kitty;
+pub mod sixel;
+pub mod header;
 pub mod help;
+pub mod info_view;
 pub mod log_view;
 pub mod lyrics;
 pub mod login;
 pub mod miller;
+pub mod mini_player;
 pub mod navigation;
+pub mod pinned_view;
+pub mod hidden_view;
 pub mod player_bar;
 pub mod player_view;
 pub mod preview;
 pub mod settings_view;
+pub mod stats_view;
 pub mod theme;
+pub mod toast;
 pub mod util;
+pub mod window_title;
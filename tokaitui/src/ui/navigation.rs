@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 
 /// Miller Columns 导航层级树中的节点类型
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum NavNode {
     Root,
     Home,
@@ -21,6 +21,8 @@ pub enum NavNode {
     MyPlaylists,
     #[allow(dead_code)] // TODO: 收藏功能
     Favorites,
+    /// 当前登录用户自己投稿的歌曲
+    MyUploads,
     History,
 
     // 动态内容
@@ -53,6 +55,7 @@ impl NavNode {
             Self::Categories => t!("nav.categories"),
             Self::MyPlaylists => t!("nav.playlists"),
             Self::Favorites => t!("nav.favorites"),
+            Self::MyUploads => t!("nav.my_uploads"),
             Self::History => t!("nav.history"),
             Self::SongList { title } => title,
             Self::SongDetail { .. } => t!("nav.detail"),
@@ -79,7 +82,7 @@ impl NavNode {
                 Self::WeeklyHot,
                 Self::Categories,
             ],
-            Self::Library => vec![Self::MyPlaylists, Self::History],
+            Self::Library => vec![Self::MyPlaylists, Self::MyUploads, Self::History],
             _ => vec![],
         }
     }
@@ -88,6 +91,27 @@ impl NavNode {
         matches!(self, Self::Root | Self::Home | Self::Library)
     }
 
+    /// 需要登录才能加载的节点（匿名浏览时不发请求，直接提示登录）
+    pub fn requires_login(&self) -> bool {
+        matches!(self, Self::MyPlaylists | Self::Favorites | Self::MyUploads | Self::History)
+    }
+
+    /// 推荐类列表：加载时过滤掉被隐藏的歌曲
+    pub fn is_feed(&self) -> bool {
+        matches!(
+            self,
+            Self::LatestReleases | Self::DailyRecommend | Self::WeeklyHot | Self::Tag { .. } | Self::SearchResults
+        )
+    }
+
+    /// 可固定到快捷列表的节点：具体的标签、歌单、用户
+    pub fn is_pinnable(&self) -> bool {
+        matches!(
+            self,
+            Self::Tag { .. } | Self::PlaylistDetail { .. } | Self::UserDetail { .. }
+        )
+    }
+
     pub fn needs_dynamic_data(&self) -> bool {
         matches!(
             self,
@@ -97,6 +121,7 @@ impl NavNode {
                 | Self::Categories
                 | Self::Tag { .. }
                 | Self::History
+                | Self::MyUploads
                 | Self::MyPlaylists
                 | Self::PlaylistDetail { .. }
                 | Self::UserDetail { .. }
@@ -189,10 +214,34 @@ impl NavStack {
         }
     }
 
+    /// 移除栈中的 `node` 及其上方所有层级，回到进入 `node` 之前的位置（光标保持原样）。
+    /// 返回是否找到该节点
+    pub fn remove_from(&mut self, node: &NavNode) -> bool {
+        match self.path.iter().position(|l| l.node == *node) {
+            Some(pos) if pos > 0 => {
+                for level in self.path.drain(pos..) {
+                    self.cursor_memory.insert(level.node, level.selected);
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
     #[allow(dead_code)] // TODO: 面包屑导航
     pub fn path(&self) -> &[NavLevel] {
         &self.path
     }
+
+    /// 当前路径的面包屑（如 "Home › Categories › VOCALOID"），不含根节点
+    pub fn breadcrumb(&self) -> String {
+        self.path
+            .iter()
+            .skip(1)
+            .map(|l| l.node.display_name())
+            .collect::<Vec<_>>()
+            .join(" \u{203a} ")
+    }
 }
 
 /// 搜索状态
@@ -202,6 +251,8 @@ pub struct SearchState {
     pub search_type: SearchType,
     pub sort: SearchSort,
     pub cursor_pos: usize,
+    /// 查询为空时在最近搜索建议中选中的条目
+    pub suggestion: Option<usize>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
@@ -263,13 +314,14 @@ impl SearchState {
             search_type: SearchType::default(),
             sort: SearchSort::default(),
             cursor_pos: 0,
+            suggestion: None,
         }
     }
 
     /// 清空查询和光标，保留 type/sort 偏好
-    #[allow(dead_code)] // TODO: 搜索重置
     pub fn clear(&mut self) {
         self.query.clear();
         self.cursor_pos = 0;
+        self.suggestion = None;
     }
 }
@@ -0,0 +1,56 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use super::navigation::NavNode;
+use super::theme::Theme;
+
+/// 渲染固定节点浮层：j/k 选择，Enter 跳转，d 取消固定
+pub fn render(frame: &mut Frame, area: Rect, nodes: &[NavNode], selected: usize) {
+    // 面板外高度 = 2 (borders) + 空行 + 条目（至少一行空提示） + 1 (hint)
+    let panel_h = nodes.len().max(1) as u16 + 4;
+    let (content_area, hint_area) = super::util::overlay_panel(
+        frame, area, t!("pinned.title"),
+        super::constants::PINNED_PANEL_WIDTH, panel_h,
+    );
+
+    let mut lines = vec![Line::from("")];
+    if nodes.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!("  {}", t!("pinned.empty")),
+            Theme::secondary(),
+        )));
+    }
+    for (i, node) in nodes.iter().enumerate() {
+        let is_selected = i == selected;
+        let marker = if is_selected { "▸ " } else { "  " };
+        lines.push(Line::from(Span::styled(
+            format!("{marker}{}", label(node)),
+            Theme::list_item_style(is_selected, true),
+        )));
+    }
+
+    // 选中项超出面板时整体上移
+    let visible = content_area.height.saturating_sub(1) as usize;
+    let scroll = (selected + 1).saturating_sub(visible) as u16;
+    frame.render_widget(Paragraph::new(lines).scroll((scroll, 0)), content_area);
+
+    let hint = Paragraph::new(Span::styled(
+        format!("  {}", t!("pinned.hint")),
+        Theme::secondary(),
+    ));
+    frame.render_widget(hint, hint_area);
+}
+
+/// 歌单、用户节点的 display_name 只是类别名，附上 ID 以区分
+fn label(node: &NavNode) -> String {
+    match node {
+        NavNode::PlaylistDetail { id } | NavNode::UserDetail { id } => {
+            format!("{} #{id}", node.display_name())
+        }
+        _ => node.display_name().to_string(),
+    }
+}
@@ -3,37 +3,50 @@ use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 use ratatui::{
     Frame,
     layout::Rect,
-    style::Style,
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::Paragraph,
 };
 
 use super::theme::Theme;
 
+/// 剩余空间不足以显示这么宽的「下一首」提示时不显示
+const NEXT_HINT_MIN_WIDTH: usize = 12;
+
+/// 播放栏的加载阶段：网络下载与解码启动分开显示，便于判断慢在哪一步
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LoadPhase {
+    #[default]
+    Idle,
+    /// 正在下载音频
+    Downloading,
+    /// 数据已就绪，播放引擎正在解码/启动
+    Starting,
+}
+
+/// 音频下载进度，由下载任务按固定间隔上报
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DownloadProgress {
+    /// 已接收字节
+    pub received: u64,
+    /// 总字节（服务端未给出 Content-Length 时为 None）
+    pub total: Option<u64>,
+    /// 距上次上报这段时间内的下载速度（字节/秒）
+    pub bytes_per_sec: u64,
+}
+
+#[derive(Default)]
 pub struct PlayerBarState {
     pub is_playing: bool,
     pub title: String,
     pub artist: String,
     pub current_secs: u32,
     pub total_secs: u32,
-    pub is_loading: bool,
+    pub phase: LoadPhase,
     pub cover_url: String,
     pub codec: String,
-}
-
-impl Default for PlayerBarState {
-    fn default() -> Self {
-        Self {
-            is_playing: false,
-            title: String::new(),
-            artist: String::new(),
-            current_secs: 0,
-            total_secs: 0,
-            is_loading: false,
-            cover_url: String::new(),
-            codec: String::new(),
-        }
-    }
+    /// 下载中的进度，超过显示阈值后才有值
+    pub download: Option<DownloadProgress>,
 }
 
 impl PlayerBarState {
@@ -42,8 +55,8 @@ impl PlayerBarState {
     }
 }
 
-/// 渲染底部播放状态栏
-pub fn render(frame: &mut Frame, area: Rect, state: &PlayerBarState) {
+/// 渲染底部播放状态栏；`next` 为按当前播放模式接下来会播放的歌曲标题
+pub fn render(frame: &mut Frame, area: Rect, state: &PlayerBarState, next: Option<&str>) {
     if !state.has_song() {
         let empty = Paragraph::new(format!("  {}", t!("player.no_song")))
             .style(Theme::secondary());
@@ -51,16 +64,16 @@ pub fn render(frame: &mut Frame, area: Rect, state: &PlayerBarState) {
         return;
     }
 
-    let status_icon = if state.is_loading {
-        "◌"
-    } else if state.is_playing {
-        "⏸"
-    } else {
-        "▶"
+    let status_icon = match state.phase {
+        LoadPhase::Downloading => "\u{2193}",
+        LoadPhase::Starting => "◌",
+        LoadPhase::Idle if state.is_playing => "⏸",
+        LoadPhase::Idle => "▶",
     };
 
     let time_current = format_time(state.current_secs);
-    let time_total = format_time(state.total_secs);
+    // 时长未知（0）时总时长显示为占位符
+    let time_total = if state.total_secs == 0 { "--:--".to_string() } else { format_time(state.total_secs) };
 
     let progress_bar = build_progress_bar(state.current_secs, state.total_secs, 10);
 
@@ -70,10 +83,30 @@ pub fn render(frame: &mut Frame, area: Rect, state: &PlayerBarState) {
     } else {
         format!("[{}] ", state.codec.to_uppercase())
     };
-    let right_part = format!(
-        " {}{}/{} {} ",
-        codec_tag, time_current, time_total, progress_bar
-    );
+    let download = state.download.filter(|_| state.phase == LoadPhase::Downloading);
+    let right_part = match download {
+        // 下载中：以下载进度与速度代替播放进度
+        Some(DownloadProgress { received, total: Some(total), bytes_per_sec }) if total > 0 => format!(
+            " \u{2193} {:>3}% {} {}/{} {} ",
+            received * 100 / total,
+            build_progress_bar((received.min(total) / 1024) as u32, (total / 1024) as u32, 10),
+            format_mb_value(received),
+            format_mb(total),
+            format_speed(bytes_per_sec),
+        ),
+        Some(DownloadProgress { received, bytes_per_sec, .. }) => format!(
+            " \u{2193} {} {} ",
+            format_mb(received),
+            format_speed(bytes_per_sec),
+        ),
+        None if state.phase == LoadPhase::Starting => {
+            format!(" {}{} ", codec_tag, t!("player.starting"))
+        }
+        None => format!(
+            " {}{}/{} {} ",
+            codec_tag, time_current, time_total, progress_bar
+        ),
+    };
 
     let available_width = area.width as usize;
     let right_len = right_part.width();
@@ -82,12 +115,22 @@ pub fn render(frame: &mut Frame, area: Rect, state: &PlayerBarState) {
 
     let song_max = available_width.saturating_sub(right_len + icon_len);
     let song_display = truncate_str(&song_info, song_max);
-    let padding = available_width.saturating_sub(icon_len + song_display.width() + right_len);
+    let mut padding = available_width.saturating_sub(icon_len + song_display.width() + right_len);
+
+    // 歌名之后还有余量时，在进度前淡色提示下一首
+    let next_hint = next
+        .map(|title| format!("{} {title} ", t!("player.next")))
+        .filter(|_| padding.saturating_sub(2) >= NEXT_HINT_MIN_WIDTH)
+        .map(|hint| truncate_str(&hint, padding - 2));
+    if let Some(hint) = &next_hint {
+        padding -= hint.width();
+    }
 
     let line = Line::from(vec![
         Span::styled(icon_part, Theme::active()),
         Span::styled(song_display, Style::default()),
         Span::raw(" ".repeat(padding)),
+        Span::styled(next_hint.unwrap_or_default(), Theme::secondary().add_modifier(Modifier::DIM)),
         Span::styled(right_part, Theme::secondary()),
     ]);
 
@@ -101,11 +144,35 @@ fn format_time(secs: u32) -> String {
     format!("{m:02}:{s:02}")
 }
 
+fn format_mb(bytes: u64) -> String {
+    format!("{} MB", format_mb_value(bytes))
+}
+
+fn format_mb_value(bytes: u64) -> String {
+    format!("{:.1}", bytes as f64 / (1024.0 * 1024.0))
+}
+
+fn format_speed(bytes_per_sec: u64) -> String {
+    let kb = bytes_per_sec / 1024;
+    if kb < 1024 {
+        format!("{kb} KB/s")
+    } else {
+        format!("{:.1} MB/s", bytes_per_sec as f64 / (1024.0 * 1024.0))
+    }
+}
+
+/// 时长未知（`total == 0`）时显示随播放位置来回移动的亮块，不计算比例
 fn build_progress_bar(current: u32, total: u32, width: usize) -> String {
     if total == 0 {
-        return "⣀".repeat(width);
+        if width < 2 {
+            return "⣀".repeat(width);
+        }
+        let period = 2 * (width - 1);
+        let pos = current as usize % period;
+        let lit = if pos < width { pos } else { period - pos };
+        return (0..width).map(|i| if i == lit { '⣿' } else { '⣀' }).collect();
     }
-    let ratio = current as f64 / total as f64;
+    let ratio = (current as f64 / total as f64).min(1.0);
     let filled = (ratio * width as f64).round() as usize;
     let empty = width.saturating_sub(filled);
     format!("{}{}", "⣿".repeat(filled), "⣀".repeat(empty))
@@ -129,3 +196,23 @@ fn truncate_str(s: &str, max: usize) -> String {
     result.push_str("..");
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::build_progress_bar;
+
+    #[test]
+    fn unknown_duration_shows_moving_block() {
+        // 色块在两端反弹：0 → 3 → 0，周期 2 * (width - 1)
+        let lit: Vec<usize> = (0..7)
+            .map(|t| build_progress_bar(t, 0, 4).chars().position(|c| c == '⣿').unwrap())
+            .collect();
+        assert_eq!(lit, [0, 1, 2, 3, 2, 1, 0]);
+        assert_eq!(build_progress_bar(4, 0, 4), "⣀⣀⣿⣀");
+    }
+
+    #[test]
+    fn position_past_duration_stays_full_width() {
+        assert_eq!(build_progress_bar(300, 200, 4), "⣿⣿⣿⣿");
+    }
+}
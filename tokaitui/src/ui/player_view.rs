@@ -18,14 +18,33 @@ pub struct PlaybackInfo<'a> {
     pub parsed_lyrics: &'a ParsedLyrics,
 }
 
-/// 渲染展开详情视图（选中歌曲 或 播放中歌曲）
+/// 展开视图的显示选项
+pub struct ViewOptions {
+    /// 封面尚未加载时绘制占位色块
+    pub placeholders: bool,
+    /// 封面缩放比例（百分比）
+    pub cover_scale: u8,
+    /// 详情（播放中为创作团队）的滚动偏移（行）
+    pub scroll: u16,
+}
+
+/// 自动滚动在顶部/底部各停留的步数
+const CREDITS_ROLL_PAUSE: u16 = 8;
+/// 自动滚动每隔多少步前进一行
+const CREDITS_ROLL_STRIDE: u16 = 3;
+/// 播放中创作团队区域的最大高度（行），名单更长时在区域内滚动
+const CREDITS_ROLL_HEIGHT: u16 = 6;
+
+/// 渲染展开详情视图（选中歌曲 或 播放中歌曲），返回详情可滚动的最大偏移
 pub fn render(
     frame: &mut Frame,
     area: Rect,
     detail: &PublicSongDetail,
     playback: Option<PlaybackInfo<'_>>,
     covers: &HashMap<String, u32>,
-) {
+    opts: ViewOptions,
+) -> u16 {
+    let ViewOptions { placeholders, cover_scale, scroll } = opts;
     let padded = super::util::padded_rect(area, 2);
 
     // 左右对半分
@@ -39,20 +58,27 @@ pub fn render(
     };
 
     // 左栏：封面水平垂直居中，视觉正方形（终端格子高≈宽的2倍，故 w=2h）
-    // 限制边：h = min(left.width/2, left.height) * 3/4，w = h*2
-    if let Some(&id) = covers.get(&detail.cover_url) {
-        let max_h = (left.width / 2).min(left.height) * 3 / 4;
-        if max_h >= 2 {
-            let cover_h = max_h;
-            let cover_w = cover_h * 2;
-            let cx = left.x + left.width.saturating_sub(cover_w) / 2;
-            let cy = left.y + left.height.saturating_sub(cover_h) / 2;
-            let cover_rect = Rect::new(cx, cy, cover_w, cover_h);
+    // 基准边：h = min(left.width/2, left.height) * 3/4，按缩放比例调整后不超过左栏，w = h*2
+    let fit_h = (left.width / 2).min(left.height);
+    let max_h = (fit_h as u32 * 3 / 4 * cover_scale as u32 / 100).min(fit_h as u32) as u16;
+    if max_h >= 2 {
+        let cover_h = max_h;
+        let cover_w = cover_h * 2;
+        let cx = left.x + left.width.saturating_sub(cover_w) / 2;
+        let cy = left.y + left.height.saturating_sub(cover_h) / 2;
+        let cover_rect = Rect::new(cx, cy, cover_w, cover_h);
+        if let Some(&id) = covers.get(&detail.cover_url) {
             frame.render_widget(super::cover_widget::CoverWidget { image_id: id }, cover_rect);
+        } else if placeholders {
+            super::util::render_cover_placeholder(
+                frame,
+                cover_rect,
+                super::util::placeholder_color(detail.id),
+            );
         }
     }
 
-    let header_lines = vec![
+    let mut header_lines = vec![
         Line::from(Span::styled(
             detail.title.clone(),
             Style::default().add_modifier(Modifier::BOLD),
@@ -62,77 +88,117 @@ pub fn render(
             Theme::secondary(),
         )),
     ];
-
-    let header_height = header_lines.len() as u16;
+    if let Some(line) = super::format::artists_line(detail) {
+        header_lines.push(line);
+    }
 
     if let Some(pb) = playback {
-        // 播放中：展示时间同步歌词
-        render_playing(frame, inner, header_lines, header_height, pb);
+        // 播放中：展示创作团队与时间同步歌词
+        render_playing(frame, inner, header_lines, detail, pb, scroll)
     } else {
         // 浏览：展示歌曲元数据 + 歌词
-        render_browsing(frame, inner, header_lines, detail);
+        render_browsing(frame, inner, header_lines, detail, scroll)
     }
 }
 
-/// 播放中歌曲的右侧内容：标题 + 时间同步歌词
+/// 自动滚动的偏移：顶部停留 → 逐行下滚 → 底部停留 → 回到顶部
+pub fn credits_roll_offset(step: u16, max_scroll: u16) -> u16 {
+    if max_scroll == 0 {
+        return 0;
+    }
+    let rolling = max_scroll.saturating_mul(CREDITS_ROLL_STRIDE);
+    let cycle = CREDITS_ROLL_PAUSE.saturating_add(rolling).saturating_add(CREDITS_ROLL_PAUSE);
+    let pos = step % cycle;
+    if pos < CREDITS_ROLL_PAUSE {
+        0
+    } else {
+        ((pos - CREDITS_ROLL_PAUSE) / CREDITS_ROLL_STRIDE).min(max_scroll)
+    }
+}
+
+/// 播放中歌曲的右侧内容：标题 + 创作团队 + 时间同步歌词，返回创作团队可滚动的最大偏移
 fn render_playing(
     frame: &mut Frame,
     inner: Rect,
     header_lines: Vec<Line<'static>>,
-    header_height: u16,
+    detail: &PublicSongDetail,
     pb: PlaybackInfo<'_>,
-) {
+    scroll: u16,
+) -> u16 {
+    let header_height = (header_lines.len() as u16).min(inner.height);
+    frame.render_widget(Paragraph::new(header_lines), Rect { height: header_height, ..inner });
+    let mut body = Rect {
+        y: inner.y + header_height,
+        height: inner.height - header_height,
+        ..inner
+    };
+
+    // 创作团队：标题下方固定高度的区域，名单超出时按 scroll 滚动
+    let crew = crew_lines(detail);
+    let mut max_scroll = 0;
+    if !crew.is_empty() && body.height > 1 {
+        let total = crew.len().min(u16::MAX as usize) as u16;
+        let visible = total.min(CREDITS_ROLL_HEIGHT).min(body.height - 1);
+        max_scroll = total - visible;
+        let rect = Rect { y: body.y + 1, height: visible, ..body };
+        frame.render_widget(Paragraph::new(crew).scroll((scroll.min(max_scroll), 0)), rect);
+        body = Rect {
+            y: rect.y + visible,
+            height: body.height - 1 - visible,
+            ..body
+        };
+    }
+
+    // 歌词与上方内容之间空一行
+    if body.height <= 1 {
+        return max_scroll;
+    }
+    let lyrics_rect = Rect { y: body.y + 1, height: body.height - 1, ..body };
     match pb.parsed_lyrics {
         ParsedLyrics::Synced(lrc_lines) => {
-            let header_para = Paragraph::new(header_lines);
-            let header_rect = Rect { height: header_height.min(inner.height), ..inner };
-            frame.render_widget(header_para, header_rect);
-
-            let lyrics_y = inner.y + header_height + 1;
-            if lyrics_y < inner.y + inner.height {
-                let lyrics_rect = Rect {
-                    x: inner.x,
-                    y: lyrics_y,
-                    width: inner.width,
-                    height: inner.height.saturating_sub(header_height + 1),
-                };
-                render_synced_lyrics(frame, lyrics_rect, lrc_lines, pb.current_secs);
-            }
+            render_synced_lyrics(frame, lyrics_rect, lrc_lines, pb.current_secs);
         }
         ParsedLyrics::Plain(plain_lines) => {
-            let mut lines = header_lines;
-            if !plain_lines.is_empty() {
-                lines.push(Line::from(""));
-                for line in plain_lines {
-                    lines.push(Line::from(Span::styled(
-                        line.clone(),
-                        Theme::secondary(),
-                    )));
-                }
-            }
-            let para = Paragraph::new(lines).wrap(Wrap { trim: false });
-            frame.render_widget(para, inner);
+            let lines: Vec<Line> = plain_lines.iter()
+                .map(|line| Line::from(Span::styled(line.clone(), Theme::secondary())))
+                .collect();
+            frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), lyrics_rect);
         }
         ParsedLyrics::Empty => {
-            let mut lines = header_lines;
-            lines.push(Line::from(""));
-            lines.push(Line::from(Span::styled(
-                t!("player.no_lyrics"),
-                Theme::secondary(),
-            )));
-            let para = Paragraph::new(lines).wrap(Wrap { trim: false });
-            frame.render_widget(para, inner);
+            let para = Paragraph::new(Span::styled(t!("player.no_lyrics"), Theme::secondary()));
+            frame.render_widget(para, lyrics_rect);
         }
     }
+    max_scroll
 }
 
-/// 浏览歌曲的右侧内容：标题 + 元数据 + 歌词
+/// 创作团队：标题行 + 每位成员一行，没有成员时为空
+fn crew_lines(detail: &PublicSongDetail) -> Vec<Line<'static>> {
+    if detail.production_crew.is_empty() {
+        return Vec::new();
+    }
+    let mut lines = vec![Line::from(Span::styled(
+        t!("miller.crew").to_string(),
+        Style::default().add_modifier(Modifier::BOLD),
+    ))];
+    for member in &detail.production_crew {
+        let name = member.person_name.as_deref().unwrap_or("?");
+        lines.push(Line::from(Span::styled(
+            format!("  {} \u{2014} {name}", member.role),
+            Theme::secondary(),
+        )));
+    }
+    lines
+}
+
+/// 浏览歌曲的右侧内容：标题 + 元数据 + 歌词，返回可滚动的最大偏移
 fn render_browsing(
     frame: &mut Frame,
     inner: Rect,
     header_lines: Vec<Line<'static>>,
     detail: &PublicSongDetail,
-) {
+    scroll: u16,
+) -> u16 {
     let mut lines = header_lines;
 
     // 副标题
@@ -195,19 +261,7 @@ fn render_browsing(
     }
 
     // 创作团队
-    if !detail.production_crew.is_empty() {
-        lines.push(Line::from(Span::styled(
-            t!("miller.crew").to_string(),
-            Style::default().add_modifier(Modifier::BOLD),
-        )));
-        for member in &detail.production_crew {
-            let name = member.person_name.as_deref().unwrap_or("?");
-            lines.push(Line::from(Span::styled(
-                format!("  {} \u{2014} {name}", member.role),
-                Theme::secondary(),
-            )));
-        }
-    }
+    lines.extend(crew_lines(detail));
 
     // 外部链接
     if !detail.external_links.is_empty() {
@@ -217,7 +271,7 @@ fn render_browsing(
                 link_spans.push(Span::styled(" \u{00b7} ", Theme::secondary()));
             }
             link_spans.push(Span::styled(
-                format!(" {} ", link.platform),
+                format!(" {} {} ", i + 1, link.platform),
                 Theme::link_badge(),
             ));
         }
@@ -260,8 +314,16 @@ fn render_browsing(
         _ => {}
     }
 
-    let para = Paragraph::new(lines).wrap(Wrap { trim: false });
+    // 按折行估算总行数，超出可视高度的部分可滚动
+    let width = inner.width.max(1) as usize;
+    let total: usize = lines.iter().map(|l| l.width().div_ceil(width).max(1)).sum();
+    let max_scroll = total.saturating_sub(inner.height as usize).min(u16::MAX as usize) as u16;
+
+    let para = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .scroll((scroll.min(max_scroll), 0));
     frame.render_widget(para, inner);
+    max_scroll
 }
 
 /// 渲染时间同步歌词：当前行高亮居中，上下文淡色
@@ -283,29 +345,19 @@ fn render_synced_lyrics(
     };
 
     // 每行歌词占 2 行（歌词 + 空行），计算可显示的歌词条数
-    let visible_items = (visible_rows + 1) / 2; // 最后一条不需要尾部空行
-
-    // 计算窗口起始位置，让当前行尽量居中
-    let half = visible_items / 2;
-    let start = if cur_idx <= half {
-        0
-    } else if cur_idx + visible_items - half > lrc_lines.len() {
-        lrc_lines.len().saturating_sub(visible_items)
-    } else {
-        cur_idx - half
-    };
-    let end = (start + visible_items).min(lrc_lines.len());
+    let visible_items = visible_rows.div_ceil(2); // 最后一条不需要尾部空行
+    let (start, end) = lyrics_window(cur_idx, lrc_lines.len(), visible_items);
 
     let mut lines: Vec<Line> = Vec::new();
-    for i in start..end {
+    for (i, lrc) in lrc_lines.iter().enumerate().take(end).skip(start) {
         if i == cur_idx {
             lines.push(Line::from(Span::styled(
-                format!("\u{25b6} {}", lrc_lines[i].text),
+                format!("\u{25b6} {}", lrc.text),
                 Theme::highlight(),
             )));
         } else {
             lines.push(Line::from(Span::styled(
-                format!("  {}", lrc_lines[i].text),
+                format!("  {}", lrc.text),
                 Theme::secondary(),
             )));
         }
@@ -318,3 +370,66 @@ fn render_synced_lyrics(
     let para = Paragraph::new(lines);
     frame.render_widget(para, area);
 }
+
+/// 计算歌词可见窗口 `[start, end)`：当前行尽量居中，
+/// 开头不留空白，结尾钳制使最后几行始终完整可见
+fn lyrics_window(cur_idx: usize, total: usize, visible_items: usize) -> (usize, usize) {
+    if visible_items == 0 || total == 0 {
+        return (0, 0);
+    }
+    let half = visible_items / 2;
+    let max_start = total.saturating_sub(visible_items);
+    let start = cur_idx.saturating_sub(half).min(max_start);
+    (start, (start + visible_items).min(total))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CREDITS_ROLL_PAUSE, CREDITS_ROLL_STRIDE, credits_roll_offset, lyrics_window};
+
+    #[test]
+    fn window_starts_at_top_for_early_lines() {
+        assert_eq!(lyrics_window(0, 100, 9), (0, 9));
+        assert_eq!(lyrics_window(4, 100, 9), (0, 9));
+    }
+
+    #[test]
+    fn window_centers_active_line_in_the_middle() {
+        let (start, end) = lyrics_window(50, 100, 9);
+        assert_eq!((start, end), (46, 55));
+        assert_eq!(50 - start, end - 1 - 50);
+    }
+
+    #[test]
+    fn window_clamps_to_last_lines_at_track_end() {
+        assert_eq!(lyrics_window(97, 100, 9), (91, 100));
+        assert_eq!(lyrics_window(99, 100, 9), (91, 100));
+    }
+
+    #[test]
+    fn window_handles_short_lyrics_and_empty_view() {
+        assert_eq!(lyrics_window(2, 5, 9), (0, 5));
+        assert_eq!(lyrics_window(3, 100, 0), (0, 0));
+    }
+
+    #[test]
+    fn credits_roll_pauses_scrolls_and_wraps() {
+        let max = 2;
+        let offsets: Vec<u16> = (0..).take(30).map(|step| credits_roll_offset(step, max)).collect();
+        let roll_end = CREDITS_ROLL_PAUSE + max * CREDITS_ROLL_STRIDE;
+        let cycle = roll_end + CREDITS_ROLL_PAUSE;
+
+        // 顶部停留，然后每 STRIDE 步前进一行，且从不后退
+        assert!(offsets[..CREDITS_ROLL_PAUSE as usize].iter().all(|&o| o == 0));
+        assert_eq!(offsets[(CREDITS_ROLL_PAUSE + CREDITS_ROLL_STRIDE) as usize], 1);
+        assert!(offsets[..cycle as usize].windows(2).all(|w| w[1] >= w[0] && w[1] - w[0] <= 1));
+        // 底部停留后回到顶部
+        assert!(offsets[roll_end as usize..cycle as usize].iter().all(|&o| o == max));
+        assert_eq!(offsets[cycle as usize], 0);
+    }
+
+    #[test]
+    fn credits_roll_stays_put_when_everything_fits() {
+        assert!((0..100).all(|step| credits_roll_offset(step, 0) == 0));
+    }
+}
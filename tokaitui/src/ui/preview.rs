@@ -1,5 +1,3 @@
-use std::collections::HashMap;
-
 use ratatui::{
     Frame,
     layout::Rect,
@@ -23,8 +21,6 @@ pub fn render_preview_column(
     selected: usize,
     data: &ColumnData,
 ) {
-    let covers = data.covers;
-    let scale = data.settings.display.cover_scale;
     if parent_node.has_static_children() {
         let children = parent_node.children();
         if children.is_empty() {
@@ -52,12 +48,14 @@ pub fn render_preview_column(
             if data.tag_cache.is_empty() {
                 if data.loading.contains(selected_node) {
                     super::util::render_placeholder(frame, area, true, "");
+                } else if data.failed.contains(selected_node) {
+                    super::util::render_load_failed(frame, area);
                 }
             } else {
                 let items: Vec<ListItem> = data.tag_cache
                     .iter()
                     .map(|tag| {
-                        ListItem::new(format!(" {}", tag)).style(Theme::secondary())
+                        ListItem::new(data.tag_label(tag)).style(Theme::secondary())
                     })
                     .collect();
                 let list = List::new(items);
@@ -67,6 +65,8 @@ pub fn render_preview_column(
             if data.playlist_cache.is_empty() {
                 if data.loading.contains(selected_node) {
                     super::util::render_placeholder(frame, area, true, "");
+                } else if data.failed.contains(selected_node) {
+                    data.render_failed(frame, area, selected_node);
                 }
             } else {
                 let items: Vec<ListItem> = data.playlist_cache
@@ -84,17 +84,27 @@ pub fn render_preview_column(
                 frame.render_widget(hint, area);
             } else {
                 let now_playing = data.queue.current_index;
-                let items: Vec<ListItem> = data.queue.songs.iter().enumerate().map(|(i, item)| {
-                    let prefix = if Some(i) == now_playing { "\u{25b6} " } else { "  " };
-                    ListItem::new(format!("{}{}", prefix, item.name)).style(Theme::secondary())
+                let items: Vec<ListItem> = data.queue_order.iter().filter_map(|&i| {
+                    let item = data.queue.songs.get(i)?;
+                    let line = if Some(i) == now_playing {
+                        Line::from(vec![
+                            Span::styled(format!("{} ", data.now_playing_glyph()), Theme::now_playing()),
+                            Span::styled(item.name.clone(), Theme::now_playing()),
+                        ])
+                    } else {
+                        Line::from(Span::styled(format!("  {}", item.name), Theme::secondary()))
+                    };
+                    Some(ListItem::new(line))
                 }).collect();
                 let list = List::new(items);
                 frame.render_widget(list, area);
             }
         } else if let Some(songs) = data.song_cache.get(selected_node) {
-            render_song_list_preview(frame, area, songs);
+            render_song_list_preview(frame, area, selected_node.display_name(), None, songs, data);
         } else if data.loading.contains(selected_node) {
             super::util::render_placeholder(frame, area, true, "");
+        } else if data.failed.contains(selected_node) {
+            data.render_failed(frame, area, selected_node);
         } else {
             let hint = Paragraph::new(vec![Line::from(Span::styled(
                 format!("  {}", selected_node.display_name()),
@@ -106,50 +116,55 @@ pub fn render_preview_column(
         if let Some(tag_name) = data.tag_cache.get(selected) {
             let tag_node = NavNode::Tag { name: tag_name.clone() };
             if let Some(songs) = data.song_cache.get(&tag_node) {
-                render_song_list_preview(frame, area, songs);
+                render_song_list_preview(frame, area, tag_name, None, songs, data);
             } else if data.loading.contains(&tag_node) {
                 super::util::render_placeholder(frame, area, true, "");
+            } else if data.failed.contains(&tag_node) {
+                super::util::render_load_failed(frame, area);
             }
         }
     } else if *parent_node == NavNode::MyPlaylists {
         if let Some(pl) = data.playlist_cache.get(selected) {
             let pl_node = NavNode::PlaylistDetail { id: pl.id };
             if let Some(songs) = data.song_cache.get(&pl_node) {
-                render_song_list_preview(frame, area, songs);
+                render_song_list_preview(frame, area, &pl.name, pl.cover_url.as_deref(), songs, data);
             } else if data.loading.contains(&pl_node) {
                 super::util::render_placeholder(frame, area, true, "");
+            } else if data.failed.contains(&pl_node) {
+                super::util::render_load_failed(frame, area);
             }
         }
     } else if *parent_node == NavNode::Queue {
-        if let Some(item) = data.queue.songs.get(selected) {
+        let index = data.queue_order.get(selected).copied();
+        if let Some(item) = index.and_then(|i| data.queue.songs.get(i)) {
             if let Some(detail) = data.queue_detail.get(&item.id) {
-                render_song_detail(frame, area, detail, covers, scale);
+                render_song_detail(frame, area, detail, data);
             } else {
-                render_queue_item_detail(frame, area, item, data.queue.current_index == Some(selected), covers, scale);
+                render_queue_item_detail(frame, area, item, data.queue.current_index == index, data);
             }
         }
     } else if *parent_node == NavNode::SearchResults {
         match data.search_type {
             SearchType::Song => {
                 if let Some(song) = data.song_cache.get(&NavNode::SearchResults).and_then(|s| s.get(selected)) {
-                    render_song_detail(frame, area, song, covers, scale);
+                    render_song_detail(frame, area, song, data);
                 }
             }
             SearchType::User => {
                 if let Some(user) = data.search_users.get(selected) {
-                    render_user_preview(frame, area, user, covers, scale);
+                    render_user_preview(frame, area, user, data);
                 }
             }
             SearchType::Playlist => {
                 if let Some(pl) = data.search_playlists.get(selected) {
-                    render_playlist_preview(frame, area, pl, covers, scale);
+                    render_playlist_preview(frame, area, pl, data);
                 }
             }
         }
-    } else if let Some(songs) = data.song_cache.get(parent_node) {
-        if let Some(song) = songs.get(selected) {
-            render_song_detail(frame, area, song, covers, scale);
-        }
+    } else if let Some(songs) = data.song_cache.get(parent_node)
+        && let Some(song) = songs.get(selected)
+    {
+        render_song_detail(frame, area, song, data);
     }
 }
 
@@ -159,18 +174,17 @@ fn render_queue_item_detail(
     area: Rect,
     item: &crate::model::queue::MusicQueueItem,
     is_playing: bool,
-    covers: &HashMap<String, u32>,
-    cover_scale: u8,
+    data: &ColumnData,
 ) {
     let inner = super::util::padded_rect(area, 2);
-    let inner = apply_cover(frame, inner, &item.cover_url, covers, cover_scale);
+    let inner = apply_cover(frame, inner, &item.cover_url, item.id, data);
 
     let mut lines = Vec::new();
 
     if is_playing {
         lines.push(Line::from(Span::styled(
             "\u{25b6} Now Playing",
-            Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+            Theme::now_playing().add_modifier(Modifier::BOLD),
         )));
     }
 
@@ -201,13 +215,47 @@ fn render_queue_item_detail(
     frame.render_widget(para, inner);
 }
 
-/// 渲染歌曲列表预览（Preview 栏中显示标题列表）
-fn render_song_list_preview(frame: &mut Frame, area: Rect, songs: &[PublicSongDetail]) {
+/// 渲染歌曲列表预览（Preview 栏中显示标题列表）。
+/// 开启 `rich_preview` 时改为展示封面（未给出时取第一首歌）、歌曲数与总时长，再列出歌曲。
+fn render_song_list_preview(
+    frame: &mut Frame,
+    area: Rect,
+    title: &str,
+    cover_url: Option<&str>,
+    songs: &[PublicSongDetail],
+    data: &ColumnData,
+) {
     if songs.is_empty() {
         let hint = Paragraph::new(Span::styled(format!("  {}", t!("miller.no_songs")), Theme::secondary()));
         frame.render_widget(hint, area);
         return;
     }
+    if data.settings.display.rich_preview {
+        let inner = super::util::padded_rect(area, 2);
+        let url = cover_url.unwrap_or(&songs[0].cover_url);
+        let inner = apply_cover(frame, inner, url, songs[0].id, data);
+        let total_secs: i64 = songs.iter().map(|s| s.duration_seconds as i64).sum();
+        let mut lines = vec![
+            Line::from(Span::styled(title.to_string(), Style::default().add_modifier(Modifier::BOLD))),
+            Line::from(vec![
+                Span::styled(format!("{} ", t!("search.songs_count")), Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(songs.len().to_string(), Theme::active()),
+                Span::styled(
+                    format!("  \u{00b7}  {}:{:02}:{:02}", total_secs / 3600, total_secs % 3600 / 60, total_secs % 60),
+                    Theme::secondary(),
+                ),
+            ]),
+            Line::from(""),
+        ];
+        lines.extend(songs.iter().map(|song| {
+            Line::from(vec![
+                Span::raw(song.title.clone()),
+                Span::styled(format!("  {}", song.uploader_name), Theme::secondary()),
+            ])
+        }));
+        frame.render_widget(Paragraph::new(lines), inner);
+        return;
+    }
     let items: Vec<ListItem> = songs
         .iter()
         .map(|song| ListItem::new(format!(" {}", song.title)).style(Theme::secondary()))
@@ -221,11 +269,10 @@ fn render_song_detail(
     frame: &mut Frame,
     area: Rect,
     song: &PublicSongDetail,
-    covers: &HashMap<String, u32>,
-    cover_scale: u8,
+    data: &ColumnData,
 ) {
     let inner = super::util::padded_rect(area, 2);
-    let inner = apply_cover(frame, inner, &song.cover_url, covers, cover_scale);
+    let inner = apply_cover(frame, inner, &song.cover_url, song.id, data);
 
     let mut lines = vec![
         Line::from(Span::styled(
@@ -246,6 +293,9 @@ fn render_song_detail(
         format!("by {}", song.uploader_name),
         Theme::secondary(),
     )));
+    if let Some(line) = super::format::artists_line(song) {
+        lines.push(line);
+    }
     lines.push(Line::from(""));
     lines.push(Line::from(vec![
         Span::styled(format!("{}  ", song.format_duration()), Theme::active()),
@@ -313,7 +363,7 @@ fn render_song_detail(
         }
     }
 
-    // 外部链接（仅提示存在，按 o 打开）
+    // 外部链接（带编号，按 o 加数字打开）
     if !song.external_links.is_empty() {
         let mut link_spans: Vec<Span> = Vec::new();
         for (i, link) in song.external_links.iter().enumerate() {
@@ -321,7 +371,7 @@ fn render_song_detail(
                 link_spans.push(Span::styled(" · ", Theme::secondary()));
             }
             link_spans.push(Span::styled(
-                format!(" {} ", link.platform),
+                format!(" {} {} ", i + 1, link.platform),
                 Theme::link_badge(),
             ));
         }
@@ -351,21 +401,17 @@ fn render_user_preview(
     frame: &mut Frame,
     area: Rect,
     user: &PublicUserProfile,
-    covers: &HashMap<String, u32>,
-    cover_scale: u8,
+    data: &ColumnData,
 ) {
     let inner = super::util::padded_rect(area, 2);
-    let inner = if let Some(ref url) = user.avatar_url {
-        apply_cover(frame, inner, url, covers, cover_scale)
-    } else {
-        inner
-    };
+    let url = user.avatar_url.as_deref().unwrap_or_default();
+    let inner = apply_cover(frame, inner, url, user.uid, data);
     let mut lines = vec![Line::from(Span::styled(user.username.clone(), Style::default().add_modifier(Modifier::BOLD)))];
-    if let Some(bio) = &user.bio {
-        if !bio.is_empty() {
-            lines.push(Line::from(""));
-            for l in bio.lines() { lines.push(Line::from(Span::styled(l.to_string(), Theme::secondary()))); }
-        }
+    if let Some(bio) = &user.bio
+        && !bio.is_empty()
+    {
+        lines.push(Line::from(""));
+        for l in bio.lines() { lines.push(Line::from(Span::styled(l.to_string(), Theme::secondary()))); }
     }
     frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
 }
@@ -375,15 +421,11 @@ fn render_playlist_preview(
     frame: &mut Frame,
     area: Rect,
     pl: &PlaylistMetadata,
-    covers: &HashMap<String, u32>,
-    cover_scale: u8,
+    data: &ColumnData,
 ) {
     let inner = super::util::padded_rect(area, 2);
-    let inner = if let Some(ref url) = pl.cover_url {
-        apply_cover(frame, inner, url, covers, cover_scale)
-    } else {
-        inner
-    };
+    let url = pl.cover_url.as_deref().unwrap_or_default();
+    let inner = apply_cover(frame, inner, url, pl.id, data);
     let mut lines = vec![
         Line::from(Span::styled(pl.name.clone(), Style::default().add_modifier(Modifier::BOLD))),
         Line::from(Span::styled(format!("by {}", pl.user_name), Theme::secondary())),
@@ -393,42 +435,49 @@ fn render_playlist_preview(
             Span::styled(format!("{}", pl.songs_count), Theme::active()),
         ]),
     ];
-    if let Some(desc) = &pl.description {
-        if !desc.is_empty() {
-            lines.push(Line::from(""));
-            for l in desc.lines() { lines.push(Line::from(Span::styled(l.to_string(), Theme::secondary()))); }
-        }
+    if let Some(desc) = &pl.description
+        && !desc.is_empty()
+    {
+        lines.push(Line::from(""));
+        for l in desc.lines() { lines.push(Line::from(Span::styled(l.to_string(), Theme::secondary()))); }
     }
     frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
 }
 
-/// 若封面已加载，在 inner 顶部渲染封面并返回收缩后的文字区域；否则原样返回
+/// 在 inner 顶部为封面预留位置并返回收缩后的文字区域：
+/// 已加载时渲染封面，下载中（或已排队）时渲染骨架块，使封面到达时文字不跳动；
+/// 其余情况开启占位时以 `placeholder_id` 派生的色块占位，否则原样返回
 pub fn apply_cover(
     frame: &mut Frame,
     inner: Rect,
     cover_url: &str,
-    covers: &HashMap<String, u32>,
-    cover_scale: u8,
+    placeholder_id: i64,
+    data: &ColumnData,
 ) -> Rect {
     let base_h = (inner.height / 3).min(20);
     if base_h < 4 {
         return inner;
     }
-    let cover_h = (base_h as u32 * cover_scale as u32 / 100).max(2) as u16;
-    if let Some(&id) = covers.get(cover_url) {
-        let cover_w = (cover_h * 2).min(inner.width);
-        let cx = inner.x + (inner.width - cover_w) / 2;
-        let cover_rect = Rect::new(cx, inner.y, cover_w, cover_h);
+    let cover_h = (base_h as u32 * data.settings.display.preview_cover_scale as u32 / 100).max(2) as u16;
+    let cover_w = (cover_h * 2).min(inner.width);
+    let cx = inner.x + (inner.width - cover_w) / 2;
+    let cover_rect = Rect::new(cx, inner.y, cover_w, cover_h);
+    let placeholder = data.cover_placeholders.then(|| super::util::placeholder_color(placeholder_id));
+    if let Some(&id) = data.covers.get(cover_url) {
         frame.render_widget(
             super::cover_widget::CoverWidget { image_id: id },
             cover_rect,
         );
-        Rect {
-            y: inner.y + cover_h + 1,
-            height: inner.height.saturating_sub(cover_h + 1),
-            ..inner
-        }
+    } else if data.cover_loading(cover_url) {
+        super::util::render_cover_skeleton(frame, cover_rect, placeholder);
+    } else if let Some(color) = placeholder {
+        super::util::render_cover_placeholder(frame, cover_rect, color);
     } else {
-        inner
+        return inner;
+    }
+    Rect {
+        y: inner.y + cover_h + 1,
+        height: inner.height.saturating_sub(cover_h + 1),
+        ..inner
     }
 }
@@ -6,18 +6,46 @@ use ratatui::{
     widgets::{List, ListItem, ListState, Paragraph},
 };
 
-use crate::config::settings::{PlayMode, Settings};
+use crate::config::settings::{AutoplayOnStart, CoverPolicy, EnterAction, PlayMode, RepeatAllFrom, Settings, UiLayout};
 use super::i18n::Lang;
 use super::theme::Theme;
 
-pub const ITEMS_COUNT: usize = 4;
+pub const ITEMS_COUNT: usize = 21;
+
+/// 设置页中不修改配置、而是触发一次操作的条目
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsAction {
+    /// 用当前登录态请求一次用户资料，检查连接与认证
+    TestConnection,
+    /// 重新登录，不清空队列与缓存
+    Relogin,
+    /// 重建播放引擎（引擎线程退出或音频设备丢失后恢复）
+    RestartEngine,
+}
 
 pub fn render_list(frame: &mut Frame, area: Rect, settings: &Settings, selected: usize) {
     let items: Vec<ListItem> = vec![
         setting_item(0, selected, t!("settings.language"), lang_label(settings.display.language)),
         setting_item(1, selected, t!("settings.play_mode"), play_mode_label(&settings.player.default_play_mode)),
         setting_item(2, selected, t!("settings.replay_gain"), bool_label(settings.player.replay_gain)),
-        setting_item_owned(3, selected, t!("settings.cover_scale"), format!("{}%", settings.display.cover_scale)),
+        setting_item_owned(3, selected, t!("settings.preview_cover_scale"), format!("{}%", settings.display.preview_cover_scale)),
+        setting_item(4, selected, t!("settings.enter_action"), enter_action_label(settings.player.enter_action)),
+        setting_item(5, selected, t!("settings.credits_roll"), bool_label(settings.display.credits_roll)),
+        setting_item(6, selected, t!("settings.prefetch_queue"), bool_label(settings.player.prefetch_queue)),
+        setting_item(7, selected, t!("settings.autoplay_on_start"), autoplay_label(settings.player.autoplay_on_start)),
+        setting_item(8, selected, t!("settings.data_saver"), bool_label(settings.data_saver)),
+        setting_item(9, selected, t!("settings.rich_preview"), bool_label(settings.display.rich_preview)),
+        setting_item(10, selected, t!("settings.confirm_quit"), bool_label(settings.confirm_quit)),
+        setting_item(11, selected, t!("settings.replace_queue_on_play"), bool_label(settings.player.replace_queue_on_play)),
+        setting_item(12, selected, t!("settings.repeat_all_from"), repeat_all_from_label(settings.player.repeat_all_from)),
+        setting_item(13, selected, t!("settings.cover_policy"), cover_policy_label(settings.display.cover_policy)),
+        setting_item_owned(14, selected, t!("settings.player_cover_scale"), format!("{}%", settings.display.player_cover_scale)),
+        setting_item(15, selected, t!("settings.marquee"), bool_label(settings.display.marquee_enabled)),
+        setting_item(16, selected, t!("settings.bar_thumbnail"), bool_label(settings.display.bar_thumbnail)),
+        setting_item(17, selected, t!("settings.layout"), layout_label(settings.display.layout)),
+        setting_item(18, selected, t!("settings.test_connection"), "\u{21b5}"),
+        setting_item(19, selected, t!("settings.relogin"), "\u{21b5}"),
+        setting_item(20, selected, t!("settings.restart_engine"), "\u{21b5}"),
     ];
 
     let list = List::new(items);
@@ -32,7 +60,21 @@ pub fn render_preview(frame: &mut Frame, area: Rect, settings: &Settings) {
         preview_item(t!("settings.language"), lang_label(settings.display.language)),
         preview_item(t!("settings.play_mode"), play_mode_label(&settings.player.default_play_mode)),
         preview_item(t!("settings.replay_gain"), bool_label(settings.player.replay_gain)),
-        preview_item_owned(t!("settings.cover_scale"), format!("{}%", settings.display.cover_scale)),
+        preview_item_owned(t!("settings.preview_cover_scale"), format!("{}%", settings.display.preview_cover_scale)),
+        preview_item(t!("settings.enter_action"), enter_action_label(settings.player.enter_action)),
+        preview_item(t!("settings.credits_roll"), bool_label(settings.display.credits_roll)),
+        preview_item(t!("settings.prefetch_queue"), bool_label(settings.player.prefetch_queue)),
+        preview_item(t!("settings.autoplay_on_start"), autoplay_label(settings.player.autoplay_on_start)),
+        preview_item(t!("settings.data_saver"), bool_label(settings.data_saver)),
+        preview_item(t!("settings.rich_preview"), bool_label(settings.display.rich_preview)),
+        preview_item(t!("settings.confirm_quit"), bool_label(settings.confirm_quit)),
+        preview_item(t!("settings.replace_queue_on_play"), bool_label(settings.player.replace_queue_on_play)),
+        preview_item(t!("settings.repeat_all_from"), repeat_all_from_label(settings.player.repeat_all_from)),
+        preview_item(t!("settings.cover_policy"), cover_policy_label(settings.display.cover_policy)),
+        preview_item_owned(t!("settings.player_cover_scale"), format!("{}%", settings.display.player_cover_scale)),
+        preview_item(t!("settings.marquee"), bool_label(settings.display.marquee_enabled)),
+        preview_item(t!("settings.bar_thumbnail"), bool_label(settings.display.bar_thumbnail)),
+        preview_item(t!("settings.layout"), layout_label(settings.display.layout)),
     ];
     let list = List::new(items);
     frame.render_widget(list, area);
@@ -57,7 +99,24 @@ pub fn render_hint(frame: &mut Frame, area: Rect, selected: usize, settings: &Se
         0 => "settings.desc.language",
         1 => "settings.desc.play_mode",
         2 => "settings.desc.replay_gain",
-        3 => "settings.desc.cover_scale",
+        3 => "settings.desc.preview_cover_scale",
+        4 => "settings.desc.enter_action",
+        5 => "settings.desc.credits_roll",
+        6 => "settings.desc.prefetch_queue",
+        7 => "settings.desc.autoplay_on_start",
+        8 => "settings.desc.data_saver",
+        9 => "settings.desc.rich_preview",
+        10 => "settings.desc.confirm_quit",
+        11 => "settings.desc.replace_queue_on_play",
+        12 => "settings.desc.repeat_all_from",
+        13 => "settings.desc.cover_policy",
+        14 => "settings.desc.player_cover_scale",
+        15 => "settings.desc.marquee",
+        16 => "settings.desc.bar_thumbnail",
+        17 => "settings.desc.layout",
+        18 => "settings.desc.test_connection",
+        19 => "settings.desc.relogin",
+        20 => "settings.desc.restart_engine",
         _ => "",
     };
     let mut lines = Vec::new();
@@ -71,42 +130,35 @@ pub fn render_hint(frame: &mut Frame, area: Rect, selected: usize, settings: &Se
 
     // 列出当前选项的可选值
     match selected {
-        0 => {
-            let current = settings.display.language;
-            for (lang, label, desc) in [
-                (Lang::En, "English", t!("settings.lang.en.desc")),
-                (Lang::Zh, "中文", t!("settings.lang.zh.desc")),
-            ] {
-                let active = lang == current;
-                let marker = if active { "● " } else { "○ " };
-                let style = if active {
-                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
-                } else {
-                    Theme::secondary()
-                };
-                lines.push(Line::from(vec![
-                    Span::styled(marker, style),
-                    Span::styled(label, style),
-                ]));
-                lines.push(Line::from(Span::styled(
-                    format!("  {}", desc),
-                    Theme::secondary(),
-                )));
-            }
-        }
-        3 => {
-            let pct = settings.display.cover_scale;
-            let bar_width = 20usize;
-            let filled = (pct as usize * bar_width / 200).min(bar_width);
-            let empty = bar_width - filled;
-            lines.push(Line::from(vec![
-                Span::styled("▕", Theme::secondary()),
-                Span::styled("█".repeat(filled), Style::default().fg(Color::Yellow)),
-                Span::styled("░".repeat(empty), Theme::secondary()),
-                Span::styled("▏", Theme::secondary()),
-                Span::styled(format!(" {}%", pct), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
-            ]));
-        }
+        0 => lines.extend(radio_options(settings.display.language, &[
+            (Lang::En, lang_label(Lang::En), t!("settings.lang.en.desc")),
+            (Lang::Zh, lang_label(Lang::Zh), t!("settings.lang.zh.desc")),
+        ])),
+        3 => lines.push(scale_bar(settings.display.preview_cover_scale)),
+        14 => lines.push(scale_bar(settings.display.player_cover_scale)),
+        4 => lines.extend(radio_options(settings.player.enter_action, &[
+            (EnterAction::PlayList, enter_action_label(EnterAction::PlayList), t!("settings.enter.play_list.desc")),
+            (EnterAction::PlayOne, enter_action_label(EnterAction::PlayOne), t!("settings.enter.play_one.desc")),
+            (EnterAction::AddAndPlay, enter_action_label(EnterAction::AddAndPlay), t!("settings.enter.add_and_play.desc")),
+        ])),
+        7 => lines.extend(radio_options(settings.player.autoplay_on_start, &[
+            (AutoplayOnStart::Off, autoplay_label(AutoplayOnStart::Off), t!("settings.autoplay.off.desc")),
+            (AutoplayOnStart::Resume, autoplay_label(AutoplayOnStart::Resume), t!("settings.autoplay.resume.desc")),
+        ])),
+        12 => lines.extend(radio_options(settings.player.repeat_all_from, &[
+            (RepeatAllFrom::QueueStart, repeat_all_from_label(RepeatAllFrom::QueueStart), t!("settings.repeat_from.queue_start.desc")),
+            (RepeatAllFrom::PlayStart, repeat_all_from_label(RepeatAllFrom::PlayStart), t!("settings.repeat_from.play_start.desc")),
+        ])),
+        13 => lines.extend(radio_options(settings.display.cover_policy, &[
+            (CoverPolicy::Off, cover_policy_label(CoverPolicy::Off), t!("settings.cover_policy.off.desc")),
+            (CoverPolicy::PlayingOnly, cover_policy_label(CoverPolicy::PlayingOnly), t!("settings.cover_policy.playing_only.desc")),
+            (CoverPolicy::Browse, cover_policy_label(CoverPolicy::Browse), t!("settings.cover_policy.browse.desc")),
+            (CoverPolicy::Prefetch, cover_policy_label(CoverPolicy::Prefetch), t!("settings.cover_policy.prefetch.desc")),
+        ])),
+        17 => lines.extend(radio_options(settings.display.layout, &[
+            (UiLayout::Classic, layout_label(UiLayout::Classic), t!("settings.layout.classic.desc")),
+            (UiLayout::Modern, layout_label(UiLayout::Modern), t!("settings.layout.modern.desc")),
+        ])),
         _ => {}
     }
 
@@ -120,6 +172,46 @@ pub fn render_hint(frame: &mut Frame, area: Rect, selected: usize, settings: &Se
     frame.render_widget(para, inner);
 }
 
+/// 单选项列表：当前值标 ●，其余标 ○，每项下方附一行说明
+fn radio_options<T: PartialEq + Copy>(
+    current: T,
+    options: &[(T, &'static str, &'static str)],
+) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    for &(value, label, desc) in options {
+        let active = value == current;
+        let marker = if active { "● " } else { "○ " };
+        let style = if active {
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+        } else {
+            Theme::secondary()
+        };
+        lines.push(Line::from(vec![
+            Span::styled(marker, style),
+            Span::styled(label, style),
+        ]));
+        lines.push(Line::from(Span::styled(
+            format!("  {}", desc),
+            Theme::secondary(),
+        )));
+    }
+    lines
+}
+
+/// 缩放比例条（20%-200%）
+fn scale_bar(pct: u8) -> Line<'static> {
+    let bar_width = 20usize;
+    let filled = (pct as usize * bar_width / 200).min(bar_width);
+    let empty = bar_width - filled;
+    Line::from(vec![
+        Span::styled("▕", Theme::secondary()),
+        Span::styled("█".repeat(filled), Style::default().fg(Color::Yellow)),
+        Span::styled("░".repeat(empty), Theme::secondary()),
+        Span::styled("▏", Theme::secondary()),
+        Span::styled(format!(" {}%", pct), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+    ])
+}
+
 fn setting_item<'a>(
     index: usize,
     selected: usize,
@@ -169,36 +261,123 @@ fn lang_label(lang: Lang) -> &'static str {
 fn play_mode_label(mode: &PlayMode) -> &'static str {
     match mode {
         PlayMode::Sequential => t!("settings.sequential"),
+        PlayMode::RepeatAll => t!("settings.repeat_all"),
         PlayMode::Shuffle => t!("settings.shuffle"),
         PlayMode::RepeatOne => t!("settings.repeat_one"),
     }
 }
 
+fn enter_action_label(action: EnterAction) -> &'static str {
+    match action {
+        EnterAction::PlayList => t!("settings.enter.play_list"),
+        EnterAction::PlayOne => t!("settings.enter.play_one"),
+        EnterAction::AddAndPlay => t!("settings.enter.add_and_play"),
+    }
+}
+
+fn repeat_all_from_label(from: RepeatAllFrom) -> &'static str {
+    match from {
+        RepeatAllFrom::QueueStart => t!("settings.repeat_from.queue_start"),
+        RepeatAllFrom::PlayStart => t!("settings.repeat_from.play_start"),
+    }
+}
+
+fn cover_policy_label(policy: CoverPolicy) -> &'static str {
+    match policy {
+        CoverPolicy::Off => t!("settings.cover_policy.off"),
+        CoverPolicy::PlayingOnly => t!("settings.cover_policy.playing_only"),
+        CoverPolicy::Browse => t!("settings.cover_policy.browse"),
+        CoverPolicy::Prefetch => t!("settings.cover_policy.prefetch"),
+    }
+}
+
+fn autoplay_label(mode: AutoplayOnStart) -> &'static str {
+    match mode {
+        AutoplayOnStart::Off => t!("settings.autoplay.off"),
+        AutoplayOnStart::Resume => t!("settings.autoplay.resume"),
+    }
+}
+
+fn layout_label(layout: UiLayout) -> &'static str {
+    match layout {
+        UiLayout::Classic => t!("settings.layout.classic"),
+        UiLayout::Modern => t!("settings.layout.modern"),
+    }
+}
+
 fn bool_label(val: bool) -> &'static str {
     if val { t!("settings.on") } else { t!("settings.off") }
 }
 
+/// 封面缩放以 10% 递增，超过 200% 回到 20%。
+/// 封面按放置区域由终端缩放，每帧重新放置，改动后无需重新上传图片
+fn next_scale(v: u8) -> u8 {
+    if v >= 200 { 20 } else { v + 10 }
+}
+
 /// Cycle the setting at the given index.
-pub fn cycle_setting(settings: &mut Settings, index: usize) {
+/// 操作类条目不修改设置，返回需要由调用方执行的操作。
+pub fn cycle_setting(settings: &mut Settings, index: usize) -> Option<SettingsAction> {
     match index {
         0 => {
             settings.display.language = settings.display.language.next();
             crate::ui::i18n::set_lang(settings.display.language);
         }
         1 => {
-            settings.player.default_play_mode = match settings.player.default_play_mode {
-                PlayMode::Sequential => PlayMode::Shuffle,
-                PlayMode::Shuffle => PlayMode::RepeatOne,
-                PlayMode::RepeatOne => PlayMode::Sequential,
-            };
+            settings.player.default_play_mode = settings.player.default_play_mode.next();
         }
         2 => {
             settings.player.replay_gain = !settings.player.replay_gain;
         }
         3 => {
-            let v = settings.display.cover_scale;
-            settings.display.cover_scale = if v >= 200 { 20 } else { v + 10 };
+            settings.display.preview_cover_scale = next_scale(settings.display.preview_cover_scale);
+        }
+        4 => {
+            settings.player.enter_action = settings.player.enter_action.next();
+        }
+        5 => {
+            settings.display.credits_roll = !settings.display.credits_roll;
+        }
+        6 => {
+            settings.player.prefetch_queue = !settings.player.prefetch_queue;
+        }
+        7 => {
+            settings.player.autoplay_on_start = settings.player.autoplay_on_start.next();
+        }
+        8 => {
+            settings.data_saver = !settings.data_saver;
+        }
+        9 => {
+            settings.display.rich_preview = !settings.display.rich_preview;
+        }
+        10 => {
+            settings.confirm_quit = !settings.confirm_quit;
+        }
+        11 => {
+            settings.player.replace_queue_on_play = !settings.player.replace_queue_on_play;
+        }
+        12 => {
+            settings.player.repeat_all_from = settings.player.repeat_all_from.next();
+        }
+        13 => {
+            settings.display.cover_policy = settings.display.cover_policy.next();
+        }
+        14 => {
+            settings.display.player_cover_scale = next_scale(settings.display.player_cover_scale);
+        }
+        15 => {
+            settings.display.marquee_enabled = !settings.display.marquee_enabled;
+        }
+        16 => {
+            settings.display.bar_thumbnail = !settings.display.bar_thumbnail;
+        }
+        17 => {
+            settings.display.layout = settings.display.layout.next();
         }
+        18 => return Some(SettingsAction::TestConnection),
+        19 => return Some(SettingsAction::Relogin),
+        20 => return Some(SettingsAction::RestartEngine),
         _ => {}
     }
+    None
 }
@@ -0,0 +1,113 @@
+use std::fmt::Write;
+
+/// 返回判定支持 Sixel 所依据的环境变量（与 Kitty 检测一样只读环境变量，不查询终端）
+pub fn detection_source() -> Option<&'static str> {
+    if let Ok(term) = std::env::var("TERM")
+        && ["foot", "mlterm", "yaft", "contour"].iter().any(|t| term.starts_with(t))
+    {
+        return Some("TERM");
+    }
+    if let Ok(val) = std::env::var("TERM_PROGRAM")
+        && matches!(val.as_str(), "WezTerm" | "mintty" | "iTerm.app")
+    {
+        return Some("TERM_PROGRAM");
+    }
+    if std::env::var_os("KONSOLE_VERSION").is_some() {
+        return Some("KONSOLE_VERSION");
+    }
+    None
+}
+
+/// 每个分量量化为 6 级，组成 216 色的调色板
+const LEVELS: u8 = 6;
+
+fn quantize(v: u8) -> u8 {
+    ((v as u16 * (LEVELS as u16 - 1) + 127) / 255) as u8
+}
+
+/// 把 RGB 像素编码为 Sixel 序列：6x6x6 色立方体调色板，每 6 行一带、逐色输出，连续相同列做行程压缩
+pub fn encode(rgb: &[u8], width: usize, height: usize) -> Vec<u8> {
+    let colors = (LEVELS as usize).pow(3);
+    let index: Vec<u8> = rgb
+        .chunks_exact(3)
+        .map(|p| quantize(p[0]) * LEVELS * LEVELS + quantize(p[1]) * LEVELS + quantize(p[2]))
+        .collect();
+
+    let mut out = String::new();
+    // P2=1：未写到的像素保持背景；"1;1 为 1:1 像素比
+    let _ = write!(out, "\x1bP0;1q\"1;1;{width};{height}");
+    let step = 100 / (LEVELS as usize - 1);
+    for c in 0..colors {
+        let (r, g, b) = (c / 36, c / 6 % 6, c % 6);
+        let _ = write!(out, "#{c};2;{};{};{}", r * step, g * step, b * step);
+    }
+
+    for band in (0..height).step_by(6) {
+        let rows = (height - band).min(6);
+        let mut used = vec![false; colors];
+        for y in band..band + rows {
+            for &i in &index[y * width..(y + 1) * width] {
+                used[i as usize] = true;
+            }
+        }
+        let mut first = true;
+        for color in (0..colors).filter(|&c| used[c]) {
+            if !first {
+                out.push('$');
+            }
+            first = false;
+            let _ = write!(out, "#{color}");
+            let mut run: Option<(char, usize)> = None;
+            for x in 0..width {
+                let bits = (0..rows)
+                    .filter(|r| index[(band + r) * width + x] as usize == color)
+                    .fold(0u8, |acc, r| acc | (1 << r));
+                let ch = (63 + bits) as char;
+                run = match run {
+                    Some((c, n)) if c == ch => Some((c, n + 1)),
+                    Some((c, n)) => {
+                        push_run(&mut out, c, n);
+                        Some((ch, 1))
+                    }
+                    None => Some((ch, 1)),
+                };
+            }
+            if let Some((c, n)) = run {
+                push_run(&mut out, c, n);
+            }
+        }
+        out.push('-');
+    }
+    out.push_str("\x1b\\");
+    out.into_bytes()
+}
+
+fn push_run(out: &mut String, ch: char, n: usize) {
+    if n > 3 {
+        let _ = write!(out, "!{n}{ch}");
+    } else {
+        out.extend(std::iter::repeat_n(ch, n));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::encode;
+
+    #[test]
+    fn encodes_solid_row_with_run_length() {
+        // 5x1 纯红：调色板 #180（r=5,g=0,b=0），第一行位为 1 → '@'
+        let rgb = [255, 0, 0].repeat(5);
+        let seq = String::from_utf8(encode(&rgb, 5, 1)).unwrap();
+        assert!(seq.starts_with("\x1bP0;1q\"1;1;5;1"));
+        assert!(seq.ends_with("#180!5@-\x1b\\"));
+    }
+
+    #[test]
+    fn separates_colors_within_a_band() {
+        // 2x1：黑、白两色各占一列
+        let rgb = [0, 0, 0, 255, 255, 255];
+        let seq = String::from_utf8(encode(&rgb, 2, 1)).unwrap();
+        assert!(seq.ends_with("#0@?$#215?@-\x1b\\"));
+    }
+}
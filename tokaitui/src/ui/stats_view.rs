@@ -0,0 +1,58 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use crate::config::stats_store::ListeningStats;
+use super::theme::Theme;
+
+/// 渲染收听统计浮层：本次会话与累计两列
+pub fn render(frame: &mut Frame, area: Rect, session: &ListeningStats, all_time: &ListeningStats) {
+    let (content_area, hint_area) = super::util::overlay_panel(
+        frame, area, t!("stats.title"),
+        super::constants::STATS_PANEL_WIDTH, 9,
+    );
+
+    let row = |label: &str, session: String, total: String| {
+        Line::from(vec![
+            Span::styled(format!("  {label:<12}"), Theme::secondary()),
+            Span::raw(format!("{session:>10}")),
+            Span::styled(format!("{total:>12}"), Theme::active()),
+        ])
+    };
+
+    let lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("  {:<12}{:>10}{:>12}", "", t!("stats.session"), t!("stats.all_time")),
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        row(t!("stats.plays"), session.plays.to_string(), all_time.plays.to_string()),
+        row(t!("stats.completed"), session.completed.to_string(), all_time.completed.to_string()),
+        row(
+            t!("stats.listened"),
+            format_duration(session.listened_secs),
+            format_duration(all_time.listened_secs),
+        ),
+    ];
+    frame.render_widget(Paragraph::new(lines), content_area);
+
+    let hint = Paragraph::new(Span::styled(
+        format!("  {}", t!("stats.close")),
+        Theme::secondary(),
+    ));
+    frame.render_widget(hint, hint_area);
+}
+
+fn format_duration(secs: u64) -> String {
+    let h = secs / 3600;
+    let m = secs % 3600 / 60;
+    if h > 0 {
+        format!("{h}h {m:02}m")
+    } else {
+        format!("{m}m {:02}s", secs % 60)
+    }
+}
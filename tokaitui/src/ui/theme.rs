@@ -1,7 +1,49 @@
+use std::sync::RwLock;
+
 use ratatui::style::{Color, Modifier, Style};
 
 pub struct Theme;
 
+/// 列表的两种强调色：选中行的背景、正在播放行的前景。两者互不覆盖，
+/// 选中的播放行同时显示两种提示
+#[derive(Debug, Clone, Copy)]
+struct Accents {
+    selection_bg: Color,
+    now_playing_fg: Color,
+}
+
+static ACCENTS: RwLock<Accents> = RwLock::new(Accents {
+    selection_bg: Color::DarkGray,
+    now_playing_fg: Color::Green,
+});
+
+fn accents() -> Accents {
+    *ACCENTS.read().unwrap_or_else(|e| e.into_inner())
+}
+
+/// 按配置的颜色名（如 `dark_gray`、`green`、`#5f87af`、`245`）设置强调色；
+/// 无法解析的保留原值，返回这些颜色名
+pub fn set_accents(selection: &str, now_playing: &str) -> Vec<String> {
+    let mut invalid = Vec::new();
+    let mut parse = |name: &str| {
+        let color = name.parse::<Color>().ok();
+        if color.is_none() {
+            invalid.push(name.to_string());
+        }
+        color
+    };
+    let selection_bg = parse(selection);
+    let now_playing_fg = parse(now_playing);
+    let mut accents = ACCENTS.write().unwrap_or_else(|e| e.into_inner());
+    if let Some(c) = selection_bg {
+        accents.selection_bg = c;
+    }
+    if let Some(c) = now_playing_fg {
+        accents.now_playing_fg = c;
+    }
+    invalid
+}
+
 /// 标签调色板：用于给不同标签分配不同的背景色块
 const TAG_COLORS: &[Color] = &[
     Color::Blue,
@@ -57,9 +99,14 @@ impl Theme {
         Style::default().add_modifier(Modifier::BOLD)
     }
 
-    #[allow(dead_code)] // TODO: 选中行样式
+    /// 选中行：强调背景，作为列表的 highlight_style
     pub fn selected_row() -> Style {
-        Style::default().bg(Color::DarkGray)
+        Style::default().bg(accents().selection_bg).add_modifier(Modifier::BOLD)
+    }
+
+    /// 正在播放行：强调前景，用于均衡器标记与标题
+    pub fn now_playing() -> Style {
+        Style::default().fg(accents().now_playing_fg)
     }
 
     #[allow(dead_code)] // TODO: 进度条样式
@@ -85,14 +132,24 @@ impl Theme {
     /// 按索引返回颜色，若与 avoid 撞色则顺移
     pub fn tag_color(index: usize, avoid: Option<Color>) -> Color {
         let mut idx = index % TAG_COLORS.len();
-        if let Some(prev) = avoid {
-            if TAG_COLORS[idx] == prev {
-                idx = (idx + 1) % TAG_COLORS.len();
-            }
+        if let Some(prev) = avoid
+            && TAG_COLORS[idx] == prev
+        {
+            idx = (idx + 1) % TAG_COLORS.len();
         }
         TAG_COLORS[idx]
     }
 
+    /// 提示类色块（如操作反馈）
+    pub fn info_badge() -> Style {
+        Style::default().fg(Color::Black).bg(Color::Cyan)
+    }
+
+    /// 警告类色块（如重复添加、状态提醒）
+    pub fn warning_badge() -> Style {
+        Style::default().fg(Color::Black).bg(Color::Yellow)
+    }
+
     /// 外部链接固定样式
     pub fn link_badge() -> Style {
         Style::default().bg(Color::DarkGray).fg(Color::White)
@@ -0,0 +1,58 @@
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    text::Span,
+    widgets::{Clear, Paragraph},
+};
+use unicode_width::UnicodeWidthStr;
+
+use super::theme::Theme;
+
+/// 提示的显示时长
+const TOAST_DURATION: Duration = Duration::from_millis(2000);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastKind {
+    Info,
+    Warn,
+}
+
+/// 短暂显示在主内容区右下角的一行提示
+pub struct Toast {
+    pub message: String,
+    pub kind: ToastKind,
+    shown_at: Instant,
+}
+
+impl Toast {
+    pub fn new(kind: ToastKind, message: String) -> Self {
+        Self { message, kind, shown_at: Instant::now() }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.shown_at.elapsed() >= TOAST_DURATION
+    }
+}
+
+/// 在 area 的右下角渲染提示（覆盖其下内容）
+pub fn render(frame: &mut Frame, area: Rect, toast: &Toast) {
+    if area.height == 0 || area.width < 4 {
+        return;
+    }
+    let text = format!(" {} ", toast.message);
+    let width = (text.width() as u16).min(area.width);
+    let rect = Rect::new(
+        area.x + area.width - width,
+        area.y + area.height - 1,
+        width,
+        1,
+    );
+    let style = match toast.kind {
+        ToastKind::Info => Theme::info_badge(),
+        ToastKind::Warn => Theme::warning_badge(),
+    };
+    frame.render_widget(Clear, rect);
+    frame.render_widget(Paragraph::new(Span::styled(text, style)), rect);
+}
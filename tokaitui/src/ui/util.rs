@@ -26,6 +26,81 @@ pub fn render_placeholder(frame: &mut Frame, area: Rect, is_loading: bool, empty
     frame.render_widget(Paragraph::new(Span::styled(format!("  {text}"), style)), area);
 }
 
+/// 渲染加载失败提示（按 r 重试）
+pub fn render_load_failed(frame: &mut Frame, area: Rect) {
+    frame.render_widget(
+        Paragraph::new(Span::styled(format!("  {}", t!("miller.load_failed")), Theme::error())),
+        area,
+    );
+}
+
+/// 渲染需要登录的占位提示（匿名浏览时的登录专属节点）
+pub fn render_login_required(frame: &mut Frame, area: Rect) {
+    frame.render_widget(
+        Paragraph::new(Span::styled(format!("  {}", t!("miller.login_required")), Theme::secondary())),
+        area,
+    );
+}
+
+/// 由 ID 派生稳定的封面占位色（同一 ID 每次启动颜色一致）
+pub fn placeholder_color(id: i64) -> Color {
+    // splitmix64 打散相邻 ID，避免连续歌曲颜色过于接近
+    let mut x = (id as u64).wrapping_add(0x9e37_79b9_7f4a_7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^= x >> 31;
+    hsv_to_rgb((x % 360) as f32, 0.45, 0.55)
+}
+
+fn hsv_to_rgb(h: f32, s: f32, v: f32) -> Color {
+    let c = v * s;
+    let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+    let m = v - c;
+    let (r, g, b) = match h as u32 {
+        0..60 => (c, x, 0.0),
+        60..120 => (x, c, 0.0),
+        120..180 => (0.0, c, x),
+        180..240 => (0.0, x, c),
+        240..300 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let to_u8 = |f: f32| ((f + m) * 255.0).round() as u8;
+    Color::Rgb(to_u8(r), to_u8(g), to_u8(b))
+}
+
+/// 封面未就绪时的占位色块：自上而下逐渐变暗的渐变
+pub fn render_cover_placeholder(frame: &mut Frame, area: Rect, color: Color) {
+    let Color::Rgb(r, g, b) = color else { return };
+    let buf = frame.buffer_mut();
+    let rows = area.height.max(1) as u32;
+    for (i, y) in (area.top()..area.bottom()).enumerate() {
+        // 底部压暗到原色的 60%
+        let k = 100 - 40 * i as u32 / rows;
+        let shade = |c: u8| (c as u32 * k / 100) as u8;
+        let bg = Color::Rgb(shade(r), shade(g), shade(b));
+        for x in area.left()..area.right() {
+            if let Some(cell) = buf.cell_mut((x, y)) {
+                cell.reset();
+                cell.set_bg(bg);
+            }
+        }
+    }
+}
+
+/// 封面下载中的骨架块：占位色（无则中性灰）上居中显示加载标记
+pub fn render_cover_skeleton(frame: &mut Frame, area: Rect, color: Option<Color>) {
+    render_cover_placeholder(frame, area, color.unwrap_or(Color::Rgb(72, 72, 72)));
+    if area.height == 0 {
+        return;
+    }
+    let mark = Rect { y: area.y + area.height / 2, height: 1, ..area };
+    frame.render_widget(
+        Paragraph::new(Span::styled("\u{25cc}", Style::default().fg(Color::Gray)))
+            .alignment(ratatui::layout::Alignment::Center),
+        mark,
+    );
+}
+
 /// 渲染居中浮层面板骨架（清除背景 + 边框 + 标题），
 /// 返回 `(content_area, hint_area)`：content 可滚动，hint 钉在底部不受滚动影响。
 pub fn overlay_panel(
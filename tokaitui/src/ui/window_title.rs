@@ -0,0 +1,39 @@
+/// 默认标题格式
+pub const DEFAULT_FORMAT: &str = "{title} \u{2014} {artist}";
+/// 没有播放歌曲时的标题
+pub const IDLE_TITLE: &str = "tokaitui";
+
+/// 按格式生成标题，支持 `{title}`、`{artist}` 占位符
+pub fn format_title(format: &str, title: &str, artist: &str) -> String {
+    format.replace("{title}", title).replace("{artist}", artist)
+}
+
+/// 设置窗口标题的 OSC 0 序列。控制字符会被替换为空格，避免提前结束序列或向终端注入其他序列
+pub fn set_title_seq(title: &str) -> Vec<u8> {
+    let clean: String = title
+        .chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect();
+    format!("\x1b]0;{clean}\x07").into_bytes()
+}
+
+/// 把终端原有标题压栈（XTWINOPS），退出时用 `POP_TITLE` 恢复；不支持的终端会忽略
+pub const PUSH_TITLE: &[u8] = b"\x1b[22;0t";
+pub const POP_TITLE: &[u8] = b"\x1b[23;0t";
+
+#[cfg(test)]
+mod tests {
+    use super::{format_title, set_title_seq};
+
+    #[test]
+    fn format_title_fills_placeholders() {
+        assert_eq!(format_title("{title} \u{2014} {artist}", "Song", "Singer"), "Song \u{2014} Singer");
+        assert_eq!(format_title("\u{266a} {title}", "Song", "Singer"), "\u{266a} Song");
+    }
+
+    #[test]
+    fn set_title_seq_strips_control_chars() {
+        assert_eq!(set_title_seq("a\x07b\x1b]0;c"), b"\x1b]0;a b ]0;c\x07".to_vec());
+        assert_eq!(set_title_seq("哈基米"), "\x1b]0;哈基米\x07".as_bytes().to_vec());
+    }
+}